@@ -0,0 +1,20 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use wow_dbc::RawDbc;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(raw) = RawDbc::read(&mut &data[..]) else {
+        return;
+    };
+
+    for i in 0..raw.len() {
+        for word in raw.words(i).into_iter().flatten() {
+            let _ = raw.string(word);
+        }
+    }
+
+    let mut v = Vec::new();
+    raw.write(&mut v).unwrap();
+    assert_eq!(v, data[..v.len()]);
+});
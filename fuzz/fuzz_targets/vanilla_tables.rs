@@ -0,0 +1,166 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use wow_dbc_fuzz::fuzz_table;
+use wow_dbc::vanilla_tables::*;
+
+fuzz_target!(|data: &[u8]| {
+    fuzz_table::<animation_data::AnimationData>(data);
+    fuzz_table::<area_poi::AreaPOI>(data);
+    fuzz_table::<area_table::AreaTable>(data);
+    fuzz_table::<area_trigger::AreaTrigger>(data);
+    fuzz_table::<attack_anim_kits::AttackAnimKits>(data);
+    fuzz_table::<attack_anim_types::AttackAnimTypes>(data);
+    fuzz_table::<auction_house::AuctionHouse>(data);
+    fuzz_table::<bank_bag_slot_prices::BankBagSlotPrices>(data);
+    fuzz_table::<camera_shakes::CameraShakes>(data);
+    fuzz_table::<cfg_categories::Cfg_Categories>(data);
+    fuzz_table::<cfg_configs::Cfg_Configs>(data);
+    fuzz_table::<char_base_info::CharBaseInfo>(data);
+    fuzz_table::<char_hair_geosets::CharHairGeosets>(data);
+    fuzz_table::<char_hair_textures::CharHairTextures>(data);
+    fuzz_table::<char_sections::CharSections>(data);
+    fuzz_table::<char_start_outfit::CharStartOutfit>(data);
+    fuzz_table::<char_variations::CharVariations>(data);
+    fuzz_table::<character_create_cameras::CharacterCreateCameras>(data);
+    fuzz_table::<character_facial_hair_styles::CharacterFacialHairStyles>(data);
+    fuzz_table::<chat_channels::ChatChannels>(data);
+    fuzz_table::<chat_profanity::ChatProfanity>(data);
+    fuzz_table::<chr_classes::ChrClasses>(data);
+    fuzz_table::<chr_races::ChrRaces>(data);
+    fuzz_table::<cinematic_camera::CinematicCamera>(data);
+    fuzz_table::<cinematic_sequences::CinematicSequences>(data);
+    fuzz_table::<creature_display_info::CreatureDisplayInfo>(data);
+    fuzz_table::<creature_display_info_extra::CreatureDisplayInfoExtra>(data);
+    fuzz_table::<creature_family::CreatureFamily>(data);
+    fuzz_table::<creature_model_data::CreatureModelData>(data);
+    fuzz_table::<creature_sound_data::CreatureSoundData>(data);
+    fuzz_table::<creature_spell_data::CreatureSpellData>(data);
+    fuzz_table::<creature_type::CreatureType>(data);
+    fuzz_table::<death_thud_lookups::DeathThudLookups>(data);
+    fuzz_table::<durability_costs::DurabilityCosts>(data);
+    fuzz_table::<durability_quality::DurabilityQuality>(data);
+    fuzz_table::<emotes::Emotes>(data);
+    fuzz_table::<emotes_text::EmotesText>(data);
+    fuzz_table::<emotes_text_data::EmotesTextData>(data);
+    fuzz_table::<emotes_text_sound::EmotesTextSound>(data);
+    fuzz_table::<environmental_damage::EnvironmentalDamage>(data);
+    fuzz_table::<exhaustion::Exhaustion>(data);
+    fuzz_table::<faction::Faction>(data);
+    fuzz_table::<faction_group::FactionGroup>(data);
+    fuzz_table::<faction_template::FactionTemplate>(data);
+    fuzz_table::<footprint_textures::FootprintTextures>(data);
+    fuzz_table::<footstep_terrain_lookup::FootstepTerrainLookup>(data);
+    fuzz_table::<gm_survey_current_survey::GMSurveyCurrentSurvey>(data);
+    fuzz_table::<gm_survey_questions::GMSurveyQuestions>(data);
+    fuzz_table::<gm_survey_surveys::GMSurveySurveys>(data);
+    fuzz_table::<gm_ticket_category::GMTicketCategory>(data);
+    fuzz_table::<game_object_art_kit::GameObjectArtKit>(data);
+    fuzz_table::<game_object_display_info::GameObjectDisplayInfo>(data);
+    fuzz_table::<game_tips::GameTips>(data);
+    fuzz_table::<ground_effect_doodad::GroundEffectDoodad>(data);
+    fuzz_table::<ground_effect_texture::GroundEffectTexture>(data);
+    fuzz_table::<helmet_geoset_vis_data::HelmetGeosetVisData>(data);
+    fuzz_table::<item_bag_family::ItemBagFamily>(data);
+    fuzz_table::<item_class::ItemClass>(data);
+    fuzz_table::<item_display_info::ItemDisplayInfo>(data);
+    fuzz_table::<item_group_sounds::ItemGroupSounds>(data);
+    fuzz_table::<item_pet_food::ItemPetFood>(data);
+    fuzz_table::<item_random_properties::ItemRandomProperties>(data);
+    fuzz_table::<item_set::ItemSet>(data);
+    fuzz_table::<item_sub_class::ItemSubClass>(data);
+    fuzz_table::<item_sub_class_mask::ItemSubClassMask>(data);
+    fuzz_table::<item_visual_effects::ItemVisualEffects>(data);
+    fuzz_table::<item_visuals::ItemVisuals>(data);
+    fuzz_table::<lfg_dungeons::LFGDungeons>(data);
+    fuzz_table::<language_words::LanguageWords>(data);
+    fuzz_table::<languages::Languages>(data);
+    fuzz_table::<light::Light>(data);
+    fuzz_table::<light_float_band::LightFloatBand>(data);
+    fuzz_table::<light_int_band::LightIntBand>(data);
+    fuzz_table::<light_params::LightParams>(data);
+    fuzz_table::<light_skybox::LightSkybox>(data);
+    fuzz_table::<liquid_type::LiquidType>(data);
+    fuzz_table::<loading_screen_taxi_splines::LoadingScreenTaxiSplines>(data);
+    fuzz_table::<loading_screens::LoadingScreens>(data);
+    fuzz_table::<lock::Lock>(data);
+    fuzz_table::<lock_type::LockType>(data);
+    fuzz_table::<mail_template::MailTemplate>(data);
+    fuzz_table::<map::Map>(data);
+    fuzz_table::<material::Material>(data);
+    fuzz_table::<npc_sounds::NPCSounds>(data);
+    fuzz_table::<name_gen::NameGen>(data);
+    fuzz_table::<names_profanity::NamesProfanity>(data);
+    fuzz_table::<names_reserved::NamesReserved>(data);
+    fuzz_table::<package::Package>(data);
+    fuzz_table::<page_text_material::PageTextMaterial>(data);
+    fuzz_table::<paper_doll_item_frame::PaperDollItemFrame>(data);
+    fuzz_table::<pet_loyalty::PetLoyalty>(data);
+    fuzz_table::<pet_personality::PetPersonality>(data);
+    fuzz_table::<quest_info::QuestInfo>(data);
+    fuzz_table::<quest_sort::QuestSort>(data);
+    fuzz_table::<resistances::Resistances>(data);
+    fuzz_table::<server_messages::ServerMessages>(data);
+    fuzz_table::<sheathe_sound_lookups::SheatheSoundLookups>(data);
+    fuzz_table::<skill_costs_data::SkillCostsData>(data);
+    fuzz_table::<skill_line::SkillLine>(data);
+    fuzz_table::<skill_line_ability::SkillLineAbility>(data);
+    fuzz_table::<skill_line_category::SkillLineCategory>(data);
+    fuzz_table::<skill_race_class_info::SkillRaceClassInfo>(data);
+    fuzz_table::<skill_tiers::SkillTiers>(data);
+    fuzz_table::<sound_ambience::SoundAmbience>(data);
+    fuzz_table::<sound_character_macro_lines::SoundCharacterMacroLines>(data);
+    fuzz_table::<sound_entries::SoundEntries>(data);
+    fuzz_table::<sound_provider_preferences::SoundProviderPreferences>(data);
+    fuzz_table::<sound_sample_preferences::SoundSamplePreferences>(data);
+    fuzz_table::<sound_water_type::SoundWaterType>(data);
+    fuzz_table::<spam_messages::SpamMessages>(data);
+    fuzz_table::<spell::Spell>(data);
+    fuzz_table::<spell_aura_names::SpellAuraNames>(data);
+    fuzz_table::<spell_cast_times::SpellCastTimes>(data);
+    fuzz_table::<spell_category::SpellCategory>(data);
+    fuzz_table::<spell_chain_effects::SpellChainEffects>(data);
+    fuzz_table::<spell_dispel_type::SpellDispelType>(data);
+    fuzz_table::<spell_duration::SpellDuration>(data);
+    fuzz_table::<spell_effect_camera_shakes::SpellEffectCameraShakes>(data);
+    fuzz_table::<spell_effect_names::SpellEffectNames>(data);
+    fuzz_table::<spell_focus_object::SpellFocusObject>(data);
+    fuzz_table::<spell_icon::SpellIcon>(data);
+    fuzz_table::<spell_item_enchantment::SpellItemEnchantment>(data);
+    fuzz_table::<spell_mechanic::SpellMechanic>(data);
+    fuzz_table::<spell_radius::SpellRadius>(data);
+    fuzz_table::<spell_range::SpellRange>(data);
+    fuzz_table::<spell_shapeshift_form::SpellShapeshiftForm>(data);
+    fuzz_table::<spell_visual::SpellVisual>(data);
+    fuzz_table::<spell_visual_effect_name::SpellVisualEffectName>(data);
+    fuzz_table::<spell_visual_kit::SpellVisualKit>(data);
+    fuzz_table::<spell_visual_precast_transitions::SpellVisualPrecastTransitions>(data);
+    fuzz_table::<stable_slot_prices::StableSlotPrices>(data);
+    fuzz_table::<startup_strings::Startup_Strings>(data);
+    fuzz_table::<stationery::Stationery>(data);
+    fuzz_table::<string_lookups::StringLookups>(data);
+    fuzz_table::<talent::Talent>(data);
+    fuzz_table::<talent_tab::TalentTab>(data);
+    fuzz_table::<taxi_nodes::TaxiNodes>(data);
+    fuzz_table::<taxi_path::TaxiPath>(data);
+    fuzz_table::<taxi_path_node::TaxiPathNode>(data);
+    fuzz_table::<terrain_type::TerrainType>(data);
+    fuzz_table::<terrain_type_sounds::TerrainTypeSounds>(data);
+    fuzz_table::<transport_animation::TransportAnimation>(data);
+    fuzz_table::<ui_sound_lookups::UISoundLookups>(data);
+    fuzz_table::<unit_blood::UnitBlood>(data);
+    fuzz_table::<unit_blood_levels::UnitBloodLevels>(data);
+    fuzz_table::<video_hardware::VideoHardware>(data);
+    fuzz_table::<vocal_ui_sounds::VocalUISounds>(data);
+    fuzz_table::<wmo_area_table::WMOAreaTable>(data);
+    fuzz_table::<weapon_impact_sounds::WeaponImpactSounds>(data);
+    fuzz_table::<weapon_swing_sounds2::WeaponSwingSounds2>(data);
+    fuzz_table::<world_map_area::WorldMapArea>(data);
+    fuzz_table::<world_map_continent::WorldMapContinent>(data);
+    fuzz_table::<world_map_overlay::WorldMapOverlay>(data);
+    fuzz_table::<world_safe_locs::WorldSafeLocs>(data);
+    fuzz_table::<world_state_ui::WorldStateUI>(data);
+    fuzz_table::<wow_error_strings::WowError_Strings>(data);
+    fuzz_table::<zone_intro_music_table::ZoneIntroMusicTable>(data);
+    fuzz_table::<zone_music::ZoneMusic>(data);
+});
@@ -0,0 +1,40 @@
+//! Shared code for the fuzz targets in `fuzz_targets`.
+//!
+//! The table targets are generated by `rxml` and call [`fuzz_table`] for every table of an expansion.
+//! Run with `cargo +nightly fuzz run vanilla_tables` from the repository root.
+
+use std::io::Cursor;
+use wow_dbc::{DbcTable, DbcTableRef};
+
+/// Reads `data` as `T` through every reading API. None of them may panic.
+pub fn fuzz_table<T: DbcTable>(data: &[u8])
+where
+    T::Row: Clone,
+{
+    if let Ok(table) = T::read(&mut &data[..]) {
+        let mut v = Vec::new();
+        table.write(&mut v).unwrap();
+    }
+
+    if let Ok(borrowed) = DbcTableRef::<T>::new(data) {
+        for row in borrowed.iter() {
+            let _ = row;
+        }
+    }
+
+    if let Ok(lenient) = T::read_lenient(&mut &data[..]) {
+        let mut v = Vec::new();
+        lenient.write(&mut v).unwrap();
+    }
+
+    if let Ok(preserved) = T::read_preserving(&mut &data[..]) {
+        let mut v = Vec::new();
+        preserved.write(&mut v).unwrap();
+    }
+
+    if let Ok(rows) = T::iter_rows(Cursor::new(data)) {
+        for row in rows {
+            let _ = row;
+        }
+    }
+}
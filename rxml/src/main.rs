@@ -5,7 +5,7 @@ pub(crate) mod types;
 pub(crate) mod writer;
 
 use crate::file_utils::overwrite_if_not_same_contents;
-use crate::rust_printer::sqlite_converter;
+use crate::rust_printer::{any_table, any_table_test, database, fuzz_target, sqlite_converter, table_list};
 use crate::types::{DbcDescription, Field, Type};
 use crate::writer::Writer;
 use std::path::PathBuf;
@@ -29,6 +29,13 @@ fn table_location(version: DbcVersion) -> PathBuf {
         .join(version.module_name())
 }
 
+fn fuzz_target_location(version: DbcVersion) -> PathBuf {
+    workspace_directory()
+        .join("fuzz")
+        .join("fuzz_targets")
+        .join(format!("{}.rs", version.module_name()))
+}
+
 fn converter_location(version: DbcVersion, ty: &str) -> PathBuf {
     let version = version.module_name();
     workspace_directory()
@@ -100,6 +107,10 @@ fn main() {
         for module in modules {
             module_file.wln(format!("pub mod {};", module));
         }
+        table_list(&mut module_file, o.descriptions(), version);
+        any_table(&mut module_file, o.descriptions());
+        database(&mut module_file, o.descriptions(), &o);
+        any_table_test(&mut module_file, o.descriptions());
 
         let mut mod_rs_path = table_location(version);
         mod_rs_path.push("mod.rs");
@@ -107,7 +118,10 @@ fn main() {
 
         let sqlite_conversion = sqlite_converter(o.descriptions(), version, &o);
         let file_path = converter_location(version, "sqlite");
-        overwrite_if_not_same_contents(sqlite_conversion.inner(), &file_path)
+        overwrite_if_not_same_contents(sqlite_conversion.inner(), &file_path);
+
+        let fuzz = fuzz_target(o.descriptions(), version);
+        overwrite_if_not_same_contents(fuzz.inner(), &fuzz_target_location(version));
     }
 }
 
@@ -126,11 +126,14 @@ fn parse_type(
             if ty.contains('[') {
                 let (ty, size) = ty.split_once('[').unwrap();
                 let size: i32 = size.replace(']', "").parse().unwrap();
-
-                return Type::Array(Box::new(Array::new(
-                    parse_type(ty, &None, enums, flags, version),
-                    size,
-                )));
+                let ty = parse_type(ty, &None, enums, flags, version);
+
+                return Type::Array(Box::new(match key {
+                    Some(Type::ForeignKey { table, .. }) => {
+                        Array::with_foreign_key(ty, size, table)
+                    }
+                    _ => Array::new(ty, size),
+                }));
             }
 
             if let Some(e) = enums.iter().find(|a| a.name() == ty) {
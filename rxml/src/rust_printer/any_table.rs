@@ -0,0 +1,144 @@
+use crate::{DbcDescription, Writer};
+use heck::ToSnakeCase;
+
+pub fn any_table(s: &mut Writer, descriptions: &[DbcDescription]) {
+    s.newline();
+    s.wln("use crate::{DbcError, DbcTable};");
+    s.wln("use crate::io::{Read, Write};");
+    s.newline();
+
+    s.wln("/// Any table in this module.");
+    s.wln("///");
+    s.wln("/// Created with [`AnyTable::read_by_filename`] for files that are only known at runtime.");
+    s.wln("#[derive(Debug, Clone, PartialEq)]");
+    s.wln("#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize))]");
+    s.bodyn("pub enum AnyTable", |s| {
+        for d in descriptions {
+            s.wln(format!("{ty}({module}::{ty}),", ty = d.name(), module = module(d)));
+        }
+    });
+
+    s.bodyn("impl AnyTable", |s| {
+        read_by_filename(s, descriptions);
+        s.newline();
+
+        s.wln("/// Name of the DBC file _with_ `.dbc` at the end, same as [`DbcTable::FILENAME`].");
+        s.open_curly("pub const fn filename(&self) -> &'static str");
+        s.open_curly("match self");
+        for d in descriptions {
+            s.wln(format!(
+                "Self::{ty}(_) => {module}::{ty}::FILENAME,",
+                ty = d.name(),
+                module = module(d),
+            ));
+        }
+        s.closing_curly(); // match self
+        s.closing_curly(); // fn filename
+        s.newline();
+
+        s.wln("/// Amount of rows in the table.");
+        s.open_curly("pub fn len(&self) -> usize");
+        s.open_curly("match self");
+        for d in descriptions {
+            s.wln(format!("Self::{}(t) => t.rows().len(),", d.name()));
+        }
+        s.closing_curly(); // match self
+        s.closing_curly(); // fn len
+        s.newline();
+
+        s.wln("/// Returns `true` if the table has no rows.");
+        s.body("pub fn is_empty(&self) -> bool", |s| {
+            s.wln("self.len() == 0");
+        });
+        s.newline();
+
+        s.wln("/// Write to bytes, same as [`DbcTable::write`].");
+        s.wln("///");
+        s.wln("/// # Errors");
+        s.wln("///");
+        s.wln("/// Returns the same errors as [`Write::write_all`].");
+        s.open_curly("pub fn write(&self, w: &mut impl Write) -> Result<(), crate::io::Error>");
+        s.open_curly("match self");
+        for d in descriptions {
+            s.wln(format!("Self::{}(t) => t.write(w),", d.name()));
+        }
+        s.closing_curly(); // match self
+        s.closing_curly(); // fn write
+    });
+}
+
+fn read_by_filename(s: &mut Writer, descriptions: &[DbcDescription]) {
+    s.wln("/// Reads the table with the [`DbcTable::FILENAME`] that matches `filename`.");
+    s.wln("///");
+    s.wln("/// `filename` is matched with [`TableInfo::matches_filename`], so directories and case are ignored.");
+    s.wln("/// Returns `Ok(None)` if no table matches.");
+    s.wln("///");
+    s.wln("/// # Errors");
+    s.wln("///");
+    s.wln("/// Returns the same errors as [`DbcTable::read`].");
+    s.open_curly(
+        "pub fn read_by_filename(filename: &str, b: &mut impl Read) -> Result<Option<Self>, DbcError>",
+    );
+
+    s.open_curly("let Some(table) = TABLES.iter().find(|a| a.matches_filename(filename)) else");
+    s.wln("return Ok(None);");
+    s.closing_curly_with(";");
+    s.newline();
+
+    s.open_curly("Ok(Some(match table.filename");
+    for d in descriptions {
+        s.wln(format!(
+            "\"{ty}.dbc\" => Self::{ty}({module}::{ty}::read(b)?),",
+            ty = d.name(),
+            module = module(d),
+        ));
+    }
+    s.wln("_ => return Ok(None),");
+    s.closing_curly_with("))");
+
+    s.closing_curly(); // fn read_by_filename
+}
+
+pub fn any_table_test(s: &mut Writer, descriptions: &[DbcDescription]) {
+    let Some(d) = descriptions.first() else {
+        return;
+    };
+    let ty = d.name();
+
+    s.newline();
+    s.wln("#[cfg(test)]");
+    s.open_curly("mod test");
+    s.wln("use super::AnyTable;");
+    s.wln(format!("use super::{module}::{ty};", module = module(d)));
+    s.wln("use crate::DbcTable;");
+    s.newline();
+
+    s.wln("#[test]");
+    s.open_curly("fn any_table()");
+    s.wln(format!("let table = {ty}::from_rows(Vec::new());"));
+    s.wln("let mut b = Vec::new();");
+    s.wln("table.write(&mut b).unwrap();");
+    s.newline();
+
+    s.wln(format!(
+        "let filename = format!(\"DBFilesClient\\\\{{}}\", {ty}::FILENAME.to_lowercase());"
+    ));
+    s.wln("let any = AnyTable::read_by_filename(&filename, &mut b.as_slice()).unwrap().unwrap();");
+    s.wln(format!("assert_eq!(any.filename(), {ty}::FILENAME);"));
+    s.wln("assert!(any.is_empty());");
+    s.wln(format!("assert_eq!(any, AnyTable::{ty}(table));"));
+    s.newline();
+
+    s.wln("let mut written = Vec::new();");
+    s.wln("any.write(&mut written).unwrap();");
+    s.wln("assert_eq!(written, b);");
+    s.newline();
+
+    s.wln("assert!(AnyTable::read_by_filename(\"Unknown.dbc\", &mut b.as_slice()).unwrap().is_none());");
+    s.closing_curly(); // fn any_table
+    s.closing_curly(); // mod test
+}
+
+fn module(d: &DbcDescription) -> String {
+    d.name().to_snake_case()
+}
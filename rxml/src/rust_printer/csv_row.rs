@@ -0,0 +1,242 @@
+use crate::types::{extended_string_ref_loc_members, string_ref_loc_members, Definer, Field, Type};
+use crate::{DbcDescription, Objects, Writer};
+use heck::ToShoutySnakeCase;
+use std::collections::BTreeMap;
+
+pub(crate) fn create_csv_row(s: &mut Writer, d: &DbcDescription, o: &Objects) {
+    let mut definers = BTreeMap::new();
+    for field in d.fields() {
+        let ty = match field.ty() {
+            Type::Array(array) => array.ty(),
+            ty => ty,
+        };
+
+        if let Type::Enum(en) | Type::Flag(en) = ty {
+            definers.insert(en.name(), en);
+        }
+    }
+
+    for en in definers.values() {
+        s.wln("#[cfg(feature = \"csv\")]");
+        s.w(format!("const {}: &[(&str, i64)] = &[", names(en)));
+        for (i, enumerator) in en.enumerators().iter().enumerate() {
+            if i != 0 {
+                s.w_no_indent(", ");
+            }
+            s.w_no_indent(format!(
+                "(\"{}\", {})",
+                enumerator.name(),
+                enumerator.value()
+            ));
+        }
+        s.wln_no_indent("];");
+        s.newline();
+    }
+
+    let columns = d.fields().iter().flat_map(columns).collect::<Vec<_>>();
+
+    s.wln("#[cfg(feature = \"csv\")]");
+    s.bodyn(format!("impl crate::CsvRow for {}Row", d.name()), |s| {
+        s.w("const COLUMNS: &'static [&'static str] = &[");
+        for (i, column) in columns.iter().enumerate() {
+            if i != 0 {
+                s.w_no_indent(", ");
+            }
+            s.w_no_indent(format!("\"{}\"", column.name));
+        }
+        s.wln_no_indent("];");
+        s.newline();
+
+        s.bodyn("fn to_csv_record(&self) -> Vec<String>", |s| {
+            s.wln("vec![");
+            s.inc_indent();
+            for column in &columns {
+                s.wln(format!("{},", to_csv(column.ty, &column.access, o)));
+            }
+            s.dec_indent();
+            s.wln("]");
+        });
+
+        s.body(
+            "fn from_csv_record(record: &[&str]) -> Result<Self, crate::CsvError>",
+            |s| {
+                s.w("let [");
+                for (i, column) in columns.iter().enumerate() {
+                    if i != 0 {
+                        s.w_no_indent(", ");
+                    }
+                    s.w_no_indent(&column.name);
+                }
+                s.wln_no_indent("] = record else {");
+                s.inc_indent();
+                s.wln(format!(
+                    "return Err(crate::csv_table::column_count({}, record.len()));",
+                    columns.len()
+                ));
+                s.dec_indent();
+                s.wln("};");
+                s.newline();
+
+                s.open_curly("Ok(Self");
+                for field in d.fields() {
+                    let value = match field.ty() {
+                        Type::StringRefLoc | Type::ExtendedStringRefLoc => {
+                            let (ty, members) = if matches!(field.ty(), Type::StringRefLoc) {
+                                ("LocalizedString", string_ref_loc_members())
+                            } else {
+                                ("ExtendedLocalizedString", extended_string_ref_loc_members())
+                            };
+
+                            let mut value = format!("crate::{ty} {{ ");
+                            for member in members {
+                                let column = format!("{}_{member}", field.name());
+                                let v = if *member == "flags" {
+                                    from_csv(&Type::U32, &column, o)
+                                } else {
+                                    from_csv(&Type::StringRef, &column, o)
+                                };
+                                value.push_str(&format!("{member}: {v}, "));
+                            }
+                            value.push('}');
+
+                            value
+                        }
+                        Type::Array(array) => {
+                            let elements = (0..array.size())
+                                .map(|i| from_csv(array.ty(), &format!("{}_{i}", field.name()), o))
+                                .collect::<Vec<_>>();
+
+                            format!("[{}]", elements.join(", "))
+                        }
+                        ty => from_csv(ty, field.name(), o),
+                    };
+
+                    s.wln(format!("{name}: {value},", name = field.name()));
+                }
+                s.closing_curly_with(")");
+            },
+        );
+    });
+}
+
+/// A single column of a field, named the same as in `sqlite_converter`.
+struct Column<'a> {
+    name: String,
+    /// Expression for the value of the column in `self`.
+    access: String,
+    ty: &'a Type,
+}
+
+fn columns(field: &Field) -> Vec<Column<'_>> {
+    let name = field.name();
+    match field.ty() {
+        Type::StringRefLoc | Type::ExtendedStringRefLoc => {
+            let members = if matches!(field.ty(), Type::StringRefLoc) {
+                string_ref_loc_members()
+            } else {
+                extended_string_ref_loc_members()
+            };
+
+            members
+                .iter()
+                .map(|member| Column {
+                    name: format!("{name}_{member}"),
+                    access: format!("self.{name}.{member}"),
+                    ty: if *member == "flags" {
+                        &Type::U32
+                    } else {
+                        &Type::StringRef
+                    },
+                })
+                .collect()
+        }
+        Type::Array(array) => (0..array.size())
+            .map(|i| Column {
+                name: format!("{name}_{i}"),
+                access: format!("self.{name}[{i}]"),
+                ty: array.ty(),
+            })
+            .collect(),
+        ty => vec![Column {
+            name: name.to_string(),
+            access: format!("self.{name}"),
+            ty,
+        }],
+    }
+}
+
+/// Name of the constant with the names and values of the enumerators.
+fn names(en: &Definer) -> String {
+    format!("{}_NAMES", en.name().to_shouty_snake_case())
+}
+
+/// Converts the field `v` into the string written to the CSV file.
+fn to_csv(ty: &Type, v: &str, o: &Objects) -> String {
+    match ty {
+        Type::I8 | Type::I16 | Type::I32 | Type::U8 | Type::U16 | Type::U32 | Type::Float => {
+            format!("{v}.to_string()")
+        }
+        Type::Bool | Type::Bool32 => format!("u8::from({v}).to_string()"),
+        Type::StringRef => format!("{v}.clone()"),
+        Type::Enum(en) => format!(
+            "crate::csv_table::enumerator_name({v}.as_int().into(), {})",
+            names(en)
+        ),
+        Type::Flag(en) => format!(
+            "crate::csv_table::flag_names({v}.as_int().into(), {})",
+            names(en)
+        ),
+        Type::PrimaryKey { .. } => format!("{v}.id.to_string()"),
+        Type::ForeignKey { table, .. } => {
+            if o.table_exists(table) {
+                format!("{v}.id.to_string()")
+            } else {
+                format!("{v}.to_string()")
+            }
+        }
+        Type::StringRefLoc | Type::ExtendedStringRefLoc | Type::Array(_) => {
+            unreachable!("localized strings and arrays are split into columns")
+        }
+    }
+}
+
+/// Parses the column variable `column` into the type of the field.
+fn from_csv(ty: &Type, column: &str, o: &Objects) -> String {
+    match ty {
+        Type::I8 | Type::I16 | Type::I32 | Type::U8 | Type::U16 | Type::U32 => {
+            format!("crate::csv_table::int(\"{column}\", {column})?")
+        }
+        Type::Bool | Type::Bool32 => format!("crate::csv_table::bool(\"{column}\", {column})?"),
+        Type::Float => format!("crate::csv_table::float(\"{column}\", {column})?"),
+        Type::StringRef => format!("{column}.to_string()"),
+        Type::Enum(en) => format!(
+            "crate::csv_table::enumeration::<_, {ty}>(\"{column}\", {column}, {names})?",
+            ty = en.ty().rust_str(),
+            names = names(en),
+        ),
+        // flags are bit patterns, so they are parsed as unsigned
+        Type::Flag(en) => format!(
+            "{name}::new(crate::csv_table::flags::<{ty}>(\"{column}\", {column}, {names})? as _)",
+            name = en.name(),
+            ty = en.ty().rust_str().replace('i', "u"),
+            names = names(en),
+        ),
+        Type::PrimaryKey { .. } => format!(
+            "{key}::new(crate::csv_table::int(\"{column}\", {column})?)",
+            key = ty.rust_str(),
+        ),
+        Type::ForeignKey { table, .. } => {
+            if o.table_exists(table) {
+                format!(
+                    "{key}::new(crate::csv_table::int(\"{column}\", {column})?)",
+                    key = ty.rust_str(),
+                )
+            } else {
+                format!("crate::csv_table::int(\"{column}\", {column})?")
+            }
+        }
+        Type::StringRefLoc | Type::ExtendedStringRefLoc | Type::Array(_) => {
+            unreachable!("localized strings and arrays are split into columns")
+        }
+    }
+}
@@ -0,0 +1,420 @@
+use crate::types::Type;
+use crate::{DbcDescription, Objects, Writer};
+use heck::ToSnakeCase;
+
+pub fn database(s: &mut Writer, descriptions: &[DbcDescription], o: &Objects) {
+    s.newline();
+    s.wln("#[cfg(feature = \"std\")]");
+    s.wln("pub use database::*;");
+    s.newline();
+
+    s.wln("// reading tables from a directory requires `std`");
+    s.wln("#[cfg(feature = \"std\")]");
+    s.body("mod database", |s| {
+        database_items(s, descriptions, o);
+    });
+}
+
+fn database_items(s: &mut Writer, descriptions: &[DbcDescription], o: &Objects) {
+    s.wln("use super::*;");
+    s.wln("use crate::{BrokenReference, DbcDirectory, ReferenceCheck, ReferenceChecker, Referrer, TableKey};");
+    s.wln("use std::any::Any;");
+    s.wln("use std::path::Path;");
+    s.wln("use std::sync::OnceLock;");
+    s.newline();
+
+    s.wln("/// Every table in this module from a [`DbcDirectory`], read the first time they are used.");
+    s.wln("///");
+    s.wln("/// Tables are read with [`DbcDatabase::get`] or the function named after the table, and are cached afterwards.");
+    s.wln("#[derive(Debug)]");
+    s.bodyn("pub struct DbcDatabase", |s| {
+        s.wln("directory: DbcDirectory,");
+        for d in descriptions {
+            s.wln(format!(
+                "{module}: OnceLock<{module}::{ty}>,",
+                module = d.name().to_snake_case(),
+                ty = d.name(),
+            ));
+        }
+    });
+
+    s.bodyn("impl DbcDatabase", |s| {
+        s.wln("/// Lists the DBC files in `path`, usually the extracted `DBFilesClient` directory. No tables are read.");
+        s.wln("///");
+        s.wln("/// # Errors");
+        s.wln("///");
+        s.wln("/// Returns the same errors as [`DbcDirectory::open`].");
+        s.body(
+            "pub fn open(path: impl AsRef<Path>) -> Result<Self, std::io::Error>",
+            |s| {
+                s.wln("Ok(Self::new(DbcDirectory::open(path)?))");
+            },
+        );
+        s.newline();
+
+        s.wln("/// Creates a database from an already opened directory.");
+        s.open_curly("pub const fn new(directory: DbcDirectory) -> Self");
+        s.open_curly("Self");
+        s.wln("directory,");
+        for d in descriptions {
+            s.wln(format!("{}: OnceLock::new(),", d.name().to_snake_case()));
+        }
+        s.closing_curly(); // Self
+        s.closing_curly(); // fn new
+        s.newline();
+
+        s.wln("/// The directory that tables are read from.");
+        s.body("pub const fn directory(&self) -> &DbcDirectory", |s| {
+            s.wln("&self.directory");
+        });
+        s.newline();
+
+        s.wln("/// Returns table `T`, reading it if it has not been read yet.");
+        s.wln("///");
+        s.wln("/// # Errors");
+        s.wln("///");
+        s.wln("/// Returns the same errors as [`DbcDirectory::read`]. Tables that fail to read are not cached.");
+        s.body(
+            "pub fn get<T: DatabaseTable>(&self) -> Result<&T, DbcError>",
+            |s| {
+                s.wln("self.directory.get_or_read(T::cell(self))");
+            },
+        );
+        s.newline();
+
+        s.wln("/// Same as [`DbcDatabase::get`], except that it returns `Ok(None)` if the directory does not have a file for `T`.");
+        s.wln("///");
+        s.wln("/// # Errors");
+        s.wln("///");
+        s.wln("/// Returns the same errors as [`DbcDatabase::get`], except for missing files.");
+        s.body(
+            "pub fn get_if_exists<T: DatabaseTable>(&self) -> Result<Option<&T>, DbcError>",
+            |s| {
+                s.body("if T::cell(self).get().is_none() && self.directory.find(&TableInfo::new::<T>(crate::Expansion::Vanilla)).is_none()", |s| {
+                    s.wln("return Ok(None);");
+                });
+                s.newline();
+                s.wln("self.get().map(Some)");
+            },
+        );
+        s.newline();
+
+        s.wln("/// Reads every table that has a file in the directory, so that later calls do not read any files.");
+        s.wln("///");
+        s.wln("/// With the `rayon` feature the files are read in parallel.");
+        s.wln("///");
+        s.wln("/// # Errors");
+        s.wln("///");
+        s.wln("/// Returns the same errors as [`DbcDatabase::get`] for the first table in [`TABLES`] that fails to read.");
+        s.wln("/// Tables that have already been read stay cached.");
+        s.open_curly("pub fn read_all(&self) -> Result<(), DbcError>");
+        s.wln("crate::database::read_each(self, &[");
+        s.inc_indent();
+        for d in descriptions {
+            s.wln(format!(
+                "read_table::<{module}::{ty}>,",
+                module = d.name().to_snake_case(),
+                ty = d.name(),
+            ));
+        }
+        s.dec_indent();
+        s.wln("])");
+        s.closing_curly(); // fn read_all
+        s.newline();
+
+        s.wln("/// Returns the row that `key` references, reading the table if it has not been read yet.");
+        s.wln("///");
+        s.wln("/// Returns `Ok(None)` without reading the table if the key is 0, see [`TableKey`].");
+        s.wln("///");
+        s.wln("/// # Errors");
+        s.wln("///");
+        s.wln("/// Returns the same errors as [`DbcDatabase::get`].");
+        s.body(
+            "pub fn resolve<K>(&self, key: K) -> Result<Option<&<K::Table as DbcTable>::Row>, DbcError> where K: TableKey, K::Table: DatabaseTable",
+            |s| {
+                s.body("if key.is_none()", |s| {
+                    s.wln("return Ok(None);");
+                });
+                s.newline();
+                s.wln("Ok(key.resolve(self.get::<K::Table>()?))");
+            },
+        );
+        s.newline();
+
+        check_references(s, descriptions, o);
+        s.newline();
+
+        referrers_of(s, descriptions, o);
+        s.newline();
+
+        s.wln("/// Tables that do not have a file in the directory.");
+        s.body("pub fn missing(&self) -> Vec<&'static TableInfo>", |s| {
+            s.wln("self.directory.missing(TABLES)");
+        });
+        s.newline();
+
+        s.wln("/// Files in the directory that are not a table in this module.");
+        s.body("pub fn unknown(&self) -> Vec<&Path>", |s| {
+            s.wln("self.directory.unknown(TABLES)");
+        });
+
+        for d in descriptions {
+            s.newline();
+            s.wln(format!(
+                "/// Returns [`{ty}`]({module}::{ty}), reading it if it has not been read yet.",
+                ty = d.name(),
+                module = d.name().to_snake_case(),
+            ));
+            s.wln("///");
+            s.wln("/// # Errors");
+            s.wln("///");
+            s.wln("/// Returns the same errors as [`DbcDatabase::get`].");
+            s.body(
+                format!(
+                    "pub fn {module}(&self) -> Result<&{module}::{ty}, DbcError>",
+                    module = d.name().to_snake_case(),
+                    ty = d.name(),
+                ),
+                |s| {
+                    s.wln("self.get()");
+                },
+            );
+        }
+    });
+
+    s.wln("/// Reads `T` for [`DbcDatabase::read_all`] if it has a file.");
+    s.body(
+        "fn read_table<T: DatabaseTable>(db: &DbcDatabase) -> Result<(), DbcError>",
+        |s| {
+            s.wln("db.get_if_exists::<T>().map(|_| ())");
+        },
+    );
+    s.newline();
+
+    s.wln("/// Tables that can be read with [`DbcDatabase::get`].");
+    s.bodyn("pub trait DatabaseTable: DbcTable + 'static", |s| {
+        s.wln("/// Where the table is cached in `db`.");
+        s.wln("fn cell(db: &DbcDatabase) -> &OnceLock<Self>;");
+    });
+
+    for d in descriptions {
+        s.bodyn(
+            format!(
+                "impl DatabaseTable for {module}::{ty}",
+                module = d.name().to_snake_case(),
+                ty = d.name(),
+            ),
+            |s| {
+                s.body("fn cell(db: &DbcDatabase) -> &OnceLock<Self>", |s| {
+                    s.wln(format!("&db.{}", d.name().to_snake_case()));
+                });
+            },
+        );
+    }
+}
+
+/// Field of a table that is a foreign key to a table that exists.
+struct ForeignKey<'a> {
+    field: &'a str,
+    target: &'a str,
+    /// Integer type of the field, or of the elements for arrays.
+    ty: String,
+    /// Integer type of the primary key of `target` for arrays, which are not stored as keys.
+    array_key_ty: Option<String>,
+}
+
+/// Fields of each table that are foreign keys to a table that exists, including arrays of foreign keys.
+fn foreign_keys<'a>(d: &'a DbcDescription, o: &Objects) -> Vec<ForeignKey<'a>> {
+    d.fields()
+        .iter()
+        .filter_map(|field| match field.ty() {
+            Type::ForeignKey { table, ty } if o.table_exists(table) => Some(ForeignKey {
+                field: field.name(),
+                target: table.as_str(),
+                ty: ty.rust_str(),
+                array_key_ty: None,
+            }),
+            Type::Array(array) => {
+                let target = array.foreign_key()?;
+                let (_, key_ty) = o.table_primary_key_ty(target)?;
+
+                Some(ForeignKey {
+                    field: field.name(),
+                    target,
+                    ty: array.ty().rust_str(),
+                    array_key_ty: Some(key_ty.rust_str()),
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn row_id(d: &DbcDescription) -> String {
+    match d.primary_key() {
+        Some((key, _)) => format!("Some(row.{}.id.into())", key.name()),
+        None => "None".to_string(),
+    }
+}
+
+fn referrers_of(s: &mut Writer, descriptions: &[DbcDescription], o: &Objects) {
+    s.wln("/// Finds every row that has a foreign key to `key`, reading every table that can reference it.");
+    s.wln("///");
+    s.wln(
+        "/// Returns an empty list without reading any tables if the key is 0, see [`TableKey`].",
+    );
+    s.wln("/// Tables that do not have a file in the directory are skipped.");
+    s.wln("/// Every element of arrays of foreign keys is searched, with the index of the element in [`Referrer::index`].");
+    s.wln("///");
+    s.wln("/// # Errors");
+    s.wln("///");
+    s.wln("/// Returns the same errors as [`DbcDatabase::get`] for tables that fail to read.");
+    s.open_curly("pub fn referrers_of<K: TableKey + 'static>(&self, key: K) -> Result<Vec<Referrer>, DbcError>");
+    s.wln("let mut referrers = Vec::new();");
+    s.body("if key.is_none()", |s| {
+        s.wln("return Ok(referrers);");
+    });
+
+    let mut targets = descriptions
+        .iter()
+        .flat_map(|d| foreign_keys(d, o).into_iter().map(|a| a.target))
+        .collect::<Vec<_>>();
+    targets.sort();
+    targets.dedup();
+
+    for target in targets {
+        s.newline();
+        s.open_curly(format!(
+            "if let Some(key) = (&key as &dyn Any).downcast_ref::<{module}::{target}Key>()",
+            module = target.to_snake_case(),
+        ));
+
+        for d in descriptions {
+            let fields = foreign_keys(d, o)
+                .into_iter()
+                .filter(|a| a.target == target)
+                .collect::<Vec<_>>();
+            if fields.is_empty() {
+                continue;
+            }
+
+            let module = d.name().to_snake_case();
+            s.open_curly(format!(
+                "if let Some(table) = self.get_if_exists::<{module}::{ty}>()?",
+                ty = d.name(),
+            ));
+            s.open_curly("for (i, row) in table.rows().iter().enumerate()");
+            for foreign_key in fields {
+                let field = foreign_key.field;
+                let push = |s: &mut Writer, index: &str| {
+                    s.wln(format!(
+                        "referrers.push(Referrer {{ table: {module}::{name}::FILENAME, row: i, id: {id}, field: \"{field}\", index: {index} }});",
+                        name = d.name(),
+                        id = row_id(d),
+                    ));
+                };
+
+                match &foreign_key.array_key_ty {
+                    None => {
+                        s.open_curly(format!("if row.{field} == *key"));
+                        push(s, "None");
+                        s.closing_curly();
+                    }
+                    Some(key_ty) => {
+                        s.open_curly(format!(
+                            "for (j, element) in row.{field}.iter().enumerate()"
+                        ));
+                        s.open_curly(format!(
+                            "if {target_module}::{target}Key::new(*element as {key_ty}) == *key",
+                            target_module = target.to_snake_case(),
+                        ));
+                        push(s, "Some(j)");
+                        s.closing_curly(); // if
+                        s.closing_curly(); // for element
+                    }
+                }
+            }
+            s.closing_curly(); // for row
+            s.closing_curly(); // if let Some(table)
+        }
+
+        s.closing_curly(); // if let Some(key)
+    }
+
+    s.newline();
+    s.wln("Ok(referrers)");
+    s.closing_curly(); // fn referrers_of
+}
+
+fn check_references(s: &mut Writer, descriptions: &[DbcDescription], o: &Objects) {
+    s.wln("/// Finds every foreign key that references a row that does not exist, reading every table in the directory.");
+    s.wln("///");
+    s.wln("/// Tables that do not have a file in the directory are skipped, both as the source and the target of foreign keys.");
+    s.wln("/// Every element of arrays of foreign keys is checked, with the index of the element in [`BrokenReference::index`].");
+    s.wln("///");
+    s.wln("/// # Errors");
+    s.wln("///");
+    s.wln("/// Returns the same errors as [`DbcDatabase::get`] for tables that fail to read.");
+    s.open_curly("pub fn check_references(&self, options: ReferenceCheck) -> Result<Vec<BrokenReference>, DbcError>");
+    s.wln("let mut checker = ReferenceChecker::new(options);");
+
+    for d in descriptions {
+        let foreign_keys = foreign_keys(d, o);
+        if foreign_keys.is_empty() {
+            continue;
+        }
+
+        let module = d.name().to_snake_case();
+        s.newline();
+        s.open_curly(format!(
+            "if let Some(table) = self.get_if_exists::<{module}::{ty}>()?",
+            ty = d.name(),
+        ));
+
+        let mut targets = foreign_keys.iter().map(|a| a.target).collect::<Vec<_>>();
+        targets.sort();
+        targets.dedup();
+        for target in &targets {
+            s.wln(format!(
+                "let {target_module} = self.get_if_exists::<{target_module}::{target}>()?;",
+                target_module = target.to_snake_case(),
+            ));
+        }
+        s.newline();
+
+        let id = row_id(d);
+
+        s.open_curly("for (i, row) in table.rows().iter().enumerate()");
+        for ForeignKey {
+            field,
+            target,
+            ty,
+            array_key_ty,
+        } in &foreign_keys
+        {
+            let target_module = target.to_snake_case();
+            let name = d.name();
+
+            match array_key_ty {
+                None => {
+                    s.wln(format!(
+                        "checker.check({target_module}, {module}::{name}::FILENAME, i, {id}, \"{field}\", None, row.{field}, (row.{field}.id as {ty}).into());",
+                    ));
+                }
+                Some(key_ty) => {
+                    s.body(format!("for (j, element) in row.{field}.iter().enumerate()"), |s| {
+                        s.wln(format!(
+                            "checker.check({target_module}, {module}::{name}::FILENAME, i, {id}, \"{field}\", Some(j), {target_module}::{target}Key::new(*element as {key_ty}), (*element).into());",
+                        ));
+                    });
+                }
+            }
+        }
+        s.closing_curly(); // for row
+        s.closing_curly(); // if let Some(table)
+    }
+
+    s.newline();
+    s.wln("Ok(checker.finish())");
+    s.closing_curly(); // fn check_references
+}
@@ -0,0 +1,28 @@
+use crate::{DbcDescription, DbcVersion, Writer};
+use heck::ToSnakeCase;
+
+pub fn fuzz_target(descriptions: &[DbcDescription], version: DbcVersion) -> Writer {
+    let mut s = Writer::new_no_name();
+
+    s.wln("#![no_main]");
+    s.newline();
+    s.wln("use libfuzzer_sys::fuzz_target;");
+    s.wln("use wow_dbc_fuzz::fuzz_table;");
+    s.wln(format!(
+        "use wow_dbc::{version}::*;",
+        version = version.module_name(),
+    ));
+    s.newline();
+
+    s.open_curly("fuzz_target!(|data: &[u8]|");
+    for description in descriptions {
+        s.wln(format!(
+            "fuzz_table::<{module}::{ty}>(data);",
+            module = description.name().to_snake_case(),
+            ty = description.name(),
+        ));
+    }
+    s.closing_curly_with(");");
+
+    s
+}
@@ -16,7 +16,9 @@ pub fn create_main_ty(s: &mut Writer, d: &DbcDescription, o: &Objects) {
     s.bodyn(format!("impl DbcTable for {name}"), |s| {
         create_types(s, d);
 
-        create_read(s, d, o);
+        create_read(s, d);
+
+        create_read_row_ref(s, d, o);
 
         create_write(s, d, o);
     });
@@ -30,6 +32,11 @@ pub fn create_main_ty(s: &mut Writer, d: &DbcDescription, o: &Objects) {
 
 fn create_types(s: &mut Writer, d: &DbcDescription) {
     s.wln(format!("type Row = {}Row;", d.name()));
+    if d.contains_string() {
+        s.wln(format!("type RowRef<'a> = {}RowRef<'a>;", d.name()));
+    } else {
+        s.wln(format!("type RowRef<'a> = {}Row;", d.name()));
+    }
     s.newline();
 
     // add constants to make generated code more readable
@@ -45,31 +52,59 @@ fn create_types(s: &mut Writer, d: &DbcDescription) {
         "const ROW_SIZE: usize = {};",
         d.row_size()
     ));
+    s.wln(format!(
+        "const STRING_REFERENCE_OFFSETS: &'static [usize] = &[{}];",
+        string_reference_offsets(d)
+            .iter()
+            .map(|a| a.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    ));
     s.newline();
 
     s.wln("fn rows(&self) -> &[Self::Row] { &self.rows }");
     s.wln("fn rows_mut(&mut self) -> &mut [Self::Row] { &mut self.rows }");
+    s.wln("fn rows_vec_mut(&mut self) -> &mut Vec<Self::Row> { &mut self.rows }");
+    s.wln("fn from_rows(rows: Vec<Self::Row>) -> Self { Self { rows } }");
     s.newline();
 }
 
+fn string_reference_offsets(d: &DbcDescription) -> Vec<usize> {
+    fn offsets(ty: &Type, offset: usize, v: &mut Vec<usize>) {
+        match ty {
+            Type::StringRef => v.push(offset),
+            // the flags after the strings are not references
+            Type::StringRefLoc | Type::ExtendedStringRefLoc => {
+                let strings = ty.row_size_count() / 4 - 1;
+                v.extend((0..strings).map(|i| offset + i * 4));
+            }
+            Type::Array(array) => {
+                let size = array.ty().row_size_count();
+                for i in 0..array.size() as usize {
+                    offsets(array.ty(), offset + i * size, v);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut v = Vec::new();
+    let mut offset = 0;
+    for field in d.fields() {
+        offsets(field.ty(), offset, &mut v);
+        offset += field.ty().row_size_count();
+    }
+
+    v
+}
+
 fn create_write(s: &mut Writer, d: &DbcDescription, o: &Objects) {
-    s.open_curly("fn write(&self, w: &mut impl Write) -> Result<(), std::io::Error>");
+    s.open_curly("fn write_with(&self, w: &mut impl Write, string_cache: &mut StringCache) -> Result<(), crate::io::Error>");
 
     // header requires the string block size, which we don't know until deduplicating the strings
     s.wln("let mut b = Vec::with_capacity(self.rows.len() * Self::ROW_SIZE);");
     s.newline();
 
-    // always allocating a string cache, even if not used, simplifies the code
-    s.wln(format!(
-        "let {} string_cache = StringCache::new();",
-        if d.contains_string() {
-            "mut"
-        } else {
-            ""
-        }
-    ));
-    s.newline();
-
     // write all rows into the buffer while building the string cache
     s.bodyn("for row in &self.rows", |s| {
         for field in d.fields() {
@@ -98,7 +133,7 @@ fn create_write(s: &mut Writer, d: &DbcDescription, o: &Objects) {
     s.wln("w.write_all(string_cache.buffer())?;");
 
     s.wln("Ok(())");
-    s.closing_curly_newline(); // pub fn write_to_bytes
+    s.closing_curly_newline(); // fn write_with
 }
 
 fn print_write_field_ty(s: &mut Writer, name: &str, ty: &Type, o: &Objects, prefix: &str, in_array: bool) {
@@ -157,7 +192,7 @@ fn print_write_field_ty(s: &mut Writer, name: &str, ty: &Type, o: &Objects, pref
         }
         Type::ExtendedStringRefLoc | Type::StringRefLoc => {
             s.wln(format!(
-                "b.write_all(&row.{name}.string_indices_as_array(&mut string_cache))?;"
+                "b.write_all(&row.{name}.string_indices_as_array(string_cache))?;"
             ));
         }
         Type::StringRef => {
@@ -192,12 +227,19 @@ fn print_write_field(s: &mut Writer, field: &Field, o: &Objects) {
 }
 
 fn create_index(s: &mut Writer, d: &DbcDescription) {
-    if d.primary_key().is_none() {
+    let Some((key, ty)) = d.primary_key() else {
         return;
-    }
+    };
 
     s.wln(format!("type PrimaryKey = {name}Key;", name = d.name()));
 
+    s.bodyn(
+        "fn primary_key(row: &Self::Row) -> Self::PrimaryKey",
+        |s| {
+            s.wln(format!("row.{}", key.name()));
+        },
+    );
+
     s.bodyn(
         "fn get(&self, key: impl TryInto<Self::PrimaryKey>) -> Option<&Self::Row>",
         |s| {
@@ -206,216 +248,183 @@ fn create_index(s: &mut Writer, d: &DbcDescription) {
         },
     );
 
-    s.body(
+    s.bodyn(
         "fn get_mut(&mut self, key: impl TryInto<Self::PrimaryKey>) -> Option<&mut Self::Row>",
         |s| {
             s.wln("let key = key.try_into().ok()?;");
             s.wln("self.rows.iter_mut().find(|a| a.id.id == key.id)");
         },
     );
-}
-
-fn create_read(s: &mut Writer, d: &DbcDescription, o: &Objects) {
-    s.open_curly("fn read(b: &mut impl std::io::Read) -> Result<Self, crate::DbcError>");
-
-    s.wln("let mut header = [0_u8; HEADER_SIZE];");
-    s.wln("b.read_exact(&mut header)?;");
-    s.wln("let header = parse_header(&header)?;");
-    s.newline();
-
-    s.bodyn("if header.record_size != Self::ROW_SIZE as u32", |s| {
-        s.wln("return Err(crate::DbcError::InvalidHeader(");
-        s.inc_indent();
 
-        s.open_curly("crate::InvalidHeaderError::RecordSize");
-        s.wln("expected: Self::ROW_SIZE as u32,");
-        s.wln("actual: header.record_size,");
-        s.closing_curly_with(","); // InvalidHeaderError::RecordSize
-
-        s.dec_indent();
-        s.wln("));"); // return Err(
+    // negative keys are not used, so signed keys start from 0 like unsigned keys
+    let largest = if matches!(ty, Type::I8 | Type::I16 | Type::I32) {
+        "self.rows.iter().map(|a| a.id.id).max().unwrap_or(0).max(0)"
+    } else {
+        "self.rows.iter().map(|a| a.id.id).max().unwrap_or(0)"
+    };
+    s.body("fn next_free_key(&self) -> Option<Self::PrimaryKey>", |s| {
+        s.wln(format!(
+            "{largest}.checked_add(1).map(Self::PrimaryKey::new)"
+        ));
     });
+}
 
-    s.bodyn(
-        "if header.field_count != Self::FIELD_COUNT as u32",
-        |s| {
-            s.wln("return Err(crate::DbcError::InvalidHeader(");
-            s.inc_indent();
-
-            s.open_curly("crate::InvalidHeaderError::FieldCount");
-            s.wln("expected: Self::FIELD_COUNT as u32,");
-            s.wln("actual: header.field_count,");
-            s.closing_curly_with(","); // InvalidHeaderError::RecordSize
+fn create_read(s: &mut Writer, d: &DbcDescription) {
+    s.open_curly("fn read(b: &mut impl crate::io::Read) -> Result<Self, crate::DbcError>");
 
-            s.dec_indent();
-            s.wln("));"); // return Err(
-        },
-    );
-
-    s.wln("let mut r = vec![0_u8; (header.record_count * header.record_size) as usize];");
-    s.wln("b.read_exact(&mut r)?;");
+    s.wln("let header = crate::header::read_table_header::<Self>(b)?;");
+    s.wln(format!(
+        "let (r, {}) = crate::header::read_records_and_string_block(b, &header)?;",
+        if d.contains_string() {
+            "string_block"
+        } else {
+            "_string_block"
+        }
+    ));
+    s.newline();
 
     if d.contains_string() {
-        s.wln("let mut string_block = vec![0_u8; header.string_block_size as usize];");
-        s.wln("b.read_exact(&mut string_block)?;");
+        s.wln("let rows = crate::util::read_rows::<Self>(&r, &string_block)?;");
+    } else {
+        s.wln("let rows = crate::util::read_rows::<Self>(&r, &[])?;");
     }
-
     s.newline();
 
-    s.wln("let mut rows = Vec::with_capacity(header.record_count as usize);");
-    s.newline();
+    s.wln(format!("Ok({} {{ rows, }})", d.name()));
+    s.closing_curly_newline(); // fn read_
+}
 
-    s.open_curly("for mut chunk in r.chunks(header.record_size as usize)");
-    s.wln("let chunk = &mut chunk;");
+fn create_read_row_ref(s: &mut Writer, d: &DbcDescription, o: &Objects) {
+    s.open_curly(format!(
+        "fn read_row_ref_with<'a>(record: &'a [u8], {string_block}: &'a [u8], {coercions}: Option<&mut Vec<crate::Coercion>>) -> Result<Self::RowRef<'a>, crate::DbcError>",
+        string_block = if d.contains_string() {
+            "string_block"
+        } else {
+            "_string_block"
+        },
+        coercions = if d.fields().iter().any(|a| a.ty().is_coercible()) {
+            "mut coercions"
+        } else {
+            "_coercions"
+        },
+    ));
+    // fields are read at fixed offsets, so the size of the record is the only bounds check
+    s.wln(format!(
+        "let chunk = crate::util::record::<{}>(record)?;",
+        d.row_size()
+    ));
     s.newline();
 
+    let mut offset = 0;
     for field in d.fields() {
-        print_read_field(s, field, o);
+        print_read_field(s, field, o, offset);
+        offset += field.ty().row_size_count();
     }
     s.newline();
 
-    s.open_curly(format!("rows.push({}Row", d.name()));
+    s.open_curly(format!(
+        "Ok({}Row{}",
+        d.name(),
+        if d.contains_string() { "Ref" } else { "" }
+    ));
 
     for field in d.fields() {
         s.wln(format!("{},", field.name()));
     }
 
-    s.closing_curly_with(");");
-
-    s.closing_curly_newline(); // for mut chunk
+    s.closing_curly_with(")");
 
-    s.wln(format!("Ok({} {{ rows, }})", d.name()));
-    s.closing_curly_newline(); // fn read_
+    s.closing_curly_newline(); // fn read_row_ref_with
 }
 
-fn print_read_field_ty(s: &mut Writer, ty: &Type, o: &Objects) {
+/// Expression that reads a field of type `ty` at the constant `offset` from `chunk`.
+fn read_field_expr(ty: &Type, o: &Objects, name: &str, offset: usize) -> String {
+    let err = format!(".map_err(crate::util::field_error::<Self, _>(\"{name}\", {offset}))?");
+
     match ty {
         Type::PrimaryKey { table, ty } => {
-            s.wln_no_indent(format!(
-                "{table_name}Key::new(crate::util::read_{ty}_le(chunk)?);",
+            format!(
+                "{table_name}Key::new(chunk.{ty}_at::<{offset}>())",
                 table_name = table,
                 ty = ty.rust_str(),
-            ));
+            )
         }
         Type::ForeignKey { table, ty } => {
             if o.table_exists(table) {
-                s.wln_no_indent(format!(
-                    "{table_name}Key::new(crate::util::read_{ty}_le(chunk)?.into());",
+                format!(
+                    "{table_name}Key::new(chunk.{ty}_at::<{offset}>().into())",
                     table_name = table,
                     ty = ty.rust_str(),
-                ));
+                )
             } else {
-                s.wln_no_indent(format!(
-                    "crate::util::read_{ty}_le(chunk)?;",
-                    ty = ty.rust_str(),
-                ));
+                format!("chunk.{ty}_at::<{offset}>()", ty = ty.rust_str())
             }
         }
-        Type::I8 => {
-            s.wln_no_indent("crate::util::read_i8_le(chunk)?;");
-        }
-        Type::I16 => {
-            s.wln_no_indent("crate::util::read_i16_le(chunk)?;");
-        }
-        Type::I32 => {
-            s.wln_no_indent("crate::util::read_i32_le(chunk)?;");
-        }
-        Type::U8 => {
-            s.wln_no_indent("crate::util::read_u8_le(chunk)?;");
-        }
-        Type::U16 => {
-            s.wln_no_indent("crate::util::read_u16_le(chunk)?;");
-        }
-        Type::U32 => {
-            s.wln_no_indent("crate::util::read_u32_le(chunk)?;");
+        Type::I8 | Type::I16 | Type::I32 | Type::U8 | Type::U16 | Type::U32 | Type::Float => {
+            format!("chunk.{ty}_at::<{offset}>()", ty = ty.rust_str())
         }
         Type::Bool => {
-            s.wln_no_indent("crate::util::read_u8_le(chunk)? != 0;");
+            format!("crate::util::read_bool(chunk.u8_at::<{offset}>(), \"{name}\", {offset}, &mut coercions)")
         }
         Type::Bool32 => {
-            s.wln_no_indent("crate::util::read_u32_le(chunk)? != 0;");
+            format!("crate::util::read_bool(chunk.u32_at::<{offset}>(), \"{name}\", {offset}, &mut coercions)")
         }
         Type::ExtendedStringRefLoc => {
-            s.wln_no_indent("crate::util::read_extended_localized_string(chunk, &string_block)?;");
+            format!("chunk.extended_localized_string_at::<{offset}>(string_block){err}")
         }
         Type::StringRefLoc => {
-            s.wln_no_indent("crate::util::read_localized_string(chunk, &string_block)?;");
+            format!("chunk.localized_string_at::<{offset}>(string_block){err}")
         }
         Type::StringRef => {
-            s.wln_no_indent("{");
-            s.inc_indent();
-            s.wln("let s = crate::util::get_string_as_vec(chunk, &string_block)?;");
-            s.wln("String::from_utf8(s)?");
-            s.closing_curly_with(";");
+            format!("chunk.str_ref_at::<{offset}>(string_block){err}")
         }
         Type::Flag(en) => {
-            s.wln_no_indent(format!(
-                "{en}::new(crate::util::read_{ty}_le(chunk)? as _);",
+            format!(
+                "{en}::new(chunk.{ty}_at::<{offset}>() as _)",
                 en = en.name(),
                 ty = en.ty().rust_str(),
-            ));
+            )
         }
         Type::Enum(en) => {
-            s.wln_no_indent(format!(
-                "crate::util::read_{ty}_le(chunk)?.try_into()?;",
+            format!(
+                "crate::util::read_enum::<Self, _, _>(chunk.{ty}_at::<{offset}>(), \"{name}\", {offset}, &mut coercions)?",
                 ty = en.ty().rust_str(),
-            ));
+            )
         }
-        Type::Float => {
-            s.wln_no_indent("crate::util::read_f32_le(chunk)?;");
-        }
-        Type::Array(array) => {
-            if array.ty().has_custom_array_impl() {
-                s.wln_no_indent(format!(
-                    "crate::util::read_array_{ty}::<{size}>(chunk)?;",
-                    ty = array.ty().rust_str(),
-                    size = array.size()
-                ));
+        Type::Array(_) => unreachable!("arrays of arrays are not valid"),
+    }
+}
 
-                return;
-            }
+fn print_read_field(s: &mut Writer, field: &Field, o: &Objects, offset: usize) {
+    rust_printer::print_field_comment(s, field);
 
-            s.wln_no_indent("{");
+    let name = field.name();
+    match field.ty() {
+        Type::Array(array) if array.ty().has_custom_array_impl() => {
+            s.wln(format!(
+                "let {name} = chunk.array_{ty}_at::<{offset}, {size}>();",
+                ty = array.ty().rust_str(),
+                size = array.size(),
+            ));
+        }
+        Type::Array(array) => {
+            // every element is read at its own constant offset
+            s.wln(format!("let {name} = ["));
             s.inc_indent();
 
-            if array.ty().is_string() {
-                s.wln(format!(
-                    "let mut arr = Vec::with_capacity({size});",
-                    size = array.size()
-                ));
-
-                s.bodyn(format!("for _ in 0..{size}", size = array.size()), |s| {
-                    s.w("let i =");
-                    print_read_field_ty(s, array.ty(), o);
-                    s.wln("arr.push(i);");
-                });
-
-                s.wln("arr.try_into().unwrap()");
-            } else {
-                s.wln(format!(
-                    "let mut arr = [{ty}::default(); {size}];",
-                    ty = array.ty().rust_str(),
-                    size = array.size()
-                ));
-
-                s.bodyn("for i in arr.iter_mut()", |s| {
-                    s.w("*i = ");
-                    print_read_field_ty(s, array.ty(), o);
-                });
-
-                s.wln("arr");
+            for i in 0..array.size() as usize {
+                let offset = offset + i * array.ty().row_size_count();
+                let name = format!("{name}[{i}]");
+                s.wln(format!("{},", read_field_expr(array.ty(), o, &name, offset)));
             }
 
-            s.closing_curly_with(";");
+            s.dec_indent();
+            s.wln("];");
+        }
+        ty => {
+            s.wln(format!("let {name} = {};", read_field_expr(ty, o, name, offset)));
         }
     }
-}
-
-fn print_read_field(s: &mut Writer, field: &Field, o: &Objects) {
-    rust_printer::print_field_comment(s, field);
-
-    s.w(format!("let {name} = ", name = field.name()));
-    print_read_field_ty(s, field.ty(), o);
 
     s.newline();
 }
@@ -3,10 +3,20 @@ use crate::{DbcDescription, DbcVersion, Objects, Writer};
 use heck::ToSnakeCase;
 use std::collections::{BTreeMap, BTreeSet};
 
+mod any_table;
+mod csv_row;
+mod database;
+mod fuzz;
 mod main_ty;
+mod row_fields;
 mod sqlite_converter;
+mod table_list;
 
+pub use any_table::{any_table, any_table_test};
+pub use database::database;
+pub use fuzz::fuzz_target;
 pub use sqlite_converter::sqlite_converter;
+pub use table_list::table_list;
 
 fn not_pascal_case_name(s: &str) -> bool {
     s.contains('_')
@@ -23,6 +33,11 @@ pub fn create_table(d: &DbcDescription, o: &Objects, version: DbcVersion) -> Wri
 
     create_row(&mut s, d, o);
 
+    create_row_ref(&mut s, d, o);
+
+    row_fields::create_row_fields(&mut s, d, o);
+
+    csv_row::create_csv_row(&mut s, d, o);
 
     create_test(&mut s, d, version);
 
@@ -50,11 +65,10 @@ fn insert(
 fn includes(s: &mut Writer, d: &DbcDescription, o: &Objects, version: DbcVersion) {
     let mut map = BTreeMap::new();
 
-    insert(&mut map, "std::io", "Write");
+    insert(&mut map, "crate::io", "Write");
+    insert(&mut map, "crate::prelude", "*");
 
-    insert(&mut map, "crate::header", "HEADER_SIZE");
     insert(&mut map, "crate::header", "DbcHeader");
-    insert(&mut map, "crate::header", "parse_header");
     insert(&mut map, "crate::util", "StringCache");
 
     insert(&mut map, "crate", "DbcTable");
@@ -65,10 +79,12 @@ fn includes(s: &mut Writer, d: &DbcDescription, o: &Objects, version: DbcVersion
 
     if d.contains_localized_string() {
         insert(&mut map, "crate", "LocalizedString");
+        insert(&mut map, "crate", "LocalizedStringRef");
     }
 
     if d.contains_extended_localized_string() {
         insert(&mut map, "crate", "ExtendedLocalizedString");
+        insert(&mut map, "crate", "ExtendedLocalizedStringRef");
     }
 
     if d.contains_localized_string() || d.contains_extended_localized_string() {
@@ -154,6 +170,27 @@ fn print_derives(s: &mut Writer, fields: &[Field], derive_copy: bool) {
     s.wln("#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize))]");
 }
 
+fn print_ref_derives(s: &mut Writer, fields: &[Field]) {
+    s.w("#[derive(Debug, Clone, Copy, PartialEq");
+    if can_derive_eq(fields) {
+        s.w_no_indent(", Eq");
+    }
+
+    s.w_no_indent(", PartialOrd");
+    if can_derive_ord(fields) {
+        s.w_no_indent(", Ord");
+    }
+
+    if can_derive_hash(fields) {
+        s.w_no_indent(", Hash");
+    }
+
+    s.wln_no_indent(")]");
+
+    // borrowed strings can only be serialized
+    s.wln("#[cfg_attr(feature = \"serde\", derive(serde::Serialize))]");
+}
+
 fn can_derive_copy(fields: &[Field]) -> bool {
     for field in fields {
         match field.ty() {
@@ -208,20 +245,73 @@ fn create_row(s: &mut Writer, d: &DbcDescription, o: &Objects) {
     s.new_struct(format!("{}Row", d.name()), |s| {
         for field in d.fields() {
             let name = field.name();
+            let ty = row_field_ty(field, o, false);
 
-            let ty = if let Type::ForeignKey { table, ty } = field.ty() {
-                if o.table_exists(table) {
-                    field.ty().rust_str()
-                } else {
-                    ty.rust_str()
-                }
-            } else {
-                field.ty().rust_str()
-            };
+            s.wln(format!("pub {name}: {ty},"));
+        }
+    });
+}
+
+fn row_field_ty(field: &Field, o: &Objects, borrowed: bool) -> String {
+    if let Type::ForeignKey { table, ty } = field.ty() {
+        if !o.table_exists(table) {
+            return ty.rust_str();
+        }
+    }
+
+    if borrowed {
+        field.ty().rust_ref_str()
+    } else {
+        field.ty().rust_str()
+    }
+}
+
+fn create_row_ref(s: &mut Writer, d: &DbcDescription, o: &Objects) {
+    // tables without strings just use the owned row
+    if !d.contains_string() {
+        return;
+    }
+
+    let name = d.name();
+
+    if not_pascal_case_name(name) {
+        s.wln("#[allow(non_camel_case_types)]");
+    }
+
+    print_ref_derives(s, d.fields());
+
+    s.new_struct(format!("{name}RowRef<'a>"), |s| {
+        for field in d.fields() {
+            let name = field.name();
+            let ty = row_field_ty(field, o, true);
 
             s.wln(format!("pub {name}: {ty},"));
         }
     });
+
+    s.bodyn(format!("impl From<{name}RowRef<'_>> for {name}Row"), |s| {
+        s.body(format!("fn from(v: {name}RowRef<'_>) -> Self"), |s| {
+            s.open_curly("Self");
+
+            for field in d.fields() {
+                let name = field.name();
+
+                match field.ty() {
+                    Type::StringRef | Type::StringRefLoc | Type::ExtendedStringRefLoc => {
+                        s.wln(format!("{name}: v.{name}.into(),"));
+                    }
+                    Type::Array(array) if array.ty().is_string() => {
+                        s.wln(format!("{name}: v.{name}.map(Into::into),"));
+                    }
+                    _ => {
+                        s.wln(format!("{name}: v.{name},"));
+                    }
+                }
+            }
+
+            s.closing_curly(); // Self
+        });
+    });
 }
 
 fn create_primary_keys(s: &mut Writer, d: &DbcDescription) {
@@ -249,6 +339,17 @@ fn create_primary_keys(s: &mut Writer, d: &DbcDescription) {
             );
         });
 
+        s.bodyn(
+            format!("impl crate::TableKey for {}", key.ty().rust_str()),
+            |s| {
+                s.wln(format!("type Table = {};", d.name()));
+                s.newline();
+                s.body("fn is_none(&self) -> bool", |s| {
+                    s.wln("self.id == 0");
+                });
+            },
+        );
+
         create_primary_key_froms(s, key, ty);
     }
 }
@@ -355,12 +456,27 @@ fn create_test(s: &mut Writer, d: &DbcDescription, version: DbcVersion) {
     s.wln(format!(
         "let actual = {ty}::read(&mut contents.as_slice()).unwrap();",
     ));
+    s.wln(format!(
+        "let borrowed = crate::DbcTableRef::<{ty}>::new(&contents).unwrap();",
+    ));
+    s.wln("assert_eq!(borrowed.len(), actual.rows.len());");
+    s.body("for (row, borrowed) in actual.rows.iter().zip(borrowed)", |s| {
+        s.wln(format!("let borrowed: {ty}Row = borrowed.unwrap().into();"));
+        s.wln("assert_eq!(row, &borrowed);");
+    });
     s.wln("let mut v = Vec::with_capacity(contents.len());");
     s.wln("actual.write(&mut v).unwrap();");
 
     s.wln(format!("let new = {ty}::read(&mut v.as_slice()).unwrap();"));
     s.wln("assert_eq!(actual, new);");
 
+    s.wln(format!(
+        "let preserved = {ty}::read_preserving(&mut contents.as_slice()).unwrap();",
+    ));
+    s.wln("let mut v = Vec::with_capacity(contents.len());");
+    s.wln("preserved.write(&mut v).unwrap();");
+    s.wln("assert_eq!(v, contents);");
+
     s.closing_curly(); // fn
     s.closing_curly(); // mod test
 }
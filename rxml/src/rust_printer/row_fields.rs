@@ -0,0 +1,160 @@
+use crate::types::Type;
+use crate::{DbcDescription, Objects, Writer};
+
+pub(crate) fn create_row_fields(s: &mut Writer, d: &DbcDescription, o: &Objects) {
+    s.bodyn(format!("impl crate::RowFields for {}Row", d.name()), |s| {
+        s.w("const FIELDS: &'static [&'static str] = &[");
+        for (i, field) in d.fields().iter().enumerate() {
+            if i != 0 {
+                s.w_no_indent(", ");
+            }
+            s.w_no_indent(format!("\"{}\"", field.name()));
+        }
+        s.wln_no_indent("];");
+        s.newline();
+
+        s.bodyn("fn id(&self) -> Option<i64>", |s| match d.primary_key() {
+            Some((key, _)) => s.wln(format!("Some(self.{}.id.into())", key.name())),
+            None => s.wln("None"),
+        });
+
+        s.bodyn("fn values(&self) -> Vec<crate::Value>", |s| {
+            s.wln("vec![");
+            s.inc_indent();
+            for field in d.fields() {
+                let value = match field.ty() {
+                    Type::Array(array) => format!(
+                        "crate::Value::Array(self.{name}.iter().map(|{pattern}| {value}).collect())",
+                        name = field.name(),
+                        pattern = if array.ty().is_string() { "a" } else { "&a" },
+                        value = value(array.ty(), "a", o),
+                    ),
+                    ty => value(ty, &format!("self.{}", field.name()), o),
+                };
+                s.wln(format!("{value},"));
+            }
+            s.dec_indent();
+            s.wln("]");
+        });
+
+        s.bodyn(
+            "fn set_field(&mut self, field: &str, value: crate::Value) -> Result<(), crate::SetFieldError>",
+            |s| {
+                s.body("match field", |s| {
+                    for field in d.fields() {
+                        s.wln(format!(
+                            "\"{name}\" => self.{name} = {value},",
+                            name = field.name(),
+                            value = from_value(field.ty(), "field", "value", o),
+                        ));
+                    }
+                    s.wln("_ => return Err(crate::SetFieldError::UnknownField { field: field.to_string() }),");
+                });
+                s.newline();
+                s.wln("Ok(())");
+            },
+        );
+
+        s.body(
+            "fn from_values(values: Vec<crate::Value>) -> Result<Self, crate::SetFieldError>",
+            |s| {
+                s.w("let [");
+                for (i, field) in d.fields().iter().enumerate() {
+                    if i != 0 {
+                        s.w_no_indent(", ");
+                    }
+                    s.w_no_indent(field.name());
+                }
+                s.wln_no_indent(format!(
+                    "]: [crate::Value; {}] = crate::fields::field_values(values)?;",
+                    d.fields().len()
+                ));
+                s.newline();
+
+                s.open_curly("Ok(Self");
+                for field in d.fields() {
+                    s.wln(format!(
+                        "{name}: {value},",
+                        name = field.name(),
+                        value = from_value(field.ty(), &format!("\"{}\"", field.name()), field.name(), o),
+                    ));
+                }
+                s.closing_curly_with(")");
+            },
+        );
+    });
+}
+
+/// Converts the `Value` in `v` into the type of the field, with `field` being the name of the field.
+fn from_value(ty: &Type, field: &str, v: &str, o: &Objects) -> String {
+    match ty {
+        Type::I8 | Type::I16 | Type::I32 | Type::U8 | Type::U16 | Type::U32 => {
+            format!("crate::fields::int({field}, {v})?")
+        }
+        Type::Bool | Type::Bool32 => format!("crate::fields::bool({field}, {v})?"),
+        Type::Float => format!("crate::fields::float({field}, {v})?"),
+        Type::StringRef => format!("crate::fields::string({field}, {v})?"),
+        Type::StringRefLoc => format!("crate::fields::localized({field}, {v})?"),
+        Type::ExtendedStringRefLoc => format!("crate::fields::extended_localized({field}, {v})?"),
+        Type::Enum(en) => format!(
+            "crate::fields::enumeration::<_, {ty}>({field}, {v})?",
+            ty = en.ty().rust_str(),
+        ),
+        Type::Flag(en) => format!(
+            "{name}::new(crate::fields::int::<{ty}>({field}, {v})? as _)",
+            name = en.name(),
+            ty = en.ty().rust_str(),
+        ),
+        Type::PrimaryKey { .. } => format!(
+            "{key}::new(crate::fields::int({field}, {v})?)",
+            key = ty.rust_str(),
+        ),
+        Type::ForeignKey { table, .. } => {
+            if o.table_exists(table) {
+                format!(
+                    "{key}::new(crate::fields::int({field}, {v})?)",
+                    key = ty.rust_str(),
+                )
+            } else {
+                format!("crate::fields::int({field}, {v})?")
+            }
+        }
+        Type::Array(array) => {
+            let value = from_value(array.ty(), "field", "v", o);
+            // functions that only take the field and value are passed directly
+            let f = match value.strip_suffix("(field, v)?") {
+                Some(function) if !function.contains('(') => function.to_string(),
+                _ => format!("|field, v| Ok({value})"),
+            };
+
+            format!("crate::fields::array({field}, {v}, {f})?")
+        }
+    }
+}
+
+/// Converts the field `v` into a `Value`.
+fn value(ty: &Type, v: &str, o: &Objects) -> String {
+    match ty {
+        Type::I8
+        | Type::I16
+        | Type::I32
+        | Type::U8
+        | Type::U16
+        | Type::U32
+        | Type::Bool
+        | Type::Bool32 => format!("crate::Value::Int({v}.into())"),
+        Type::Float => format!("crate::Value::Float({v})"),
+        Type::StringRef => format!("crate::Value::String({v}.clone())"),
+        Type::StringRefLoc | Type::ExtendedStringRefLoc => format!("crate::Value::from(&{v})"),
+        Type::Enum(_) | Type::Flag(_) => format!("crate::Value::Int({v}.as_int().into())"),
+        Type::PrimaryKey { .. } => format!("crate::Value::Key({v}.id.into())"),
+        Type::ForeignKey { table, .. } => {
+            if o.table_exists(table) {
+                format!("crate::Value::Key({v}.id.into())")
+            } else {
+                format!("crate::Value::Key({v}.into())")
+            }
+        }
+        Type::Array(_) => unreachable!("arrays of arrays are not supported"),
+    }
+}
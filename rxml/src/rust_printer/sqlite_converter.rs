@@ -1,7 +1,6 @@
 use crate::types::{extended_string_ref_loc_members, string_ref_loc_members, DbcDescription, Type};
 use crate::writer::Writer;
 use crate::{DbcVersion, Objects};
-use heck::ToSnakeCase;
 
 pub fn sqlite_converter(
     descriptions: &[DbcDescription],
@@ -25,16 +24,16 @@ fn write_to_sqlite_function(s: &mut Writer, descriptions: &[DbcDescription], o:
     s.wln("let tx = conn.transaction()?;");
     s.newline();
 
-    s.open_curly("match file_name");
+    s.open_curly("let Some(data) = AnyTable::read_by_filename(file_name, file_contents)? else");
+    s.wln("return Err(SqliteError::FilenameNotFound { name: file_name.to_string() });");
+    s.closing_curly_with(";");
+    s.newline();
+
+    s.open_curly("match data");
 
     for description in descriptions {
-        s.open_curly(format!("\"{}.dbc\" =>", description.name()));
+        s.open_curly(format!("AnyTable::{}(data) =>", description.name()));
 
-        s.wln(format!(
-            "let data = {module}::{ty}::read(file_contents)?;",
-            module = description.name().to_snake_case(),
-            ty = description.name(),
-        ));
         s.wln(format!("let (table, insert) = {}();", description.name()));
         s.wln("tx.execute(table, ())?;");
         s.newline();
@@ -92,8 +91,7 @@ fn write_to_sqlite_function(s: &mut Writer, descriptions: &[DbcDescription], o:
         s.closing_curly(); // => {
     }
 
-    s.wln("v => return Err(SqliteError::FilenameNotFound { name: v.to_string() }),");
-    s.closing_curly(); // match file_name
+    s.closing_curly(); // match data
 
     s.newline();
     s.wln("tx.commit()?;");
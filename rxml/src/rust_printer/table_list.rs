@@ -0,0 +1,26 @@
+use crate::{DbcDescription, DbcVersion, Writer};
+use heck::ToSnakeCase;
+
+pub fn table_list(s: &mut Writer, descriptions: &[DbcDescription], version: DbcVersion) {
+    let expansion = match version {
+        DbcVersion::Vanilla => "Vanilla",
+        DbcVersion::Tbc => "Tbc",
+        DbcVersion::Wrath => "Wrath",
+    };
+
+    s.newline();
+    s.wln("use crate::{Expansion, TableInfo};");
+    s.newline();
+    s.wln("/// Every table in this module.");
+    s.wln("pub const TABLES: &[TableInfo] = &[");
+    s.inc_indent();
+    for d in descriptions {
+        s.wln(format!(
+            "TableInfo::new::<{module}::{ty}>(Expansion::{expansion}),",
+            module = d.name().to_snake_case(),
+            ty = d.name(),
+        ));
+    }
+    s.dec_indent();
+    s.wln("];");
+}
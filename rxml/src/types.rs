@@ -273,6 +273,19 @@ impl Type {
         }
     }
 
+    /// Type used in the borrowed `RowRef` structs, strings reference the string block.
+    pub fn rust_ref_str(&self) -> String {
+        match &self {
+            Type::StringRefLoc => "LocalizedStringRef<'a>".to_string(),
+            Type::ExtendedStringRefLoc => "ExtendedLocalizedStringRef<'a>".to_string(),
+            Type::StringRef => "&'a str".to_string(),
+            Type::Array(array) => {
+                format!("[{}; {}]", array.ty.rust_ref_str(), array.size)
+            }
+            _ => self.rust_str(),
+        }
+    }
+
     pub fn str(&self) -> String {
         match &self {
             Type::I8 => I8_NAME.to_string(),
@@ -316,6 +329,15 @@ impl Type {
         )
     }
 
+    /// Values that can be coerced when reading leniently.
+    pub fn is_coercible(&self) -> bool {
+        match self {
+            Type::Enum(_) | Type::Bool | Type::Bool32 => true,
+            Type::Array(array) => array.ty().is_coercible(),
+            _ => false,
+        }
+    }
+
     pub fn has_custom_array_impl(&self) -> bool {
         matches!(self, Type::I32 | Type::U32 | Type::Float)
     }
@@ -325,11 +347,26 @@ impl Type {
 pub struct Array {
     ty: Type,
     size: i32,
+    foreign_key: Option<String>,
 }
 
 impl Array {
     pub fn new(ty: Type, size: i32) -> Self {
-        Self { ty, size }
+        Self {
+            ty,
+            size,
+            foreign_key: None,
+        }
+    }
+    /// Array where every element is a foreign key to `table`.
+    ///
+    /// The elements keep their integer type, the key is only used for checking references.
+    pub fn with_foreign_key(ty: Type, size: i32, table: &str) -> Self {
+        Self {
+            ty,
+            size,
+            foreign_key: Some(table.to_string()),
+        }
     }
     pub fn ty(&self) -> &Type {
         &self.ty
@@ -337,4 +374,7 @@ impl Array {
     pub fn size(&self) -> i32 {
         self.size
     }
+    pub fn foreign_key(&self) -> Option<&str> {
+        self.foreign_key.as_deref()
+    }
 }
@@ -0,0 +1,54 @@
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use std::hint::black_box;
+use wow_dbc::wrath_tables::spell::{Spell, SpellKey, SpellRow};
+use wow_dbc::{DbcTable, DbcTableRef};
+
+/// About the amount of rows in `Spell.dbc` from 3.3.5.
+const ROWS: i32 = 50_000;
+
+/// Synthetic `Spell.dbc` with different names and descriptions for every row.
+fn spell() -> Vec<u8> {
+    // a zeroed record only has invalid enum values, which are coerced to their defaults
+    let record = [0_u8; Spell::ROW_SIZE];
+    let row: SpellRow = Spell::read_row_ref_with(&record, &[0], Some(&mut Vec::new()))
+        .unwrap()
+        .into();
+
+    let rows = (1..=ROWS)
+        .map(|i| {
+            let mut row = row.clone();
+            row.id = SpellKey::new(i);
+            row.name_lang.en_gb = format!("Spell {i}");
+            row.description_lang.en_gb = format!("Description of spell {i}");
+            row
+        })
+        .collect();
+
+    let mut b = Vec::new();
+    Spell::from_rows(rows).write(&mut b).unwrap();
+
+    b
+}
+
+fn read(c: &mut Criterion) {
+    let b = spell();
+
+    let mut group = c.benchmark_group("spell");
+    group.sample_size(20);
+    group.throughput(Throughput::Bytes(b.len() as u64));
+
+    group.bench_function("read", |bench| {
+        bench.iter(|| Spell::read(&mut black_box(b.as_slice())).unwrap())
+    });
+    group.bench_function("read_ref", |bench| {
+        bench.iter(|| {
+            let table = DbcTableRef::<Spell>::new(black_box(&b)).unwrap();
+            table.iter().map(Result::unwrap).count()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, read);
+criterion_main!(benches);
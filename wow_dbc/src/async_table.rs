@@ -0,0 +1,146 @@
+use crate::header::{parse_table_header, HEADER_SIZE};
+use crate::{DbcError, DbcTable};
+use std::future::Future;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+/// Reading and writing of tables with [`tokio`] readers and writers. Implemented for every [`DbcTable`].
+///
+/// The bytes of the table are read and written asynchronously,
+/// while the parsing happens in [`DbcTable::read`] and [`DbcTable::write`] without blocking on IO,
+/// so the results and errors are exactly the same as for the synchronous functions.
+/// Parsing runs in [`tokio::task::spawn_blocking`], so large tables do not block the runtime.
+///
+/// ```rust,ignore
+/// let mut file = tokio::fs::File::open("Spell.dbc").await?;
+/// let table = Spell::read_async(&mut file).await?;
+/// ```
+pub trait AsyncDbcTable: DbcTable {
+    /// Read table from an async reader, see [`DbcTable::read`].
+    ///
+    /// Only the bytes of the table are read, so the reader is left at the same position as with [`DbcTable::read`].
+    /// The bytes are parsed on the blocking thread pool of the runtime.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`DbcTable::read`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a [`tokio`] runtime, same as [`tokio::task::spawn_blocking`].
+    fn read_async<R: AsyncRead + Unpin + Send>(
+        r: &mut R,
+    ) -> impl Future<Output = Result<Self, DbcError>> + Send
+    where
+        Self: Send + 'static,
+    {
+        async move {
+            let b = read_table_bytes::<Self, R>(r).await?;
+
+            tokio::task::spawn_blocking(move || Self::read(&mut b.as_slice()))
+                .await
+                .unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic()))
+        }
+    }
+
+    /// Write to an async writer in the DBC format, see [`DbcTable::write`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`AsyncWriteExt::write_all`].
+    fn write_async<W: AsyncWrite + Unpin + Send>(
+        &self,
+        w: &mut W,
+    ) -> impl Future<Output = Result<(), std::io::Error>> + Send
+    where
+        Self: Sync,
+    {
+        async move {
+            let mut b = Vec::new();
+            self.write(&mut b)?;
+
+            w.write_all(&b).await
+        }
+    }
+}
+
+impl<T: DbcTable> AsyncDbcTable for T {}
+
+/// Reads the header, the records, and the string block, stopping early at the end of the reader.
+///
+/// If the header is invalid only the header is read,
+/// since [`DbcTable::read`] returns the same error without reading further.
+async fn read_table_bytes<T: DbcTable, R: AsyncRead + Unpin>(
+    r: &mut R,
+) -> Result<Vec<u8>, std::io::Error> {
+    // the buffer grows with the bytes that are actually read, same as `read_bytes`
+    let mut b = Vec::new();
+    (&mut *r)
+        .take(HEADER_SIZE as u64)
+        .read_to_end(&mut b)
+        .await?;
+
+    let Ok(header) = <&[u8; HEADER_SIZE]>::try_from(b.as_slice()) else {
+        return Ok(b);
+    };
+    let Ok(header) = parse_table_header::<T>(header) else {
+        return Ok(b);
+    };
+    let Ok(records_size) = header.records_size() else {
+        return Ok(b);
+    };
+
+    let size = records_size as u64 + u64::from(header.string_block_size);
+    (&mut *r).take(size).read_to_end(&mut b).await?;
+
+    Ok(b)
+}
+
+#[cfg(all(test, feature = "vanilla"))]
+mod test {
+    use super::*;
+    use crate::fixtures::item_class_row;
+    use crate::vanilla_tables::item_class::ItemClass;
+    use crate::MalformedError;
+    use wow_world_base::vanilla::ItemWeaponClass;
+
+    #[tokio::test]
+    async fn read_and_write() {
+        let mut row = item_class_row(2, "Weapon");
+        row.subclass_map = 1;
+        row.item_class = ItemWeaponClass::Weapon;
+        let table = ItemClass { rows: vec![row] };
+
+        let mut b = Vec::new();
+        table.write_async(&mut b).await.unwrap();
+        let mut sync = Vec::new();
+        table.write(&mut sync).unwrap();
+        assert_eq!(b, sync);
+
+        // trailing bytes are not read
+        b.extend_from_slice(&[1, 2, 3]);
+        let mut r = b.as_slice();
+        assert_eq!(ItemClass::read_async(&mut r).await.unwrap(), table);
+        assert_eq!(r, [1, 2, 3]);
+
+        // futures can be spawned
+        let mut r = std::io::Cursor::new(b.clone());
+        let spawned = tokio::spawn(async move { ItemClass::read_async(&mut r).await });
+        assert_eq!(spawned.await.unwrap().unwrap(), table);
+
+        for len in [0, 10, HEADER_SIZE, b.len() - 4] {
+            let sync = ItemClass::read(&mut &b[..len]).unwrap_err();
+            let err = ItemClass::read_async(&mut &b[..len]).await.unwrap_err();
+            assert!(matches!(
+                err,
+                DbcError::Malformed(MalformedError::Truncated { .. })
+            ));
+            assert_eq!(err.to_string(), sync.to_string());
+        }
+
+        b[0] = 0;
+        let sync = ItemClass::read(&mut b.as_slice()).unwrap_err();
+        let err = ItemClass::read_async(&mut b.as_slice()).await.unwrap_err();
+        assert!(matches!(err, DbcError::InvalidHeader(_)));
+        assert_eq!(err.to_string(), sync.to_string());
+    }
+}
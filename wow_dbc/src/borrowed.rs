@@ -0,0 +1,210 @@
+use crate::header::{parse_table_header, HEADER_SIZE};
+use crate::prelude::*;
+use crate::{DbcError, DbcTable, MalformedError};
+use core::iter::Enumerate;
+use core::marker::PhantomData;
+use core::slice::ChunksExact;
+
+/// Borrowed view over the bytes of a DBC file that parses rows on demand.
+///
+/// Strings are returned as `&'a str` pointing directly into the string block,
+/// so nothing is allocated until a row is converted into the owned [`DbcTable::Row`] with [`Into`].
+///
+/// ```rust,ignore
+/// let contents = std::fs::read("Spell.dbc")?;
+/// let spells = DbcTableRef::<Spell>::new(&contents)?;
+///
+/// for row in spells.iter() {
+///     let row = row?;
+///     println!("{}", row.name.en_gb);
+/// }
+/// ```
+pub struct DbcTableRef<'a, T: DbcTable> {
+    records: &'a [u8],
+    string_block: &'a [u8],
+    table: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: DbcTable> DbcTableRef<'a, T> {
+    /// Validates the header of `data` and creates the view.
+    ///
+    /// No rows are parsed until they are accessed.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same header errors as [`DbcTable::read`], and [`MalformedError::Truncated`] if `data` is shorter than the header says.
+    pub fn new(data: &'a [u8]) -> Result<Self, DbcError> {
+        let header = data
+            .get(..HEADER_SIZE)
+            .and_then(|a| a.try_into().ok())
+            .ok_or_else(|| truncated(HEADER_SIZE, data.len()))?;
+        let header = parse_table_header::<T>(header)?;
+
+        let data = &data[HEADER_SIZE..];
+        let records_size = header.records_size()?;
+        let string_block_size = header.string_block_size as usize;
+
+        let records = data
+            .get(..records_size)
+            .ok_or_else(|| truncated(records_size, data.len()))?;
+        let string_block = data[records_size..]
+            .get(..string_block_size)
+            .ok_or_else(|| truncated(string_block_size, data.len() - records_size))?;
+
+        Ok(Self {
+            records,
+            string_block,
+            table: PhantomData,
+        })
+    }
+
+    /// Amount of rows in the table.
+    pub const fn len(&self) -> usize {
+        self.records.len() / T::ROW_SIZE
+    }
+
+    /// Returns `true` if the table has no rows.
+    pub const fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Parses the row at `index`, or returns [`None`] if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Option<Result<T::RowRef<'a>, DbcError>> {
+        let start = index.checked_mul(T::ROW_SIZE)?;
+        let record = self.records.get(start..start.checked_add(T::ROW_SIZE)?)?;
+
+        Some(T::read_row_ref(record, self.string_block).map_err(|e| e.with_row(index)))
+    }
+
+    /// Iterator that parses every row in order.
+    pub fn iter(&self) -> RowRefIter<'a, T> {
+        RowRefIter {
+            records: self.records.chunks_exact(T::ROW_SIZE).enumerate(),
+            string_block: self.string_block,
+            table: PhantomData,
+        }
+    }
+
+    /// Parses every row and converts them into the owned table.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`DbcTable::read`].
+    pub fn to_table(&self) -> Result<T, DbcError> {
+        let rows = self
+            .iter()
+            .map(|a| a.map(Into::into))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(T::from_rows(rows))
+    }
+}
+
+impl<T: DbcTable> Clone for DbcTableRef<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: DbcTable> Copy for DbcTableRef<'_, T> {}
+
+impl<T: DbcTable> core::fmt::Debug for DbcTableRef<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DbcTableRef")
+            .field("table", &T::FILENAME)
+            .field("rows", &self.len())
+            .field("string_block_size", &self.string_block.len())
+            .finish()
+    }
+}
+
+impl<'a, T: DbcTable> IntoIterator for DbcTableRef<'a, T> {
+    type Item = Result<T::RowRef<'a>, DbcError>;
+    type IntoIter = RowRefIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the rows of a [`DbcTableRef`]. Created by [`DbcTableRef::iter`].
+pub struct RowRefIter<'a, T: DbcTable> {
+    records: Enumerate<ChunksExact<'a, u8>>,
+    string_block: &'a [u8],
+    table: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: DbcTable> Iterator for RowRefIter<'a, T> {
+    type Item = Result<T::RowRef<'a>, DbcError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, record) = self.records.next()?;
+
+        Some(T::read_row_ref(record, self.string_block).map_err(|e| e.with_row(index)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.records.size_hint()
+    }
+}
+
+impl<T: DbcTable> ExactSizeIterator for RowRefIter<'_, T> {}
+
+fn truncated(expected: usize, actual: usize) -> DbcError {
+    MalformedError::Truncated {
+        expected: expected as u64,
+        actual: actual as u64,
+    }
+    .into()
+}
+
+#[cfg(all(test, feature = "vanilla"))]
+mod test {
+    use super::*;
+    use crate::fixtures::spell_icon_row;
+    use crate::vanilla_tables::spell_icon::{SpellIcon, SpellIconRow};
+
+    fn spell_icon() -> SpellIcon {
+        SpellIcon {
+            rows: vec![
+                spell_icon_row(1, "Interface\\Icons\\Temp"),
+                spell_icon_row(2, ""),
+            ],
+        }
+    }
+
+    #[test]
+    fn borrowed_rows() {
+        let table = spell_icon();
+        let mut b = Vec::new();
+        table.write(&mut b).unwrap();
+
+        let borrowed = DbcTableRef::<SpellIcon>::new(&b).unwrap();
+        assert_eq!(borrowed.len(), 2);
+
+        let first = borrowed.get(0).unwrap().unwrap();
+        assert_eq!(first.texture_file, "Interface\\Icons\\Temp");
+        assert!(borrowed.get(2).is_none());
+        assert!(borrowed.get(usize::MAX / SpellIcon::ROW_SIZE).is_none());
+
+        let rows = borrowed
+            .iter()
+            .map(|a| a.map(Into::into))
+            .collect::<Result<Vec<SpellIconRow>, _>>()
+            .unwrap();
+        assert_eq!(rows, table.rows);
+        assert_eq!(borrowed.to_table().unwrap(), table);
+    }
+
+    #[test]
+    fn truncated() {
+        let mut b = Vec::new();
+        spell_icon().write(&mut b).unwrap();
+        b.pop();
+
+        assert!(matches!(
+            DbcTableRef::<SpellIcon>::new(&b),
+            Err(DbcError::Malformed(MalformedError::Truncated { .. }))
+        ));
+    }
+}
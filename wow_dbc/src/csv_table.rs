@@ -0,0 +1,294 @@
+use crate::{CsvError, DbcTable};
+use std::io::{Read, Write};
+
+/// Conversion of rows to and from CSV records. Implemented for every row type.
+///
+/// Columns are named the same as in the `SQLite` databases from `wow_dbc_converter`:
+/// localized strings have a column for every locale and the flags, like `name_en_gb` and `name_flags`,
+/// and arrays have a column for every element, like `reagent_0`.
+///
+/// Enums and flags are written with the names from the table definitions, like `Weapon` or `Initial|Global`,
+/// and can be read from either names or integers.
+/// Names are compared without case and underscores, so `ZoneDependency` is the same as `zone_dependency`.
+pub trait CsvRow: Sized {
+    /// Names of every column, in the order they are written.
+    const COLUMNS: &'static [&'static str];
+
+    /// Values of every column, in the same order as [`CsvRow::COLUMNS`].
+    fn to_csv_record(&self) -> Vec<String>;
+
+    /// Creates a row from the values of every column, in the same order as [`CsvRow::COLUMNS`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CsvError::ColumnCount`] if the amount of values is wrong,
+    /// and [`CsvError::InvalidValue`] if a value can not be parsed as the type of its column.
+    fn from_csv_record(record: &[&str]) -> Result<Self, CsvError>;
+}
+
+pub(crate) fn write_csv<T: DbcTable>(table: &T, w: impl Write) -> Result<(), CsvError>
+where
+    T::Row: CsvRow,
+{
+    let mut w = csv::Writer::from_writer(w);
+
+    w.write_record(T::Row::COLUMNS)?;
+    for row in table.rows() {
+        w.write_record(row.to_csv_record())?;
+    }
+    w.flush().map_err(csv::Error::from)?;
+
+    Ok(())
+}
+
+pub(crate) fn read_csv<T: DbcTable>(r: impl Read) -> Result<T, CsvError>
+where
+    T::Row: CsvRow,
+{
+    let mut r = csv::Reader::from_reader(r);
+
+    // columns can be in any order, so find the index of every column in the header
+    let header = r.headers()?.clone();
+    if let Some(column) = header.iter().find(|a| !T::Row::COLUMNS.contains(a)) {
+        return Err(CsvError::UnknownColumn {
+            column: column.to_string(),
+        });
+    }
+    let indices = T::Row::COLUMNS
+        .iter()
+        .map(|column| {
+            header
+                .iter()
+                .position(|a| a == *column)
+                .ok_or_else(|| CsvError::MissingColumn {
+                    column: column.to_string(),
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut rows = Vec::new();
+    for (i, record) in r.records().enumerate() {
+        let record = record?;
+        // the reader fails on records with a different length than the header
+        let values = indices
+            .iter()
+            .map(|&a| record.get(a).unwrap_or_default())
+            .collect::<Vec<_>>();
+
+        rows.push(T::Row::from_csv_record(&values).map_err(|e| e.with_row(i))?);
+    }
+
+    Ok(T::from_rows(rows))
+}
+
+#[cfg(any(feature = "vanilla", feature = "tbc", feature = "wrath"))]
+fn invalid(column: &str, value: &str) -> CsvError {
+    CsvError::InvalidValue {
+        row: 0,
+        column: column.to_string(),
+        value: value.to_string(),
+    }
+}
+
+#[cfg(any(feature = "vanilla", feature = "tbc", feature = "wrath"))]
+pub(crate) const fn column_count(expected: usize, actual: usize) -> CsvError {
+    CsvError::ColumnCount { expected, actual }
+}
+
+#[cfg(any(feature = "vanilla", feature = "tbc", feature = "wrath"))]
+pub(crate) fn int<T: std::str::FromStr>(column: &str, value: &str) -> Result<T, CsvError> {
+    value.trim().parse().map_err(|_| invalid(column, value))
+}
+
+// only vanilla tables have bools and flags
+#[cfg(feature = "vanilla")]
+pub(crate) fn bool(column: &str, value: &str) -> Result<bool, CsvError> {
+    match value.trim() {
+        "0" | "false" => Ok(false),
+        "1" | "true" => Ok(true),
+        _ => Err(invalid(column, value)),
+    }
+}
+
+#[cfg(any(feature = "vanilla", feature = "tbc", feature = "wrath"))]
+pub(crate) fn float(column: &str, value: &str) -> Result<f32, CsvError> {
+    value.trim().parse().map_err(|_| invalid(column, value))
+}
+
+#[cfg(any(feature = "vanilla", feature = "tbc", feature = "wrath"))]
+pub(crate) fn enumeration<E: TryFrom<R>, R: TryFrom<i64>>(
+    column: &str,
+    value: &str,
+    names: &[(&str, i64)],
+) -> Result<E, CsvError> {
+    enumerator(value.trim(), names)
+        .and_then(|v| R::try_from(v).ok())
+        .and_then(|v| E::try_from(v).ok())
+        .ok_or_else(|| invalid(column, value))
+}
+
+/// Flags separated by `|`, with an empty value being no flags.
+#[cfg(feature = "vanilla")]
+pub(crate) fn flags<T: TryFrom<i64>>(
+    column: &str,
+    value: &str,
+    names: &[(&str, i64)],
+) -> Result<T, CsvError> {
+    let mut flags = 0;
+    for part in value.split('|').map(str::trim).filter(|a| !a.is_empty()) {
+        flags |= enumerator(part, names).ok_or_else(|| invalid(column, value))?;
+    }
+
+    T::try_from(flags).map_err(|_| invalid(column, value))
+}
+
+/// Value of an enumerator from its name, or from the value as decimal or hexadecimal.
+#[cfg(any(feature = "vanilla", feature = "tbc", feature = "wrath"))]
+fn enumerator(value: &str, names: &[(&str, i64)]) -> Option<i64> {
+    if let Some(hex) = value.strip_prefix("0x") {
+        return i64::from_str_radix(hex, 16).ok();
+    }
+    if let Ok(v) = value.parse() {
+        return Some(v);
+    }
+
+    let normalized = |a: &str| {
+        a.chars()
+            .filter(|c| *c != '_')
+            .map(|c| c.to_ascii_lowercase())
+            .collect::<String>()
+    };
+    let value = normalized(value);
+
+    names
+        .iter()
+        .find(|(name, _)| normalized(name) == value)
+        .map(|(_, v)| *v)
+}
+
+/// Name of the enumerator with `value`, or the value itself for values without a name.
+#[cfg(any(feature = "vanilla", feature = "tbc", feature = "wrath"))]
+pub(crate) fn enumerator_name(value: i64, names: &[(&str, i64)]) -> String {
+    names
+        .iter()
+        .find(|(_, v)| *v == value)
+        .map_or_else(|| value.to_string(), |(name, _)| name.to_string())
+}
+
+/// Names of every flag in `value` separated by `|`, with any bits without a name at the end in hexadecimal.
+#[cfg(feature = "vanilla")]
+pub(crate) fn flag_names(value: i64, names: &[(&str, i64)]) -> String {
+    if value == 0 {
+        return enumerator_name(value, names);
+    }
+
+    let mut parts = Vec::new();
+    let mut remaining = value;
+    for (name, flag) in names {
+        if *flag != 0 && remaining & flag == *flag {
+            parts.push(name.to_string());
+            remaining &= !flag;
+        }
+    }
+    if remaining != 0 {
+        parts.push(format!("{remaining:#x}"));
+    }
+
+    parts.join("|")
+}
+
+#[cfg(all(test, feature = "vanilla"))]
+mod test {
+    use crate::fixtures::item_class_row;
+    use crate::vanilla_tables::chat_channels::{ChatChannels, ChatChannelsRow};
+    use crate::vanilla_tables::item_class::{ItemClass, ItemClassRow};
+    use crate::{CsvError, CsvRow, DbcTable};
+    use wow_world_base::vanilla::{DefaultChannelFlags, ItemWeaponClass};
+
+    #[test]
+    fn round_trip() {
+        let mut row = item_class_row(2, "Weapon, \"sharp\"");
+        row.subclass_map = 1;
+        row.item_class = ItemWeaponClass::Weapon;
+        row.class_name.de_de = "Waffe".to_string();
+        row.class_name.flags = 0xff01fe;
+        let table = ItemClass { rows: vec![row] };
+
+        let mut csv = Vec::new();
+        table.write_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        assert_eq!(
+            csv.lines().collect::<Vec<_>>(),
+            [
+                "id,subclass_map,item_class,class_name_en_gb,class_name_ko_kr,class_name_fr_fr,class_name_de_de,\
+                class_name_en_cn,class_name_en_tw,class_name_es_es,class_name_es_mx,class_name_flags",
+                "2,1,Weapon,\"Weapon, \"\"sharp\"\"\",,,Waffe,,,,,16712190",
+            ]
+        );
+
+        let new = ItemClass::read_csv(csv.as_bytes()).unwrap();
+        assert_eq!(new, table);
+
+        let mut dbc = Vec::new();
+        new.write(&mut dbc).unwrap();
+        assert_eq!(ItemClass::read(&mut dbc.as_slice()).unwrap(), table);
+    }
+
+    #[test]
+    fn enums_and_flags() {
+        // columns in a different order, names in a different case, and numbers instead of names
+        let csv = "item_class,id,subclass_map,class_name_en_gb,class_name_ko_kr,class_name_fr_fr,class_name_de_de,\
+            class_name_en_cn,class_name_en_tw,class_name_es_es,class_name_es_mx,class_name_flags
+            weapon,2,1,,,,,,,,,0
+            0,1,0,,,,,,,,,0
+        ";
+        let table = ItemClass::read_csv(csv.replace("    ", "").as_bytes()).unwrap();
+        assert_eq!(table.rows[0].item_class, ItemWeaponClass::Weapon);
+        assert_eq!(table.rows[1].item_class, ItemWeaponClass::Item);
+
+        let mut record = vec!["0"; ChatChannelsRow::COLUMNS.len()];
+        record[1] = "initial | zone_dependency|0x10000000";
+        let row = ChatChannelsRow::from_csv_record(&record).unwrap();
+        assert_eq!(
+            row.flags,
+            DefaultChannelFlags::new(
+                DefaultChannelFlags::INITIAL | DefaultChannelFlags::ZONE_DEPENDENCY | 0x1000_0000
+            )
+        );
+        let record = row.to_csv_record();
+        assert_eq!(record[1], "Initial|ZoneDependency|0x10000000");
+
+        let table = ChatChannels { rows: vec![row] };
+        let mut csv = Vec::new();
+        table.write_csv(&mut csv).unwrap();
+        assert_eq!(ChatChannels::read_csv(csv.as_slice()).unwrap(), table);
+    }
+
+    #[test]
+    fn errors() {
+        let header = ItemClassRow::COLUMNS.join(",");
+
+        let csv = format!("{header}\n2,1,Armor,,,,,,,,,0\n");
+        assert!(matches!(
+            ItemClass::read_csv(csv.as_bytes()),
+            Err(CsvError::InvalidValue { row: 0, column, value }) if column == "item_class" && value == "Armor"
+        ));
+
+        let csv = format!("{header}\n2,1,0,,,,,,,,,0\n3,x,0,,,,,,,,,0\n");
+        assert!(matches!(
+            ItemClass::read_csv(csv.as_bytes()),
+            Err(CsvError::InvalidValue { row: 1, column, .. }) if column == "subclass_map"
+        ));
+
+        assert!(matches!(
+            ItemClass::read_csv("id,subclass_map\n".as_bytes()),
+            Err(CsvError::MissingColumn { column }) if column == "item_class"
+        ));
+
+        let csv = format!("{header},notes\n");
+        assert!(matches!(
+            ItemClass::read_csv(csv.as_bytes()),
+            Err(CsvError::UnknownColumn { column }) if column == "notes"
+        ));
+    }
+}
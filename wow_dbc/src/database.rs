@@ -0,0 +1,220 @@
+use crate::{DbcError, DbcTable, TableInfo};
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+/// Directory of `.dbc` files, usually the `DBFilesClient` directory extracted from the MPQ files.
+///
+/// Used by the `DbcDatabase` of every table module ([`vanilla_tables::DbcDatabase`](crate::vanilla_tables::DbcDatabase))
+/// to find the files for tables.
+/// Files are matched to tables with [`TableInfo::matches_filename`], so case does not matter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DbcDirectory {
+    path: PathBuf,
+    files: Vec<PathBuf>,
+}
+
+impl DbcDirectory {
+    /// Lists the `.dbc` files in `path`. Subdirectories are not searched.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`std::fs::read_dir`].
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, std::io::Error> {
+        let path = path.as_ref().to_path_buf();
+
+        let mut files = Vec::new();
+        for entry in std::fs::read_dir(&path)? {
+            let file = entry?.path();
+
+            let is_dbc = file
+                .extension()
+                .is_some_and(|a| a.eq_ignore_ascii_case("dbc"));
+            if is_dbc && file.is_file() {
+                files.push(file);
+            }
+        }
+        files.sort();
+
+        Ok(Self { path, files })
+    }
+
+    /// The directory.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Every `.dbc` file in the directory, sorted by path.
+    pub fn files(&self) -> &[PathBuf] {
+        &self.files
+    }
+
+    /// The file for `table`, if it exists.
+    pub fn find(&self, table: &TableInfo) -> Option<&Path> {
+        self.files
+            .iter()
+            .find(|a| is_file_of(a, table))
+            .map(PathBuf::as_path)
+    }
+
+    /// Reads the file for `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbcError::Io`] with [`std::io::ErrorKind::NotFound`] if there is no file for `T`,
+    /// and the same errors as [`DbcTable::read`].
+    pub fn read<T: DbcTable>(&self) -> Result<T, DbcError> {
+        // the expansion does not matter for finding the file
+        let table = TableInfo::new::<T>(crate::Expansion::Vanilla);
+
+        let Some(file) = self.find(&table) else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("'{}' not found in '{}'", T::FILENAME, self.path.display()),
+            )
+            .into());
+        };
+
+        T::read(&mut BufReader::new(std::fs::File::open(file)?))
+    }
+
+    /// Returns the table in `cell`, or reads it and stores it in `cell` if it has not been read yet.
+    ///
+    /// If multiple threads read the same table at the same time, only the first table to be stored is kept.
+    #[cfg(any(feature = "vanilla", feature = "tbc", feature = "wrath"))]
+    pub(crate) fn get_or_read<'a, T: DbcTable>(
+        &self,
+        cell: &'a std::sync::OnceLock<T>,
+    ) -> Result<&'a T, DbcError> {
+        if let Some(table) = cell.get() {
+            return Ok(table);
+        }
+
+        let table = self.read::<T>()?;
+
+        Ok(cell.get_or_init(|| table))
+    }
+
+    /// The tables in `tables` that do not have a file in the directory.
+    pub fn missing<'a>(&self, tables: &'a [TableInfo]) -> Vec<&'a TableInfo> {
+        tables.iter().filter(|a| self.find(a).is_none()).collect()
+    }
+
+    /// The files in the directory that are not for any table in `tables`.
+    pub fn unknown(&self, tables: &[TableInfo]) -> Vec<&Path> {
+        self.files
+            .iter()
+            .filter(|file| !tables.iter().any(|table| is_file_of(file, table)))
+            .map(PathBuf::as_path)
+            .collect()
+    }
+}
+
+/// Reads one table into `D`, used by [`read_each`].
+#[cfg(any(feature = "vanilla", feature = "tbc", feature = "wrath"))]
+type ReadTable<D> = fn(&D) -> Result<(), DbcError>;
+
+/// Calls every function in `reads` with `db`, in parallel with the `rayon` feature.
+///
+/// Returns the error of the first function in `reads` that fails.
+/// Without the `rayon` feature the functions after it are not called.
+#[cfg(any(feature = "vanilla", feature = "tbc", feature = "wrath"))]
+pub(crate) fn read_each<D: Sync>(db: &D, reads: &[ReadTable<D>]) -> Result<(), DbcError> {
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+
+        // collected in order so that the error does not depend on which thread finishes first
+        let results = reads.par_iter().map(|read| read(db)).collect::<Vec<_>>();
+        results.into_iter().collect()
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    reads.iter().try_for_each(|read| read(db))
+}
+
+fn is_file_of(file: &Path, table: &TableInfo) -> bool {
+    file.file_name()
+        .is_some_and(|a| table.matches_filename(&a.to_string_lossy()))
+}
+
+#[cfg(all(test, feature = "vanilla"))]
+mod test {
+    use crate::fixtures::spell_icon_row;
+    use crate::vanilla_tables::item_class::ItemClassKey;
+    use crate::vanilla_tables::spell_icon::{SpellIcon, SpellIconKey};
+    use crate::vanilla_tables::DbcDatabase;
+    use crate::{DbcError, DbcTable, TableKey};
+
+    #[test]
+    fn database() {
+        let dir = std::env::temp_dir().join(format!("wow_dbc_database_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let table = SpellIcon {
+            rows: vec![spell_icon_row(1, "Interface\\Icons\\1")],
+        };
+        let mut b = Vec::new();
+        table.write(&mut b).unwrap();
+        std::fs::write(dir.join("spellicon.DBC"), &b).unwrap();
+        std::fs::write(dir.join("Unknown.dbc"), &b).unwrap();
+        std::fs::write(dir.join("SpellIcon.txt"), &b).unwrap();
+
+        let db = DbcDatabase::open(&dir).unwrap();
+
+        let first = db.spell_icon().unwrap();
+        assert_eq!(first, &table);
+        // read once and then cached
+        assert!(std::ptr::eq(first, db.get::<SpellIcon>().unwrap()));
+
+        assert!(matches!(
+            db.item_class(),
+            Err(DbcError::Io(e)) if e.kind() == std::io::ErrorKind::NotFound
+        ));
+
+        // a key of 0 does not reference anything and does not read the table
+        assert_eq!(
+            db.resolve(SpellIconKey::new(1)).unwrap(),
+            Some(&table.rows[0])
+        );
+        assert_eq!(db.resolve(SpellIconKey::new(0)).unwrap(), None);
+        assert_eq!(db.resolve(ItemClassKey::new(0)).unwrap(), None);
+        assert!(db.resolve(ItemClassKey::new(1)).is_err());
+        assert_eq!(SpellIconKey::new(1).resolve(&table), Some(&table.rows[0]));
+        assert_eq!(SpellIconKey::new(2).resolve(&table), None);
+
+        assert!(db.missing().iter().any(|a| a.name() == "ItemClass"));
+        assert!(db.missing().iter().all(|a| a.name() != "SpellIcon"));
+        assert_eq!(db.unknown(), [dir.join("Unknown.dbc").as_path()]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn read_all() {
+        let dir = std::env::temp_dir().join(format!("wow_dbc_read_all_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let table = SpellIcon {
+            rows: vec![spell_icon_row(1, "Interface\\Icons\\1")],
+        };
+        let mut b = Vec::new();
+        table.write(&mut b).unwrap();
+        std::fs::write(dir.join("SpellIcon.dbc"), &b).unwrap();
+
+        let db = DbcDatabase::open(&dir).unwrap();
+        db.read_all().unwrap();
+
+        // tables are cached, so the file is not needed anymore
+        std::fs::remove_file(dir.join("SpellIcon.dbc")).unwrap();
+        assert_eq!(db.spell_icon().unwrap(), &table);
+
+        std::fs::write(dir.join("SpellIcon.dbc"), &b).unwrap();
+        std::fs::write(dir.join("ItemClass.dbc"), &b[..10]).unwrap();
+
+        let db = DbcDatabase::open(&dir).unwrap();
+        assert!(matches!(db.read_all(), Err(DbcError::Malformed(_))));
+        assert!(db.item_class().is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
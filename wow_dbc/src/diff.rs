@@ -0,0 +1,189 @@
+use crate::fields::{identical, leaves};
+use crate::prelude::*;
+use crate::util::Map;
+use crate::{Indexable, RowFields, Value};
+
+/// Differences between two versions of a table. Created by [`Indexable::diff`].
+///
+/// Rows are matched by primary key.
+/// If multiple rows have the same key they are matched in the order they appear,
+/// and any extra rows are reported as added or removed.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TableDiff {
+    /// Name of the table, same as [`DbcTable::FILENAME`].
+    pub table: &'static str,
+    /// Rows that are only in the new table, in the order of the new table.
+    pub added: Vec<DiffRow>,
+    /// Rows that are only in the old table, in the order of the old table.
+    pub removed: Vec<DiffRow>,
+    /// Rows that are in both tables but have different values, in the order of the old table.
+    pub changed: Vec<RowDiff>,
+}
+
+/// A row that was added or removed.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DiffRow {
+    /// Primary key of the row.
+    pub id: i64,
+    /// Every field of the row.
+    pub values: Vec<DiffField>,
+}
+
+/// Name and value of a field in a [`DiffRow`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DiffField {
+    /// Name of the field.
+    pub field: &'static str,
+    /// Value of the field.
+    pub value: Value,
+}
+
+/// Changed fields of a row that is in both tables.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RowDiff {
+    /// Primary key of the row.
+    pub id: i64,
+    /// Every field that is different.
+    pub changes: Vec<FieldChange>,
+}
+
+/// A single value that is different between the old and new row.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FieldChange {
+    /// Name of the field.
+    ///
+    /// Strings of localized strings are compared separately and named after the field and locale, like `name.de_de`,
+    /// with `name.flags` for the flags.
+    /// Elements of arrays are named after the field and index, like `reagent[2]`.
+    pub field: String,
+    /// Value in the old table.
+    pub old: Value,
+    /// Value in the new table.
+    pub new: Value,
+}
+
+impl TableDiff {
+    pub(crate) fn new<T: Indexable>(old: &T, new: &T) -> Self {
+        let mut new_rows: Map<T::PrimaryKey, Vec<&T::Row>> = Map::new();
+        for row in new.rows().iter().rev() {
+            new_rows.entry(T::primary_key(row)).or_default().push(row);
+        }
+
+        let mut removed = Vec::new();
+        let mut changed = Vec::new();
+        for old_row in old.rows() {
+            let Some(new_row) = new_rows
+                .get_mut(&T::primary_key(old_row))
+                .and_then(Vec::pop)
+            else {
+                removed.push(DiffRow::new(old_row));
+                continue;
+            };
+
+            let changes = leaves(old_row)
+                .into_iter()
+                .zip(leaves(new_row))
+                .filter(|((_, old), (_, new))| !identical(old, new))
+                .map(|((field, old), (_, new))| FieldChange { field, old, new })
+                .collect::<Vec<_>>();
+
+            if !changes.is_empty() {
+                changed.push(RowDiff {
+                    id: id(old_row),
+                    changes,
+                });
+            }
+        }
+
+        // remaining rows are in reverse order, so find them through the new table
+        let added = new
+            .rows()
+            .iter()
+            .filter(|row| {
+                new_rows
+                    .get(&T::primary_key(row))
+                    .is_some_and(|a| a.iter().any(|a| core::ptr::eq(*a, *row)))
+            })
+            .map(DiffRow::new)
+            .collect();
+
+        Self {
+            table: T::FILENAME,
+            added,
+            removed,
+            changed,
+        }
+    }
+
+    /// Returns `true` if the tables have the same rows.
+    pub const fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl DiffRow {
+    fn new<R: RowFields>(row: &R) -> Self {
+        Self {
+            id: id(row),
+            values: R::FIELDS
+                .iter()
+                .zip(row.values())
+                .map(|(field, value)| DiffField { field, value })
+                .collect(),
+        }
+    }
+}
+
+/// Only tables with a primary key can be compared.
+fn id<R: RowFields>(row: &R) -> i64 {
+    row.id().unwrap_or_default()
+}
+
+#[cfg(all(test, feature = "vanilla"))]
+mod test {
+    use super::*;
+    use crate::fixtures::item_class_row;
+    use crate::vanilla_tables::item_class::ItemClass;
+
+    #[test]
+    fn diff() {
+        let old = ItemClass {
+            rows: vec![
+                item_class_row(1, "Weapon"),
+                item_class_row(2, "Armor"),
+                item_class_row(3, "Quest"),
+            ],
+        };
+        let mut new = old.clone();
+        assert!(old.diff(&new).is_empty());
+
+        new.remove(3_u32).unwrap();
+        new.push(item_class_row(4, "Key")).unwrap();
+        new.get_mut(1_u32).unwrap().class_name.de_de = "Waffe".to_string();
+
+        let diff = old.diff(&new);
+        assert_eq!(diff.table, "ItemClass.dbc");
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.removed[0].id, 3);
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].id, 4);
+        assert_eq!(diff.added[0].values[0].field, "id");
+        assert_eq!(diff.added[0].values[0].value, Value::Key(4));
+        assert_eq!(
+            diff.changed,
+            [RowDiff {
+                id: 1,
+                changes: vec![FieldChange {
+                    field: "class_name.de_de".to_string(),
+                    old: Value::String(String::new()),
+                    new: Value::String("Waffe".to_string()),
+                }],
+            }]
+        );
+    }
+}
@@ -0,0 +1,478 @@
+mod schema;
+#[cfg(feature = "xml")]
+mod xml;
+
+pub use schema::*;
+#[cfg(feature = "xml")]
+pub use xml::*;
+
+use crate::header::{read_header, read_records_and_string_block, DbcHeader};
+use crate::io::{Read, Write};
+use crate::prelude::*;
+use crate::util::{
+    read_f32_le, read_i16_le, read_i32_le, read_i8_le, read_u16_le, read_u32_le, read_u8_le,
+    str_at, StringCache,
+};
+use crate::{DbcError, FieldError, InvalidHeaderError, SchemaMismatchError};
+
+/// Table read with a [`Schema`] supplied at runtime instead of a generated definition.
+///
+/// Useful for modified tables that have extra or changed fields.
+///
+/// ```rust,ignore
+/// let schema = Schema::from_xml(&std::fs::read_to_string("ItemClass.xml")?, Expansion::Vanilla)?;
+/// let table = DynamicTable::read(schema, &mut std::fs::File::open("ItemClass.dbc")?)?;
+///
+/// for row in table.rows() {
+///     println!("{:?}", row.values);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DynamicTable {
+    schema: Schema,
+    rows: Vec<DynamicRow>,
+}
+
+/// Single row of a [`DynamicTable`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DynamicRow {
+    /// Values in the same order as [`Schema::fields`].
+    pub values: Vec<Value>,
+}
+
+/// Value of a single field in a [`DynamicRow`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Value {
+    /// All integer types, including bools, enums and flags.
+    Int(i64),
+    /// Floating point numbers.
+    Float(f32),
+    /// Strings.
+    String(String),
+    /// Localized strings, with either 8 or 16 strings depending on the [`FieldType`].
+    LocalizedString {
+        /// Strings in the same order as they are stored in the file, starting with `en_gb`.
+        strings: Vec<String>,
+        /// Flags after the strings.
+        flags: u32,
+    },
+    /// Arrays.
+    Array(Vec<Value>),
+    /// Primary and foreign keys.
+    Key(i64),
+}
+
+impl Value {
+    /// Returns the integer for [`Value::Int`] and [`Value::Key`].
+    pub const fn as_int(&self) -> Option<i64> {
+        match self {
+            Value::Int(v) | Value::Key(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Returns the float for [`Value::Float`].
+    pub const fn as_float(&self) -> Option<f32> {
+        match self {
+            Value::Float(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Returns the string for [`Value::String`].
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl DynamicTable {
+    /// Creates a table from `rows`.
+    pub const fn new(schema: Schema, rows: Vec<DynamicRow>) -> Self {
+        Self { schema, rows }
+    }
+
+    /// Read table from bytes using `schema`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`DbcTable::read`](crate::DbcTable::read),
+    /// with the record size and field count checked against `schema`.
+    ///
+    /// Will error with [`SchemaMismatchError::TooLarge`] if the row size or field count
+    /// of `schema` is too large for a header.
+    pub fn read(schema: Schema, b: &mut impl Read) -> Result<Self, DbcError> {
+        let (row_size, field_count) = header_sizes(&schema)?;
+
+        let header = read_header(b)?;
+
+        if header.record_size as usize != row_size {
+            return Err(DbcError::InvalidHeader(InvalidHeaderError::RecordSize {
+                expected: row_size as u32,
+                actual: header.record_size,
+            }));
+        }
+
+        if header.field_count as usize != field_count {
+            return Err(DbcError::InvalidHeader(InvalidHeaderError::FieldCount {
+                expected: field_count as u32,
+                actual: header.field_count,
+            }));
+        }
+
+        let (records, string_block) = read_records_and_string_block(b, &header)?;
+
+        let mut rows = Vec::with_capacity(records.len() / row_size.max(1));
+
+        // tables without fields have nothing to read
+        if row_size != 0 {
+            for (i, mut chunk) in records.chunks(row_size).enumerate() {
+                let mut values = Vec::with_capacity(schema.fields.len());
+                let mut offset = 0;
+
+                for field in &schema.fields {
+                    let value = read_value(&field.ty, &mut chunk, &string_block).map_err(|e| {
+                        DbcError::Field(Box::new(FieldError {
+                            table: schema.filename().into(),
+                            row: Some(i),
+                            field: field.name.clone().into(),
+                            field_offset: offset,
+                            record_size: row_size,
+                            error: e,
+                        }))
+                    })?;
+                    values.push(value);
+                    // every field fits since the row size did not overflow
+                    offset += field.ty.size().unwrap_or_default();
+                }
+
+                rows.push(DynamicRow { values });
+            }
+        }
+
+        Ok(Self { schema, rows })
+    }
+
+    /// Write to bytes.
+    ///
+    /// The string block will always start with a zero byte so that a string index of 0 is always an empty string.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Write::write_all`] wrapped in [`DbcError::Io`].
+    ///
+    /// Will error with [`SchemaMismatchError`] if a row does not match the schema,
+    /// an integer is out of range for its field, or the row size or field count of the schema is too large for a header.
+    pub fn write(&self, w: &mut impl Write) -> Result<(), DbcError> {
+        let (row_size, field_count) = header_sizes(&self.schema)?;
+
+        let mut string_cache = StringCache::new();
+        let mut b = Vec::with_capacity(self.rows.len().saturating_mul(row_size));
+
+        for (i, row) in self.rows.iter().enumerate() {
+            if row.values.len() != self.schema.fields.len() {
+                return Err(SchemaMismatchError::ValueCount {
+                    row: i,
+                    expected: self.schema.fields.len(),
+                    actual: row.values.len(),
+                }
+                .into());
+            }
+
+            for (field, value) in self.schema.fields.iter().zip(&row.values) {
+                write_value(&field.ty, value, &mut b, &mut string_cache).map_err(
+                    |InvalidValue| SchemaMismatchError::InvalidValue {
+                        row: i,
+                        field: field.name.clone(),
+                        value: value.clone(),
+                    },
+                )?;
+            }
+        }
+
+        let header = DbcHeader {
+            record_count: self.rows.len() as u32,
+            field_count: field_count as u32,
+            record_size: row_size as u32,
+            string_block_size: string_cache.size(),
+        };
+
+        w.write_all(&header.write_header())?;
+        w.write_all(&b)?;
+        w.write_all(string_cache.buffer())?;
+
+        Ok(())
+    }
+
+    /// The schema used for reading and writing.
+    pub const fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// Array of all rows in the order they were read.
+    pub fn rows(&self) -> &[DynamicRow] {
+        &self.rows
+    }
+
+    /// Mutable access to all rows, for changing, adding and removing rows.
+    pub const fn rows_mut(&mut self) -> &mut Vec<DynamicRow> {
+        &mut self.rows
+    }
+
+    /// Value of the field called `field` in row `index`.
+    pub fn value(&self, index: usize, field: &str) -> Option<&Value> {
+        let field = self.schema.field_index(field)?;
+
+        self.rows.get(index)?.values.get(field)
+    }
+
+    /// Gets the first row with the primary key, if the schema has a primary key and the row is present.
+    pub fn get(&self, key: i64) -> Option<&DynamicRow> {
+        let field = self.schema.primary_key()?;
+
+        self.rows
+            .iter()
+            .find(|a| a.values.get(field).and_then(Value::as_int) == Some(key))
+    }
+}
+
+fn read_value(ty: &FieldType, chunk: &mut &[u8], string_block: &[u8]) -> Result<Value, DbcError> {
+    Ok(match ty {
+        FieldType::U8 | FieldType::Bool => Value::Int(read_u8_le(chunk)?.into()),
+        FieldType::U16 => Value::Int(read_u16_le(chunk)?.into()),
+        FieldType::U32 | FieldType::Bool32 => Value::Int(read_u32_le(chunk)?.into()),
+        FieldType::I8 => Value::Int(read_i8_le(chunk)?.into()),
+        FieldType::I16 => Value::Int(read_i16_le(chunk)?.into()),
+        FieldType::I32 => Value::Int(read_i32_le(chunk)?.into()),
+        FieldType::Float => Value::Float(read_f32_le(chunk)?),
+        FieldType::String => Value::String(str_at(read_u32_le(chunk)?, string_block)?.to_string()),
+        FieldType::LocalizedString | FieldType::ExtendedLocalizedString => {
+            let amount = ty.localized_strings().unwrap_or_default();
+            let mut strings = Vec::with_capacity(amount);
+            for _ in 0..amount {
+                strings.push(str_at(read_u32_le(chunk)?, string_block)?.to_string());
+            }
+
+            Value::LocalizedString {
+                strings,
+                flags: read_u32_le(chunk)?,
+            }
+        }
+        FieldType::PrimaryKey(ty) | FieldType::ForeignKey { ty, .. } => {
+            match read_value(ty, chunk, string_block)? {
+                Value::Int(v) => Value::Key(v),
+                v => v,
+            }
+        }
+        FieldType::Array { ty, size } => {
+            let mut values = Vec::with_capacity(*size);
+            for _ in 0..*size {
+                values.push(read_value(ty, chunk, string_block)?);
+            }
+
+            Value::Array(values)
+        }
+    })
+}
+
+/// A value that does not match its field, which is returned as [`SchemaMismatchError::InvalidValue`] for the whole field.
+struct InvalidValue;
+
+fn write_value(
+    ty: &FieldType,
+    value: &Value,
+    b: &mut Vec<u8>,
+    string_cache: &mut StringCache,
+) -> Result<(), InvalidValue> {
+    match (ty, value) {
+        (FieldType::U8 | FieldType::Bool, Value::Int(v)) => {
+            b.extend_from_slice(&int::<u8>(*v)?.to_le_bytes());
+        }
+        (FieldType::U16, Value::Int(v)) => b.extend_from_slice(&int::<u16>(*v)?.to_le_bytes()),
+        (FieldType::U32 | FieldType::Bool32, Value::Int(v)) => {
+            b.extend_from_slice(&int::<u32>(*v)?.to_le_bytes());
+        }
+        (FieldType::I8, Value::Int(v)) => b.extend_from_slice(&int::<i8>(*v)?.to_le_bytes()),
+        (FieldType::I16, Value::Int(v)) => b.extend_from_slice(&int::<i16>(*v)?.to_le_bytes()),
+        (FieldType::I32, Value::Int(v)) => b.extend_from_slice(&int::<i32>(*v)?.to_le_bytes()),
+        (FieldType::Float, Value::Float(v)) => b.extend_from_slice(&v.to_le_bytes()),
+        (FieldType::String, Value::String(v)) => {
+            b.extend_from_slice(&string_cache.add_string(v).to_le_bytes());
+        }
+        (
+            FieldType::LocalizedString | FieldType::ExtendedLocalizedString,
+            Value::LocalizedString { strings, flags },
+        ) => {
+            let amount = ty.localized_strings().unwrap_or_default();
+            if strings.len() != amount {
+                return Err(InvalidValue);
+            }
+
+            for s in strings {
+                b.extend_from_slice(&string_cache.add_string(s).to_le_bytes());
+            }
+            b.extend_from_slice(&flags.to_le_bytes());
+        }
+        (FieldType::PrimaryKey(ty) | FieldType::ForeignKey { ty, .. }, Value::Key(v)) => {
+            write_value(ty, &Value::Int(*v), b, string_cache)?;
+        }
+        (FieldType::Array { ty, size }, Value::Array(values)) => {
+            if values.len() != *size {
+                return Err(InvalidValue);
+            }
+
+            for value in values {
+                write_value(ty, value, b, string_cache)?;
+            }
+        }
+        _ => return Err(InvalidValue),
+    }
+
+    Ok(())
+}
+
+fn int<T: TryFrom<i64>>(v: i64) -> Result<T, InvalidValue> {
+    T::try_from(v).map_err(|_| InvalidValue)
+}
+
+/// [`Schema::row_size`] and [`Schema::field_count`], or an error if they do not fit in a header.
+fn header_sizes(schema: &Schema) -> Result<(usize, usize), SchemaMismatchError> {
+    match (schema.row_size(), schema.field_count()) {
+        (Some(row_size), Some(field_count)) => Ok((row_size, field_count)),
+        _ => Err(SchemaMismatchError::TooLarge {
+            schema: schema.name.clone(),
+        }),
+    }
+}
+
+#[cfg(all(test, feature = "vanilla"))]
+mod test {
+    use super::*;
+    use crate::fixtures::item_class_row;
+    use crate::vanilla_tables::item_class::ItemClass;
+    use crate::DbcTable;
+    use wow_world_base::vanilla::ItemWeaponClass;
+
+    pub(super) fn item_class() -> ItemClass {
+        let mut consumable = item_class_row(1, "Consumable");
+        consumable.subclass_map = 3;
+
+        let mut weapon = item_class_row(2, "Weapon");
+        weapon.item_class = ItemWeaponClass::Weapon;
+        weapon.class_name.flags = 0xff01fe;
+
+        ItemClass {
+            rows: vec![consumable, weapon],
+        }
+    }
+
+    pub(super) fn check_item_class(schema: Schema) {
+        let table = item_class();
+        let mut b = Vec::new();
+        table.write(&mut b).unwrap();
+
+        let dynamic = DynamicTable::read(schema, &mut b.as_slice()).unwrap();
+        assert_eq!(dynamic.rows().len(), 2);
+        assert_eq!(dynamic.get(2).unwrap().values[2], Value::Int(1));
+        assert_eq!(dynamic.value(0, "subclass_map"), Some(&Value::Int(3)));
+
+        let Some(Value::LocalizedString { strings, flags }) = dynamic.value(1, "class_name") else {
+            panic!("expected localized string");
+        };
+        assert_eq!(strings[0], "Weapon");
+        assert_eq!(*flags, 0xff01fe);
+
+        let mut written = Vec::new();
+        dynamic.write(&mut written).unwrap();
+        assert_eq!(written, b);
+    }
+
+    #[test]
+    fn dynamic_item_class() {
+        let schema = Schema::new(
+            "ItemClass",
+            vec![
+                SchemaField::new("id", FieldType::PrimaryKey(Box::new(FieldType::U32))),
+                SchemaField::new("subclass_map", FieldType::U32),
+                SchemaField::new("item_class", FieldType::I32),
+                SchemaField::new("class_name", FieldType::LocalizedString),
+            ],
+        );
+        assert_eq!(schema.row_size(), Some(ItemClass::ROW_SIZE));
+        assert_eq!(schema.field_count(), Some(ItemClass::FIELD_COUNT));
+
+        check_item_class(schema.clone());
+
+        // extra fields are rejected by the header
+        let mut extra = schema.clone();
+        extra.fields.push(SchemaField::new("extra", FieldType::U32));
+        let mut b = Vec::new();
+        item_class().write(&mut b).unwrap();
+        assert!(matches!(
+            DynamicTable::read(extra, &mut b.as_slice()),
+            Err(DbcError::InvalidHeader(_))
+        ));
+
+        let mut table = DynamicTable::new(schema, Vec::new());
+        table.rows_mut().push(DynamicRow {
+            values: vec![Value::Key(1), Value::Int(-1), Value::Int(0), Value::Int(0)],
+        });
+        assert!(matches!(
+            table.write(&mut Vec::new()).unwrap_err(),
+            DbcError::Schema(SchemaMismatchError::InvalidValue { row: 0, field, .. }) if field == "subclass_map"
+        ));
+
+        table.rows_mut()[0].values.pop();
+        assert!(matches!(
+            table.write(&mut Vec::new()).unwrap_err(),
+            DbcError::Schema(SchemaMismatchError::ValueCount {
+                row: 0,
+                expected: 4,
+                actual: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn too_large() {
+        let array = FieldType::Array {
+            ty: Box::new(FieldType::ExtendedLocalizedString),
+            size: usize::MAX / 2,
+        };
+        assert_eq!(array.size(), None);
+        assert_eq!(array.field_count(), None);
+
+        // fits in a usize but not in the header
+        let schema = Schema::new(
+            "Large",
+            vec![
+                SchemaField::new(
+                    "a",
+                    FieldType::Array {
+                        ty: Box::new(FieldType::U32),
+                        size: 1 << 30,
+                    },
+                ),
+                SchemaField::new("b", FieldType::U32),
+            ],
+        );
+        assert_eq!(schema.row_size(), None);
+        assert_eq!(schema.field_count(), Some((1 << 30) + 1));
+
+        let mut b = Vec::new();
+        item_class().write(&mut b).unwrap();
+        assert!(matches!(
+            DynamicTable::read(schema.clone(), &mut b.as_slice()),
+            Err(DbcError::Schema(SchemaMismatchError::TooLarge { .. }))
+        ));
+        assert!(matches!(
+            DynamicTable::new(schema, Vec::new()).write(&mut Vec::new()),
+            Err(DbcError::Schema(SchemaMismatchError::TooLarge { .. }))
+        ));
+    }
+}
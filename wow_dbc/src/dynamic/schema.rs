@@ -0,0 +1,172 @@
+use crate::prelude::*;
+
+/// Layout of a table that is only known at runtime. Used by [`DynamicTable`](crate::DynamicTable).
+///
+/// Can be created by hand or parsed from the XML definitions used to generate the tables in this crate
+/// with `Schema::from_xml` when the `xml` feature is enabled.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Schema {
+    /// Name of the table _without_ `.dbc` at the end.
+    pub name: String,
+    /// Fields of every row in the order they appear in the file.
+    pub fields: Vec<SchemaField>,
+}
+
+impl Schema {
+    /// Creates a schema from `fields`.
+    pub fn new(name: impl Into<String>, fields: Vec<SchemaField>) -> Self {
+        Self {
+            name: name.into(),
+            fields,
+        }
+    }
+
+    /// The name of the DBC file _with_ `.dbc` at the end.
+    pub fn filename(&self) -> String {
+        format!("{}.dbc", self.name)
+    }
+
+    /// The number of fields per row, as written in the header.
+    ///
+    /// Returns [`None`] if the number does not fit in the `u32` of the header.
+    pub fn field_count(&self) -> Option<usize> {
+        header_sum(self.fields.iter().map(|a| a.ty.field_count()))
+    }
+
+    /// The size of each row in bytes.
+    ///
+    /// Returns [`None`] if the size does not fit in the `u32` of the header.
+    pub fn row_size(&self) -> Option<usize> {
+        header_sum(self.fields.iter().map(|a| a.ty.size()))
+    }
+
+    /// Index of the field called `name`.
+    pub fn field_index(&self, name: &str) -> Option<usize> {
+        self.fields.iter().position(|a| a.name == name)
+    }
+
+    /// Index of the primary key field, if the table has one.
+    pub fn primary_key(&self) -> Option<usize> {
+        self.fields
+            .iter()
+            .position(|a| matches!(a.ty, FieldType::PrimaryKey(_)))
+    }
+}
+
+/// Sum of `values` if it can be written in a header.
+fn header_sum(mut values: impl Iterator<Item = Option<usize>>) -> Option<usize> {
+    values
+        .try_fold(0_usize, |sum, a| sum.checked_add(a?))
+        .filter(|&sum| u32::try_from(sum).is_ok())
+}
+
+/// Single field of a [`Schema`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SchemaField {
+    /// Name of the field, same as the field of the generated row.
+    pub name: String,
+    /// Type of the field.
+    pub ty: FieldType,
+}
+
+impl SchemaField {
+    /// Creates a field.
+    pub fn new(name: impl Into<String>, ty: FieldType) -> Self {
+        Self {
+            name: name.into(),
+            ty,
+        }
+    }
+}
+
+/// Type of a [`SchemaField`].
+///
+/// Enums and flags are represented by their underlying integer type.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FieldType {
+    /// `uint8`.
+    U8,
+    /// `uint16`.
+    U16,
+    /// `uint32`.
+    U32,
+    /// `int8`.
+    I8,
+    /// `int16`.
+    I16,
+    /// `int32`.
+    I32,
+    /// `float`.
+    Float,
+    /// `bool`, stored as a single byte.
+    Bool,
+    /// `bool32`, stored as four bytes.
+    Bool32,
+    /// `string_ref`, offset into the string block.
+    String,
+    /// `string_ref_loc` for 1.12, 8 strings followed by flags.
+    LocalizedString,
+    /// `string_ref_loc` for 2.4.3 and 3.3.5, 16 strings followed by flags.
+    ExtendedLocalizedString,
+    /// Primary key of the table with the integer type.
+    PrimaryKey(Box<FieldType>),
+    /// Primary key of another table with the integer type.
+    ForeignKey {
+        /// Name of the referenced table _without_ `.dbc` at the end.
+        table: String,
+        /// Integer type of the key.
+        ty: Box<FieldType>,
+    },
+    /// Fixed size array.
+    Array {
+        /// Type of every element.
+        ty: Box<FieldType>,
+        /// Amount of elements.
+        size: usize,
+    },
+}
+
+impl FieldType {
+    /// Size of the field in bytes.
+    ///
+    /// Returns [`None`] if the size of an array overflows.
+    pub fn size(&self) -> Option<usize> {
+        Some(match self {
+            FieldType::U8 | FieldType::I8 | FieldType::Bool => 1,
+            FieldType::U16 | FieldType::I16 => 2,
+            FieldType::U32
+            | FieldType::I32
+            | FieldType::Float
+            | FieldType::Bool32
+            | FieldType::String => 4,
+            FieldType::LocalizedString => 9 * 4,
+            FieldType::ExtendedLocalizedString => 17 * 4,
+            FieldType::PrimaryKey(ty) | FieldType::ForeignKey { ty, .. } => ty.size()?,
+            FieldType::Array { ty, size } => ty.size()?.checked_mul(*size)?,
+        })
+    }
+
+    /// Amount of fields the type counts as in the header.
+    ///
+    /// Returns [`None`] if the amount for an array overflows.
+    pub fn field_count(&self) -> Option<usize> {
+        Some(match self {
+            FieldType::LocalizedString => 9,
+            FieldType::ExtendedLocalizedString => 17,
+            FieldType::Array { ty, size } => ty.field_count()?.checked_mul(*size)?,
+            _ => 1,
+        })
+    }
+
+    /// Amount of strings in localized strings.
+    pub(crate) const fn localized_strings(&self) -> Option<usize> {
+        match self {
+            FieldType::LocalizedString => Some(8),
+            FieldType::ExtendedLocalizedString => Some(16),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{Expansion, FieldType, Schema, SchemaField};
+use roxmltree::Node;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+
+impl Schema {
+    /// Parses the XML format used for the table definitions in `rxml/vanilla_xml`, `rxml/tbc_xml` and `rxml/wrath_xml` of the repository.
+    ///
+    /// `expansion` decides the amount of strings in localized strings.
+    /// Field names are changed in the same way as for the generated rows, so `type` becomes `ty`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SchemaError`] if the document is not valid XML or does not follow the format.
+    pub fn from_xml(xml: &str, expansion: Expansion) -> Result<Self, SchemaError> {
+        let doc = roxmltree::Document::parse(xml).map_err(|e| SchemaError::Xml(e.to_string()))?;
+
+        let dbc = doc.root_element();
+        if dbc.tag_name().name() != "dbc" {
+            return Err(SchemaError::MissingElement("dbc"));
+        }
+
+        let name = child_text(&dbc, "name")?;
+
+        let mut definers = Vec::new();
+        for definer in dbc
+            .children()
+            .filter(|a| matches!(a.tag_name().name(), "enum" | "flag"))
+        {
+            let ty = parse_type(child_text(&definer, "type")?, &[], expansion)?;
+            definers.push((child_text(&definer, "name")?, ty));
+        }
+
+        let mut fields = Vec::new();
+        for field in dbc.children().filter(|a| a.tag_name().name() == "field") {
+            let ty = parse_type(child_text(&field, "type")?, &definers, expansion)?;
+
+            let ty = if let Some(key) = field.children().find(|a| a.tag_name().name() == "key") {
+                match child_text(&key, "type")? {
+                    "primary" => FieldType::PrimaryKey(Box::new(ty)),
+                    "foreign" => FieldType::ForeignKey {
+                        table: child_text(&key, "parent")?.to_string(),
+                        ty: Box::new(ty),
+                    },
+                    key => return Err(SchemaError::InvalidKey(key.to_string())),
+                }
+            } else {
+                ty
+            };
+
+            fields.push(SchemaField::new(
+                field_name(child_text(&field, "name")?),
+                ty,
+            ));
+        }
+
+        let schema = Self::new(name, fields);
+        if schema.row_size().is_none() || schema.field_count().is_none() {
+            return Err(SchemaError::TooLarge);
+        }
+
+        Ok(schema)
+    }
+}
+
+/// Errors from parsing a [`Schema`] with [`Schema::from_xml`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaError {
+    /// The document is not valid XML.
+    Xml(String),
+    /// A required element is missing or empty.
+    MissingElement(&'static str),
+    /// A field has a type that is not a built in type, enum, or flag.
+    UnknownType(String),
+    /// A key has a type other than `primary` or `foreign`.
+    InvalidKey(String),
+    /// The row size or field count is too large for a header.
+    TooLarge,
+}
+
+impl Display for SchemaError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SchemaError::Xml(e) => e.fmt(f),
+            SchemaError::MissingElement(e) => write!(f, "missing element '{e}'"),
+            SchemaError::UnknownType(ty) => write!(f, "unknown type '{ty}'"),
+            SchemaError::InvalidKey(key) => write!(f, "invalid key type '{key}'"),
+            SchemaError::TooLarge => write!(f, "row size or field count is too large"),
+        }
+    }
+}
+
+impl Error for SchemaError {}
+
+fn child_text<'a>(node: &Node<'a, '_>, name: &'static str) -> Result<&'a str, SchemaError> {
+    node.children()
+        .find(|a| a.tag_name().name() == name)
+        .and_then(|a| a.text())
+        .ok_or(SchemaError::MissingElement(name))
+}
+
+fn parse_type(
+    ty: &str,
+    definers: &[(&str, FieldType)],
+    expansion: Expansion,
+) -> Result<FieldType, SchemaError> {
+    Ok(match ty {
+        "uint8" => FieldType::U8,
+        "uint16" => FieldType::U16,
+        "uint32" => FieldType::U32,
+        "int8" => FieldType::I8,
+        "int16" => FieldType::I16,
+        "int32" => FieldType::I32,
+        "float" => FieldType::Float,
+        "bool" => FieldType::Bool,
+        "bool32" => FieldType::Bool32,
+        "string_ref" => FieldType::String,
+        "string_ref_loc" => match expansion {
+            Expansion::Vanilla => FieldType::LocalizedString,
+            Expansion::Tbc | Expansion::Wrath => FieldType::ExtendedLocalizedString,
+        },
+        ty => {
+            if let Some((ty, size)) = ty.split_once('[') {
+                let size = size
+                    .strip_suffix(']')
+                    .and_then(|a| a.parse().ok())
+                    .ok_or_else(|| SchemaError::UnknownType(format!("{ty}[{size}")))?;
+
+                return Ok(FieldType::Array {
+                    ty: Box::new(parse_type(ty, definers, expansion)?),
+                    size,
+                });
+            }
+
+            definers
+                .iter()
+                .find(|(name, _)| *name == ty)
+                .map(|(_, ty)| ty.clone())
+                .ok_or_else(|| SchemaError::UnknownType(ty.to_string()))?
+        }
+    })
+}
+
+fn field_name(s: &str) -> &str {
+    match s.trim_end_matches('_') {
+        "type" => "ty",
+        "enum" => "en",
+        s => s,
+    }
+}
+
+#[cfg(all(test, feature = "vanilla"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn xml_item_class() {
+        // same as rxml/vanilla_xml/ItemClass.xml in the repository
+        let xml = r#"<?xml version="1.0"?>
+<dbc>
+    <name>ItemClass</name>
+    <field>
+        <type>uint32</type>
+        <name>id</name>
+        <key>
+            <type>primary</type>
+        </key>
+    </field>
+    <field>
+        <type>uint32</type>
+        <name>subclass_map</name>
+    </field>
+    <enum>
+        <type>int32</type>
+        <name>ItemWeaponClass</name>
+        <options>
+            <option name="item" value="0x00" />
+            <option name="weapon" value="0x01" />
+        </options>
+    </enum>
+    <field>
+        <type>ItemWeaponClass</type>
+        <name>item_class</name>
+    </field>
+    <field>
+        <type>string_ref_loc</type>
+        <name>class_name</name>
+    </field>
+</dbc>"#;
+
+        let schema = Schema::from_xml(xml, Expansion::Vanilla).unwrap();
+        assert_eq!(schema.name, "ItemClass");
+        assert_eq!(
+            schema.fields[0].ty,
+            FieldType::PrimaryKey(Box::new(FieldType::U32))
+        );
+        assert_eq!(schema.fields[2].ty, FieldType::I32);
+
+        crate::dynamic::test::check_item_class(schema);
+
+        assert_eq!(
+            Schema::from_xml(
+                "<dbc><name>A</name><field><type>u33</type><name>a</name></field></dbc>",
+                Expansion::Vanilla
+            ),
+            Err(SchemaError::UnknownType("u33".to_string()))
+        );
+        assert_eq!(
+            Schema::from_xml(
+                "<dbc><name>A</name><field><type>uint32[2000000000]</type><name>a</name></field></dbc>",
+                Expansion::Vanilla
+            ),
+            Err(SchemaError::TooLarge)
+        );
+    }
+}
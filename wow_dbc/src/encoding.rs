@@ -0,0 +1,412 @@
+use crate::header::{read_records_and_string_block, read_table_header};
+use crate::io::{Read, Write};
+use crate::prelude::*;
+use crate::util::{bytes_at, Map, StringCache};
+use crate::{DbcError, DbcTable};
+use alloc::borrow::Cow;
+
+/// How strings that are not valid UTF-8 are read with [`DbcTable::read_decoding`].
+///
+/// The files shipped with the game only contain UTF-8, but files edited by other tools can contain strings in a legacy codepage.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StringDecoding {
+    /// Return [`DbcError::String`] like [`DbcTable::read`].
+    #[default]
+    Strict,
+    /// Replace invalid sequences with `U+FFFD REPLACEMENT CHARACTER`.
+    Lossy,
+    /// Read strings that are not valid UTF-8 as the codepage.
+    ///
+    /// Strings that are valid UTF-8 are still read as UTF-8.
+    Codepage(Codepage),
+}
+
+impl StringDecoding {
+    /// The encoding for writing every string in the codepage of the decoding.
+    ///
+    /// [`StringDecoding::Lossy`] writes UTF-8, so the replaced sequences are not restored.
+    /// Use [`DecodedTable::write`] for writing only the strings that were decoded in the codepage.
+    pub const fn encoding(self) -> StringEncoding {
+        match self {
+            StringDecoding::Strict | StringDecoding::Lossy => StringEncoding::Utf8,
+            StringDecoding::Codepage(codepage) => StringEncoding::Codepage(codepage),
+        }
+    }
+}
+
+/// How strings are written with [`DbcTable::write_encoding`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StringEncoding {
+    /// Write UTF-8 like [`DbcTable::write`].
+    #[default]
+    Utf8,
+    /// Write every string in the codepage.
+    ///
+    /// Characters that the codepage can not represent are written as `?`.
+    Codepage(Codepage),
+}
+
+impl StringEncoding {
+    pub(crate) fn encode(self, s: &str) -> Cow<'_, [u8]> {
+        match self {
+            StringEncoding::Utf8 => Cow::Borrowed(s.as_bytes()),
+            StringEncoding::Codepage(codepage) => Cow::Owned(codepage.encode(s)),
+        }
+    }
+
+    /// Amount of bytes `c` takes up when encoded.
+    pub(crate) const fn encoded_len(self, c: char) -> usize {
+        match self {
+            StringEncoding::Utf8 => c.len_utf8(),
+            StringEncoding::Codepage(_) => 1,
+        }
+    }
+}
+
+/// Single byte legacy codepages used by Windows tools.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Codepage {
+    /// ISO 8859-1, where every byte is the Unicode code point of the same value.
+    Latin1,
+    /// Windows-1251, Cyrillic.
+    Windows1251,
+    /// Windows-1252, Western European.
+    Windows1252,
+}
+
+impl Codepage {
+    /// Decodes `b` into a string. Every byte is a valid character.
+    ///
+    /// Bytes that are undefined in Windows codepages are decoded as the C1 control character with the same value.
+    pub fn decode(self, b: &[u8]) -> String {
+        b.iter().map(|&a| self.decode_byte(a)).collect()
+    }
+
+    /// Encodes `s` into bytes, replacing characters that can not be represented with `?`.
+    pub fn encode(self, s: &str) -> Vec<u8> {
+        s.chars()
+            .map(|c| self.encode_char(c).unwrap_or(b'?'))
+            .collect()
+    }
+
+    const fn decode_byte(self, b: u8) -> char {
+        match (self, b) {
+            (Codepage::Windows1251, 0x80..=0xBF) => WINDOWS_1251[(b - 0x80) as usize],
+            (Codepage::Windows1251, 0xC0..=0xFF) => {
+                // 'А' to 'я' are in the same order as in Unicode
+                match char::from_u32(0x0410 + (b - 0xC0) as u32) {
+                    Some(c) => c,
+                    None => char::REPLACEMENT_CHARACTER,
+                }
+            }
+            (Codepage::Windows1252, 0x80..=0x9F) => WINDOWS_1252[(b - 0x80) as usize],
+            _ => b as char,
+        }
+    }
+
+    fn encode_char(self, c: char) -> Option<u8> {
+        let table: &[char] = match self {
+            Codepage::Latin1 => &[],
+            Codepage::Windows1251 => &WINDOWS_1251,
+            Codepage::Windows1252 => &WINDOWS_1252,
+        };
+
+        if let Some(i) = table.iter().position(|&a| a == c) {
+            return Some(0x80 + i as u8);
+        }
+
+        match (self, c as u32) {
+            (_, 0x00..=0x7F) => Some(c as u8),
+            (Codepage::Latin1, 0x80..=0xFF) | (Codepage::Windows1252, 0xA0..=0xFF) => Some(c as u8),
+            (Codepage::Windows1251, c @ 0x0410..=0x044F) => Some((c - 0x0410) as u8 + 0xC0),
+            _ => None,
+        }
+    }
+}
+
+/// Characters for bytes `0x80` to `0xBF`.
+const WINDOWS_1251: [char; 64] = [
+    'Ђ', 'Ѓ', '‚', 'ѓ', '„', '…', '†', '‡', '€', '‰', 'Љ', '‹', 'Њ', 'Ќ', 'Ћ', 'Џ', //
+    'ђ', '‘', '’', '“', '”', '•', '–', '—', '\u{98}', '™', 'љ', '›', 'њ', 'ќ', 'ћ', 'џ', //
+    '\u{A0}', 'Ў', 'ў', 'Ј', '¤', 'Ґ', '¦', '§', 'Ё', '©', 'Є', '«', '¬', '\u{AD}', '®',
+    'Ї', //
+    '°', '±', 'І', 'і', 'ґ', 'µ', '¶', '·', 'ё', '№', 'є', '»', 'ј', 'Ѕ', 'ѕ', 'ї', //
+];
+
+/// Characters for bytes `0x80` to `0x9F`, the rest are the same as [`Codepage::Latin1`].
+const WINDOWS_1252: [char; 32] = [
+    '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8D}', 'Ž',
+    '\u{8F}', //
+    '\u{90}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{9D}', 'ž',
+    'Ÿ', //
+];
+
+/// Table read with [`DbcTable::read_decoding`], together with every string that was not valid UTF-8.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedTable<T: DbcTable> {
+    /// The table with decoded strings.
+    pub table: T,
+    /// Every string reference that points to a string that is not valid UTF-8, in the order they were read.
+    pub invalid_strings: Vec<InvalidString>,
+    decoding: StringDecoding,
+}
+
+impl<T: DbcTable> DecodedTable<T> {
+    /// Read table from bytes, decoding strings that are not valid UTF-8 with `decoding`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`DbcTable::read`],
+    /// except for [`DbcError::String`] if `decoding` is not [`StringDecoding::Strict`].
+    pub fn read(b: &mut impl Read, decoding: StringDecoding) -> Result<Self, DbcError> {
+        let header = read_table_header::<T>(b)?;
+        let (mut records, mut string_block) = read_records_and_string_block(b, &header)?;
+
+        let mut invalid_strings = Vec::new();
+        if decoding != StringDecoding::Strict {
+            decode_strings::<T>(
+                &mut records,
+                &mut string_block,
+                decoding,
+                &mut invalid_strings,
+            );
+        }
+
+        let mut rows = Vec::with_capacity(records.len() / T::ROW_SIZE);
+        for (i, record) in records.chunks(T::ROW_SIZE).enumerate() {
+            let row = T::read_row_ref(record, &string_block).map_err(|e| e.with_row(i))?;
+            rows.push(row.into());
+        }
+
+        Ok(Self {
+            table: T::from_rows(rows),
+            invalid_strings,
+            decoding,
+        })
+    }
+
+    /// Write to bytes, writing the strings that were decoded with a [`Codepage`] back in the codepage.
+    ///
+    /// Every other string is written as UTF-8 like [`DbcTable::write`],
+    /// so files that contain both UTF-8 and codepage strings are written back the same way.
+    /// A decoded string is only written in the codepage if the string in the same row and field has not been changed,
+    /// so rows should not be moved.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Write::write_all`].
+    pub fn write(&self, w: &mut impl Write) -> Result<(), crate::io::Error> {
+        let StringDecoding::Codepage(codepage) = self.decoding else {
+            return self.table.write(w);
+        };
+
+        // strings are added to the cache in the same order as the string references
+        let strings = T::STRING_REFERENCE_OFFSETS.len();
+        let decoded = self
+            .invalid_strings
+            .iter()
+            .filter_map(|a| {
+                let field = T::STRING_REFERENCE_OFFSETS
+                    .iter()
+                    .position(|&offset| offset == a.field_offset)?;
+
+                Some((a.row * strings + field, a.decoded.clone()))
+            })
+            .collect();
+
+        self.table
+            .write_with(w, &mut StringCache::decoded(codepage, decoded))
+    }
+
+    /// The decoding used to read the table.
+    pub const fn decoding(&self) -> StringDecoding {
+        self.decoding
+    }
+}
+
+/// A string that was not valid UTF-8 and was decoded with a [`StringDecoding`] other than [`StringDecoding::Strict`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InvalidString {
+    /// Index of the row in the file.
+    pub row: usize,
+    /// Offset in bytes of the string reference from the start of the row.
+    pub field_offset: usize,
+    /// Offset of the string in the string block of the file.
+    pub offset: u32,
+    /// The string in the file, without the terminating NUL.
+    pub bytes: Vec<u8>,
+    /// The string after decoding.
+    pub decoded: String,
+}
+
+/// Appends the decoded version of every invalid string to the string block and points the records to them,
+/// so that the rows can be read as usual.
+///
+/// Invalid offsets are left alone in order to return the normal errors with field context when reading the row.
+fn decode_strings<T: DbcTable>(
+    records: &mut [u8],
+    string_block: &mut Vec<u8>,
+    decoding: StringDecoding,
+    invalid_strings: &mut Vec<InvalidString>,
+) {
+    let mut decoded_offsets = Map::new();
+
+    for (i, record) in records.chunks_mut(T::ROW_SIZE).enumerate() {
+        for &field_offset in T::STRING_REFERENCE_OFFSETS {
+            let reference = &mut record[field_offset..field_offset + 4];
+            let offset =
+                u32::from_le_bytes([reference[0], reference[1], reference[2], reference[3]]);
+
+            let Ok(bytes) = bytes_at(offset, string_block) else {
+                continue;
+            };
+            if core::str::from_utf8(bytes).is_ok() {
+                continue;
+            }
+
+            let bytes = bytes.to_vec();
+            let decoded = match decoding {
+                StringDecoding::Strict => unreachable!("strict decoding does not decode strings"),
+                StringDecoding::Lossy => String::from_utf8_lossy(&bytes).into_owned(),
+                StringDecoding::Codepage(codepage) => codepage.decode(&bytes),
+            };
+
+            let new_offset = *decoded_offsets.entry(offset).or_insert_with(|| {
+                let new_offset = string_block.len() as u32;
+                string_block.extend_from_slice(decoded.as_bytes());
+                string_block.push(0);
+
+                new_offset
+            });
+            reference.copy_from_slice(&new_offset.to_le_bytes());
+
+            invalid_strings.push(InvalidString {
+                row: i,
+                field_offset,
+                offset,
+                bytes,
+                decoded,
+            });
+        }
+    }
+}
+
+#[cfg(all(test, feature = "vanilla"))]
+mod test {
+    use super::*;
+    use crate::header::DbcHeader;
+    use crate::vanilla_tables::spell_icon::SpellIcon;
+
+    #[test]
+    fn codepages() {
+        for codepage in [
+            Codepage::Latin1,
+            Codepage::Windows1251,
+            Codepage::Windows1252,
+        ] {
+            let b = (1..=0xFF_u8).collect::<Vec<_>>();
+            let s = codepage.decode(&b);
+            assert_eq!(s.chars().count(), b.len());
+            assert_eq!(codepage.encode(&s), b);
+        }
+
+        assert_eq!(Codepage::Latin1.decode(b"caf\xe9"), "café");
+        assert_eq!(Codepage::Windows1252.decode(b"\x80"), "€");
+        assert_eq!(
+            Codepage::Windows1251.decode(b"\xcf\xf0\xe8\xe2\xe5\xf2"),
+            "Привет"
+        );
+        assert_eq!(Codepage::Windows1251.encode("Ёж €"), b"\xa8\xe6 \x88");
+        assert_eq!(Codepage::Latin1.encode("€"), b"?");
+    }
+
+    #[test]
+    fn decode_latin1() {
+        let string_block = b"\0caf\xe9\0Icon\0".to_vec();
+        let header = DbcHeader {
+            record_count: 3,
+            field_count: SpellIcon::FIELD_COUNT as u32,
+            record_size: SpellIcon::ROW_SIZE as u32,
+            string_block_size: string_block.len() as u32,
+        };
+
+        let mut b = header.write_header().to_vec();
+        for (id, offset) in [(1_u32, 1_u32), (2, 6), (3, 1)] {
+            b.extend_from_slice(&id.to_le_bytes());
+            b.extend_from_slice(&offset.to_le_bytes());
+        }
+        b.extend_from_slice(&string_block);
+
+        assert!(SpellIcon::read(&mut b.as_slice()).is_err());
+        assert!(matches!(
+            SpellIcon::read_decoding(&mut b.as_slice(), StringDecoding::Strict),
+            Err(DbcError::Field(e)) if matches!(e.error, DbcError::String(_))
+        ));
+
+        let lossy = SpellIcon::read_decoding(&mut b.as_slice(), StringDecoding::Lossy).unwrap();
+        assert_eq!(lossy.table.rows[0].texture_file, "caf\u{FFFD}");
+
+        let latin1 = SpellIcon::read_decoding(
+            &mut b.as_slice(),
+            StringDecoding::Codepage(Codepage::Latin1),
+        )
+        .unwrap();
+        assert_eq!(latin1.table.rows[0].texture_file, "café");
+        assert_eq!(latin1.table.rows[1].texture_file, "Icon");
+        assert_eq!(latin1.table.rows[2].texture_file, "café");
+
+        let rows = latin1
+            .invalid_strings
+            .iter()
+            .map(|a| (a.row, a.field_offset, a.offset, a.bytes.as_slice()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            [(0, 4, 1, b"caf\xe9".as_slice()), (2, 4, 1, b"caf\xe9")]
+        );
+
+        let mut written = Vec::new();
+        latin1.write(&mut written).unwrap();
+        assert_eq!(written, b);
+    }
+
+    #[test]
+    fn mixed_utf8_and_codepage() {
+        // the same string as UTF-8 and as Latin-1
+        let string_block = b"\0caf\xc3\xa9\0caf\xe9\0".to_vec();
+        let header = DbcHeader {
+            record_count: 2,
+            field_count: SpellIcon::FIELD_COUNT as u32,
+            record_size: SpellIcon::ROW_SIZE as u32,
+            string_block_size: string_block.len() as u32,
+        };
+
+        let mut b = header.write_header().to_vec();
+        for (id, offset) in [(1_u32, 1_u32), (2, 7)] {
+            b.extend_from_slice(&id.to_le_bytes());
+            b.extend_from_slice(&offset.to_le_bytes());
+        }
+        b.extend_from_slice(&string_block);
+
+        let mut latin1 = SpellIcon::read_decoding(
+            &mut b.as_slice(),
+            StringDecoding::Codepage(Codepage::Latin1),
+        )
+        .unwrap();
+        assert_eq!(latin1.table.rows[0].texture_file, "café");
+        assert_eq!(latin1.table.rows[1].texture_file, "café");
+        assert_eq!(latin1.invalid_strings.len(), 1);
+
+        let mut written = Vec::new();
+        latin1.write(&mut written).unwrap();
+        assert_eq!(written, b);
+
+        // changed strings are written as UTF-8
+        latin1.table.rows[1].texture_file = "caf\u{e9}s".to_string();
+        let mut written = Vec::new();
+        latin1.write(&mut written).unwrap();
+        let read = SpellIcon::read(&mut written.as_slice()).unwrap();
+        assert_eq!(read.rows[0].texture_file, "café");
+        assert_eq!(read.rows[1].texture_file, "cafés");
+    }
+}
@@ -1,36 +1,71 @@
-use std::error::Error;
-use std::fmt::{Display, Formatter};
-use std::string::FromUtf8Error;
+use crate::header::HEADER_SIZE;
+use crate::prelude::*;
+use alloc::borrow::Cow;
+use alloc::string::FromUtf8Error;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+#[cfg(any(feature = "vanilla", feature = "tbc", feature = "wrath"))]
 use wow_world_base::EnumError;
 
 /// Main error enum. Returned from [`crate::DbcTable::read`].
 #[derive(Debug)]
 pub enum DbcError {
     /// IO errors.
-    Io(std::io::Error),
+    Io(crate::io::Error),
     /// Errors from invalid enum values.
+    #[cfg(any(feature = "vanilla", feature = "tbc", feature = "wrath"))]
     InvalidEnum(EnumError),
     /// Errors from converting bytes to strings.
     String(FromUtf8Error),
     /// Errors related to headers.
     InvalidHeader(InvalidHeaderError),
+    /// Errors from reading a specific field of a row, with the location of the field.
+    Field(Box<FieldError>),
+    /// Errors from files that do not contain what the header says.
+    Malformed(MalformedError),
+    /// Errors from a [`Schema`](crate::Schema) that does not fit in a header or does not match the rows of a [`DynamicTable`](crate::DynamicTable).
+    Schema(SchemaMismatchError),
+}
+
+impl DbcError {
+    /// Adds the index of the row to errors from [`DbcTable::read_row_ref`](crate::DbcTable::read_row_ref).
+    pub(crate) fn with_row(self, row: usize) -> Self {
+        match self {
+            DbcError::Field(mut e) => {
+                e.row = Some(row);
+                DbcError::Field(e)
+            }
+            e => e,
+        }
+    }
 }
 
 impl Display for DbcError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
             DbcError::Io(i) => i.fmt(f),
+            #[cfg(any(feature = "vanilla", feature = "tbc", feature = "wrath"))]
             DbcError::InvalidEnum(i) => i.fmt(f),
             DbcError::String(i) => i.fmt(f),
             DbcError::InvalidHeader(i) => i.fmt(f),
+            DbcError::Field(i) => i.fmt(f),
+            DbcError::Malformed(i) => i.fmt(f),
+            DbcError::Schema(i) => i.fmt(f),
         }
     }
 }
 
-impl Error for DbcError {}
+impl Error for DbcError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DbcError::Field(e) => Some(&e.error),
+            _ => None,
+        }
+    }
+}
 
-impl From<std::io::Error> for DbcError {
-    fn from(i: std::io::Error) -> Self {
+impl From<crate::io::Error> for DbcError {
+    fn from(i: crate::io::Error) -> Self {
         Self::Io(i)
     }
 }
@@ -47,6 +82,403 @@ impl From<InvalidHeaderError> for DbcError {
     }
 }
 
+impl From<MalformedError> for DbcError {
+    fn from(e: MalformedError) -> Self {
+        Self::Malformed(e)
+    }
+}
+
+impl From<SchemaMismatchError> for DbcError {
+    fn from(e: SchemaMismatchError) -> Self {
+        Self::Schema(e)
+    }
+}
+
+/// Error from adding a row to a table that already has a row with the same primary key.
+///
+/// Returned by [`Indexable::push`](crate::Indexable::push) and [`Indexable::insert_sorted`](crate::Indexable::insert_sorted).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateKeyError<R> {
+    /// Name of the table, same as [`DbcTable::FILENAME`](crate::DbcTable::FILENAME).
+    pub table: &'static str,
+    /// The row that was not added.
+    pub row: R,
+}
+
+impl<R> DuplicateKeyError<R> {
+    pub(crate) const fn new<T: crate::DbcTable<Row = R>>(row: R) -> Self {
+        Self {
+            table: T::FILENAME,
+            row,
+        }
+    }
+}
+
+impl<R> Display for DuplicateKeyError<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "'{}' already has a row with the same primary key",
+            self.table
+        )
+    }
+}
+
+impl<R: core::fmt::Debug> Error for DuplicateKeyError<R> {}
+
+/// Errors from using a [`Schema`](crate::Schema) with a [`DynamicTable`](crate::DynamicTable).
+#[derive(Debug, Clone, PartialEq)]
+pub enum SchemaMismatchError {
+    /// The row size or field count of the schema is too large for a header.
+    TooLarge {
+        /// Name of the schema.
+        schema: String,
+    },
+    /// A row has a different amount of values than the schema has fields.
+    ValueCount {
+        /// Index of the row.
+        row: usize,
+        /// Amount of fields in the schema.
+        expected: usize,
+        /// Amount of values in the row.
+        actual: usize,
+    },
+    /// A value has the wrong type, is out of range, or has the wrong amount of strings or elements for its field.
+    InvalidValue {
+        /// Index of the row.
+        row: usize,
+        /// Name of the field.
+        field: String,
+        /// The value of the field.
+        value: crate::Value,
+    },
+}
+
+impl Display for SchemaMismatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SchemaMismatchError::TooLarge { schema } => {
+                write!(f, "schema '{}' is too large for a header", schema)
+            }
+            SchemaMismatchError::ValueCount {
+                row,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "row {} has '{}' values but the schema has '{}' fields",
+                row, actual, expected
+            ),
+            SchemaMismatchError::InvalidValue { row, field, value } => write!(
+                f,
+                "row {} field '{}' has invalid value {:?}",
+                row, field, value
+            ),
+        }
+    }
+}
+
+impl Error for SchemaMismatchError {}
+
+/// Errors from adding a record with [`RawDbc::push_record`](crate::RawDbc::push_record).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PushRecordError {
+    /// The file has records with a size of 0 bytes, which [`RawDbc::read`](crate::RawDbc::read) does not accept.
+    EmptyRecords,
+    /// The record does not have the record size of the file.
+    RecordSize {
+        /// Size of every record in the file.
+        expected: u32,
+        /// Size of the record.
+        actual: usize,
+    },
+    /// The file already has as many records as the header can store.
+    TooManyRecords,
+}
+
+impl Display for PushRecordError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PushRecordError::EmptyRecords => {
+                write!(f, "records with a size of 0 bytes can not be read")
+            }
+            PushRecordError::RecordSize { expected, actual } => write!(
+                f,
+                "invalid record size. Expected '{}', got '{}'",
+                expected, actual
+            ),
+            PushRecordError::TooManyRecords => write!(f, "too many records for the header"),
+        }
+    }
+}
+
+impl Error for PushRecordError {}
+
+/// Errors from setting fields through [`RowFields`](crate::RowFields).
+#[derive(Debug, Clone, PartialEq)]
+pub enum SetFieldError {
+    /// The row does not have a field with the name.
+    UnknownField {
+        /// Name of the field, including any locale or array index.
+        field: String,
+    },
+    /// The value has the wrong type or is out of range for the field.
+    InvalidValue {
+        /// Name of the field, including any locale or array index.
+        field: String,
+        /// The value that could not be set.
+        value: crate::Value,
+    },
+    /// A field that is needed to create a row was not given.
+    MissingField {
+        /// Name of the field.
+        field: String,
+    },
+    /// The amount of values given to [`RowFields::from_values`](crate::RowFields::from_values) is wrong.
+    FieldCount {
+        /// Amount of fields in the row.
+        expected: usize,
+        /// Amount of values given.
+        actual: usize,
+    },
+}
+
+impl Display for SetFieldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SetFieldError::UnknownField { field } => write!(f, "unknown field '{}'", field),
+            SetFieldError::MissingField { field } => write!(f, "missing field '{}'", field),
+            SetFieldError::InvalidValue { field, value } => {
+                write!(f, "invalid value for field '{}': {:?}", field, value)
+            }
+            SetFieldError::FieldCount { expected, actual } => write!(
+                f,
+                "invalid amount of fields. Expected '{}', got '{}'",
+                expected, actual
+            ),
+        }
+    }
+}
+
+impl Error for SetFieldError {}
+
+/// Errors from applying a [`Patch`](crate::Patch) with [`Indexable::apply_patch`](crate::Indexable::apply_patch).
+#[derive(Debug, Clone, PartialEq)]
+pub enum PatchError {
+    /// A row to delete does not exist.
+    DeleteMissingRow {
+        /// Primary key of the row.
+        id: i64,
+    },
+    /// A row to set a field in does not exist.
+    SetMissingRow {
+        /// Primary key of the row.
+        id: i64,
+        /// Name of the field.
+        field: String,
+    },
+    /// A field does not have the old value that the patch expects, usually because the table has changed since the patch was made.
+    OldValueMismatch {
+        /// Primary key of the row.
+        id: i64,
+        /// Name of the field.
+        field: String,
+        /// The old value in the patch.
+        expected: crate::Value,
+        /// The value in the table.
+        actual: crate::Value,
+    },
+    /// A row to upsert has missing, unknown, or invalid fields.
+    InvalidUpsert {
+        /// Index of the row in [`Patch::upsert`](crate::Patch::upsert).
+        index: usize,
+        /// The error from creating the row.
+        error: SetFieldError,
+    },
+    /// A value to set is invalid for the field.
+    InvalidSet {
+        /// Primary key of the row.
+        id: i64,
+        /// The error from setting the field.
+        error: SetFieldError,
+    },
+}
+
+impl Display for PatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PatchError::DeleteMissingRow { id } => {
+                write!(f, "can not delete row '{}' since it does not exist", id)
+            }
+            PatchError::SetMissingRow { id, field } => write!(
+                f,
+                "can not set field '{}' of row '{}' since the row does not exist",
+                field, id
+            ),
+            PatchError::OldValueMismatch {
+                id,
+                field,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "field '{}' of row '{}' was expected to be {:?}, but is {:?}",
+                field, id, expected, actual
+            ),
+            PatchError::InvalidUpsert { index, error } => {
+                write!(f, "upserted row '{}': {}", index, error)
+            }
+            PatchError::InvalidSet { id, error } => write!(f, "row '{}': {}", id, error),
+        }
+    }
+}
+
+impl Error for PatchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PatchError::InvalidUpsert { error, .. } | PatchError::InvalidSet { error, .. } => {
+                Some(error)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Errors from reading and writing CSV files with [`DbcTable::read_csv`](crate::DbcTable::read_csv)
+/// and [`DbcTable::write_csv`](crate::DbcTable::write_csv).
+#[cfg(feature = "csv")]
+#[derive(Debug)]
+pub enum CsvError {
+    /// Errors from the CSV reader or writer, including IO errors.
+    Csv(csv::Error),
+    /// The header does not have a column for the table.
+    MissingColumn {
+        /// Name of the column.
+        column: String,
+    },
+    /// The header has a column that is not in the table.
+    UnknownColumn {
+        /// Name of the column.
+        column: String,
+    },
+    /// A record does not have the same amount of columns as the table.
+    ColumnCount {
+        /// Amount of columns in the table.
+        expected: usize,
+        /// Amount of columns in the record.
+        actual: usize,
+    },
+    /// A value can not be parsed as the type of its column.
+    InvalidValue {
+        /// Index of the record, not counting the header.
+        row: usize,
+        /// Name of the column.
+        column: String,
+        /// The value in the file.
+        value: String,
+    },
+}
+
+#[cfg(feature = "csv")]
+impl CsvError {
+    /// Adds the index of the record to errors from [`CsvRow::from_csv_record`](crate::CsvRow::from_csv_record).
+    pub(crate) fn with_row(self, row: usize) -> Self {
+        match self {
+            CsvError::InvalidValue { column, value, .. } => CsvError::InvalidValue { row, column, value },
+            e => e,
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+impl Display for CsvError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CsvError::Csv(e) => e.fmt(f),
+            CsvError::MissingColumn { column } => write!(f, "missing column '{}'", column),
+            CsvError::UnknownColumn { column } => write!(f, "unknown column '{}'", column),
+            CsvError::ColumnCount { expected, actual } => write!(
+                f,
+                "record has {} columns, but {} were expected",
+                actual, expected
+            ),
+            CsvError::InvalidValue { row, column, value } => write!(
+                f,
+                "invalid value '{}' for column '{}' in row {}",
+                value, column, row
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+impl Error for CsvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CsvError::Csv(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+impl From<csv::Error> for CsvError {
+    fn from(e: csv::Error) -> Self {
+        Self::Csv(e)
+    }
+}
+
+/// Location of an error that happened while reading a field.
+#[derive(Debug)]
+pub struct FieldError {
+    /// Name of the table, same as [`DbcTable::FILENAME`](crate::DbcTable::FILENAME).
+    pub table: Cow<'static, str>,
+    /// Index of the row in the file.
+    ///
+    /// Is [`None`] if the row was read directly through [`DbcTable::read_row_ref`](crate::DbcTable::read_row_ref).
+    pub row: Option<usize>,
+    /// Name of the field in the row, with the index for elements of arrays like `reagent[2]`.
+    pub field: Cow<'static, str>,
+    /// Offset in bytes of the field from the start of the row.
+    pub field_offset: usize,
+    /// Size in bytes of every row.
+    pub record_size: usize,
+    /// The error from reading the field.
+    pub error: DbcError,
+}
+
+impl FieldError {
+    /// Offset in bytes of the row from the start of the file, if the row is known.
+    pub const fn record_offset(&self) -> Option<usize> {
+        match self.row {
+            Some(row) => Some(HEADER_SIZE + row * self.record_size),
+            None => None,
+        }
+    }
+}
+
+impl Display for FieldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "'{}'", self.table)?;
+
+        if let (Some(row), Some(record_offset)) = (self.row, self.record_offset()) {
+            write!(f, " row {} (record offset {:#x})", row, record_offset)?;
+        }
+
+        write!(
+            f,
+            " field '{}' (field offset {:#x}): {}",
+            self.field, self.field_offset, self.error
+        )
+    }
+}
+
+impl Error for FieldError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
 /// Errors from reading the header of the DBC file.
 #[derive(Debug)]
 pub enum InvalidHeaderError {
@@ -72,7 +504,7 @@ pub enum InvalidHeaderError {
 }
 
 impl Display for InvalidHeaderError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
             InvalidHeaderError::RecordSize { expected, actual } => {
                 write!(
@@ -95,8 +527,186 @@ impl Display for InvalidHeaderError {
 
 impl Error for InvalidHeaderError {}
 
+/// Errors from files that are truncated or reference data outside of the file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MalformedError {
+    /// The file ended before the header, all records and the string block were read.
+    Truncated {
+        /// Amount of bytes that were expected after the current position.
+        expected: u64,
+        /// Amount of bytes that were actually available.
+        actual: u64,
+    },
+    /// The size of all records is too large to be addressed.
+    RecordsTooLarge {
+        /// Amount of records in the header.
+        record_count: u32,
+        /// Size of every record in the header.
+        record_size: u32,
+    },
+    /// The header has records with a size of 0 bytes.
+    EmptyRecords {
+        /// Amount of records in the header.
+        record_count: u32,
+    },
+    /// A string offset points outside of the string block.
+    StringOffsetOutOfBounds {
+        /// Offset into the string block.
+        offset: u32,
+        /// Size of the string block.
+        string_block_size: u32,
+    },
+    /// A string does not end with a NUL byte before the end of the string block.
+    UnterminatedString {
+        /// Offset into the string block.
+        offset: u32,
+    },
+}
+
+impl Display for MalformedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MalformedError::Truncated { expected, actual } => write!(
+                f,
+                "file is truncated. Expected '{}' more bytes, got '{}'",
+                expected, actual
+            ),
+            MalformedError::RecordsTooLarge {
+                record_count,
+                record_size,
+            } => write!(
+                f,
+                "records are too large: '{}' records of '{}' bytes",
+                record_count, record_size
+            ),
+            MalformedError::EmptyRecords { record_count } => write!(
+                f,
+                "header has '{}' records with a size of 0 bytes",
+                record_count
+            ),
+            MalformedError::StringOffsetOutOfBounds {
+                offset,
+                string_block_size,
+            } => write!(
+                f,
+                "string offset '{}' is outside of string block with size '{}'",
+                offset, string_block_size
+            ),
+            MalformedError::UnterminatedString { offset } => {
+                write!(f, "string at offset '{}' is not terminated", offset)
+            }
+        }
+    }
+}
+
+impl Error for MalformedError {}
+
+#[cfg(any(feature = "vanilla", feature = "tbc", feature = "wrath"))]
 impl From<EnumError> for DbcError {
     fn from(i: EnumError) -> Self {
         Self::InvalidEnum(i)
     }
 }
+
+#[cfg(all(test, feature = "vanilla"))]
+mod test {
+    use crate::fixtures::spell_icon_row;
+    use crate::vanilla_tables::spell_icon::SpellIcon;
+    use crate::header::DbcHeader;
+    use crate::{DbcError, DbcTable, DbcTableRef, MalformedError};
+
+    #[test]
+    fn field_context() {
+        let table = SpellIcon {
+            rows: vec![spell_icon_row(1, "a"), spell_icon_row(2, "b")],
+        };
+        let mut b = Vec::new();
+        table.write(&mut b).unwrap();
+
+        // string block is "\0a\0b\0", make "b" invalid UTF-8
+        let len = b.len();
+        b[len - 2] = 0xff;
+
+        let check = |e: DbcError| {
+            let DbcError::Field(e) = e else {
+                panic!("expected field error, got {e:?}");
+            };
+            assert_eq!(e.table, "SpellIcon.dbc");
+            assert_eq!(e.row, Some(1));
+            assert_eq!(e.field, "texture_file");
+            assert_eq!(e.field_offset, 4);
+            assert_eq!(e.record_offset(), Some(20 + 8));
+            assert!(matches!(e.error, DbcError::String(_)));
+            assert_eq!(
+                e.to_string(),
+                format!(
+                    "'SpellIcon.dbc' row 1 (record offset 0x1c) field 'texture_file' (field offset 0x4): {}",
+                    e.error
+                )
+            );
+        };
+
+        check(SpellIcon::read(&mut b.as_slice()).unwrap_err());
+
+        let borrowed = DbcTableRef::<SpellIcon>::new(&b).unwrap();
+        check(borrowed.get(1).unwrap().unwrap_err());
+        check(borrowed.iter().nth(1).unwrap().unwrap_err());
+    }
+
+    #[test]
+    fn malformed() {
+        let file = |record_count: u32, records: &[u32], string_block: &[u8]| {
+            let mut b = DbcHeader {
+                record_count,
+                field_count: SpellIcon::FIELD_COUNT as u32,
+                record_size: SpellIcon::ROW_SIZE as u32,
+                string_block_size: string_block.len() as u32,
+            }
+            .write_header()
+            .to_vec();
+            for v in records {
+                b.extend_from_slice(&v.to_le_bytes());
+            }
+            b.extend_from_slice(string_block);
+            b
+        };
+        let source = |e: DbcError| match e {
+            DbcError::Field(e) => e.error,
+            e => e,
+        };
+
+        let b = file(1, &[1, 100], b"\0a\0");
+        assert!(matches!(
+            source(SpellIcon::read(&mut b.as_slice()).unwrap_err()),
+            DbcError::Malformed(MalformedError::StringOffsetOutOfBounds {
+                offset: 100,
+                string_block_size: 3
+            })
+        ));
+
+        let b = file(1, &[1, 1], b"\0abc");
+        assert!(matches!(
+            source(SpellIcon::read(&mut b.as_slice()).unwrap_err()),
+            DbcError::Malformed(MalformedError::UnterminatedString { offset: 1 })
+        ));
+
+        // does not allocate the size in the header up front
+        let b = file(u32::MAX, &[1, 0], b"\0");
+        assert!(matches!(
+            SpellIcon::read(&mut b.as_slice()).unwrap_err(),
+            DbcError::Malformed(MalformedError::Truncated { .. })
+        ));
+        assert!(matches!(
+            DbcTableRef::<SpellIcon>::new(&b).unwrap_err(),
+            DbcError::Malformed(_)
+        ));
+
+        assert!(matches!(
+            SpellIcon::read_row_ref(&[1, 0, 0, 0], b"\0").unwrap_err(),
+            DbcError::Malformed(MalformedError::Truncated {
+                expected: 8,
+                actual: 4
+            })
+        ));
+    }
+}
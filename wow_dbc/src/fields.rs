@@ -0,0 +1,340 @@
+use crate::prelude::*;
+use crate::{SetFieldError, Value};
+
+/// Names of the strings in [`Value::LocalizedString`], same as the fields of `LocalizedString` and `ExtendedLocalizedString`.
+pub(crate) const LOCALES: [&str; 16] = [
+    "en_gb",
+    "ko_kr",
+    "fr_fr",
+    "de_de",
+    "en_cn",
+    "en_tw",
+    "es_es",
+    "es_mx",
+    "ru_ru",
+    "ja_jp",
+    "pt_pt",
+    "it_it",
+    "unknown_12",
+    "unknown_13",
+    "unknown_14",
+    "unknown_15",
+];
+
+/// Access to the fields of a row as [`Value`]s, without knowing the type of the row.
+///
+/// Implemented for every row type. Values use the same representation as [`DynamicTable`](crate::DynamicTable):
+/// enums, flags and bools are [`Value::Int`], and primary and foreign keys are [`Value::Key`].
+///
+/// [`RowFields::get`] and [`RowFields::set`] can also access single strings of localized strings,
+/// like `name.de_de` or `name.flags`, and single elements of arrays, like `reagent[2]`.
+pub trait RowFields {
+    /// Names of every field, in the order they are stored in the file.
+    const FIELDS: &'static [&'static str];
+
+    /// The primary key of the row, if the table has a primary key.
+    fn id(&self) -> Option<i64>;
+
+    /// Values of every field, in the same order as [`RowFields::FIELDS`].
+    fn values(&self) -> Vec<Value>;
+
+    /// Sets the whole field named `field` to `value`.
+    ///
+    /// Integers are accepted for both [`Value::Int`] and [`Value::Key`], and for [`Value::Float`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SetFieldError::UnknownField`] if the row does not have the field,
+    /// and [`SetFieldError::InvalidValue`] if the value has the wrong type, is out of range, or is not a valid enumerator.
+    fn set_field(&mut self, field: &str, value: Value) -> Result<(), SetFieldError>;
+
+    /// Creates a row from the value of every field, in the same order as [`RowFields::FIELDS`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SetFieldError::FieldCount`] if the amount of values is wrong,
+    /// and the same errors as [`RowFields::set_field`] for the values.
+    fn from_values(values: Vec<Value>) -> Result<Self, SetFieldError>
+    where
+        Self: Sized;
+
+    /// Returns the value of the field at `path`, if it exists.
+    fn get(&self, path: &str) -> Option<Value> {
+        let (field, part) = parse_path(path)?;
+        let index = Self::FIELDS.iter().position(|a| *a == field)?;
+        let value = self.values().swap_remove(index);
+
+        match (part, value) {
+            (None, value) => Some(value),
+            (Some(Part::Locale(i)), Value::LocalizedString { mut strings, .. }) => {
+                (i < strings.len()).then(|| Value::String(strings.swap_remove(i)))
+            }
+            (Some(Part::Flags), Value::LocalizedString { flags, .. }) => {
+                Some(Value::Int(flags.into()))
+            }
+            (Some(Part::Index(i)), Value::Array(mut values)) => {
+                (i < values.len()).then(|| values.swap_remove(i))
+            }
+            _ => None,
+        }
+    }
+
+    /// Sets the field at `path` to `value`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`RowFields::set_field`].
+    fn set(&mut self, path: &str, value: Value) -> Result<(), SetFieldError> {
+        let unknown = || SetFieldError::UnknownField {
+            field: path.to_string(),
+        };
+
+        let (field, part) = parse_path(path).ok_or_else(unknown)?;
+        let Some(part) = part else {
+            return self.set_field(field, value);
+        };
+        let index = Self::FIELDS
+            .iter()
+            .position(|a| *a == field)
+            .ok_or_else(unknown)?;
+
+        let new = match (part, self.values().swap_remove(index)) {
+            (Part::Locale(i), Value::LocalizedString { mut strings, flags }) if i < strings.len() => {
+                strings[i] = string(path, value)?;
+
+                Value::LocalizedString { strings, flags }
+            }
+            (Part::Flags, Value::LocalizedString { strings, .. }) => Value::LocalizedString {
+                strings,
+                flags: int(path, value)?,
+            },
+            (Part::Index(i), Value::Array(mut values)) if i < values.len() => {
+                values[i] = value;
+
+                Value::Array(values)
+            }
+            _ => return Err(unknown()),
+        };
+
+        self.set_field(field, new)
+    }
+}
+
+/// Part of a field that [`RowFields::get`] and [`RowFields::set`] can access.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Part {
+    Locale(usize),
+    Flags,
+    Index(usize),
+}
+
+fn parse_path(path: &str) -> Option<(&str, Option<Part>)> {
+    if let Some((field, index)) = path.strip_suffix(']').and_then(|a| a.split_once('[')) {
+        return Some((field, Some(Part::Index(index.parse().ok()?))));
+    }
+
+    if let Some((field, locale)) = path.split_once('.') {
+        let part = if locale == "flags" {
+            Part::Flags
+        } else {
+            Part::Locale(LOCALES.iter().position(|a| *a == locale)?)
+        };
+
+        return Some((field, Some(part)));
+    }
+
+    Some((path, None))
+}
+
+/// Every field of `row` with localized strings and arrays split into single strings and elements,
+/// named the same as paths for [`RowFields::get`].
+pub(crate) fn leaves<R: RowFields>(row: &R) -> Vec<(String, Value)> {
+    let mut leaves = Vec::new();
+    for (field, value) in R::FIELDS.iter().zip(row.values()) {
+        flatten(field.to_string(), value, &mut leaves);
+    }
+
+    leaves
+}
+
+fn flatten(path: String, value: Value, leaves: &mut Vec<(String, Value)>) {
+    match value {
+        Value::LocalizedString { strings, flags } => {
+            for (locale, string) in LOCALES.iter().zip(strings) {
+                leaves.push((format!("{path}.{locale}"), Value::String(string)));
+            }
+            leaves.push((format!("{path}.flags"), Value::Int(flags.into())));
+        }
+        Value::Array(values) => {
+            for (i, value) in values.into_iter().enumerate() {
+                flatten(format!("{path}[{i}]"), value, leaves);
+            }
+        }
+        value => leaves.push((path, value)),
+    }
+}
+
+/// Same as `==` except that floats are compared by their bits, so NaN is identical to itself.
+pub(crate) fn identical(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Float(a), Value::Float(b)) => a.to_bits() == b.to_bits(),
+        (a, b) => a == b,
+    }
+}
+
+fn invalid(field: &str, value: Value) -> SetFieldError {
+    SetFieldError::InvalidValue {
+        field: field.to_string(),
+        value,
+    }
+}
+
+/// Splits `values` into exactly one value per field.
+#[cfg(any(feature = "vanilla", feature = "tbc", feature = "wrath"))]
+pub(crate) fn field_values<const N: usize>(values: Vec<Value>) -> Result<[Value; N], SetFieldError> {
+    values
+        .try_into()
+        .map_err(|values: Vec<Value>| SetFieldError::FieldCount {
+            expected: N,
+            actual: values.len(),
+        })
+}
+
+pub(crate) fn int<T: TryFrom<i64>>(field: &str, value: Value) -> Result<T, SetFieldError> {
+    value
+        .as_int()
+        .and_then(|v| T::try_from(v).ok())
+        .ok_or_else(|| invalid(field, value))
+}
+
+// only vanilla tables have bools
+#[cfg(feature = "vanilla")]
+pub(crate) fn bool(field: &str, value: Value) -> Result<bool, SetFieldError> {
+    match value.as_int() {
+        Some(0) => Ok(false),
+        Some(1) => Ok(true),
+        _ => Err(invalid(field, value)),
+    }
+}
+
+#[cfg(any(feature = "vanilla", feature = "tbc", feature = "wrath"))]
+pub(crate) fn float(field: &str, value: Value) -> Result<f32, SetFieldError> {
+    match value {
+        Value::Float(v) => Ok(v),
+        Value::Int(v) => Ok(v as f32),
+        value => Err(invalid(field, value)),
+    }
+}
+
+pub(crate) fn string(field: &str, value: Value) -> Result<String, SetFieldError> {
+    match value {
+        Value::String(v) => Ok(v),
+        value => Err(invalid(field, value)),
+    }
+}
+
+#[cfg(any(feature = "vanilla", feature = "tbc", feature = "wrath"))]
+pub(crate) fn enumeration<E: TryFrom<R>, R: TryFrom<i64>>(
+    field: &str,
+    value: Value,
+) -> Result<E, SetFieldError> {
+    value
+        .as_int()
+        .and_then(|v| R::try_from(v).ok())
+        .and_then(|v| E::try_from(v).ok())
+        .ok_or_else(|| invalid(field, value))
+}
+
+/// Converts every element of an array with `f`, which is called with the name of the element.
+#[cfg(any(feature = "vanilla", feature = "tbc", feature = "wrath"))]
+pub(crate) fn array<T, const N: usize>(
+    field: &str,
+    value: Value,
+    f: impl Fn(&str, Value) -> Result<T, SetFieldError>,
+) -> Result<[T; N], SetFieldError> {
+    let values = match value {
+        Value::Array(values) if values.len() == N => values,
+        value => return Err(invalid(field, value)),
+    };
+
+    let values = values
+        .into_iter()
+        .enumerate()
+        .map(|(i, v)| f(&format!("{field}[{i}]"), v))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(values
+        .try_into()
+        .unwrap_or_else(|_| unreachable!("length is checked")))
+}
+
+/// Strings and flags of a localized string, with missing strings being empty.
+#[cfg(any(feature = "vanilla", feature = "tbc", feature = "wrath"))]
+fn localized_strings<const N: usize>(
+    field: &str,
+    value: Value,
+) -> Result<([String; N], u32), SetFieldError> {
+    let (strings, flags) = match value {
+        Value::LocalizedString { strings, flags } if strings.len() <= N => (strings, flags),
+        value => return Err(invalid(field, value)),
+    };
+
+    let mut array: [String; N] = core::array::from_fn(|_| String::new());
+    for (a, b) in array.iter_mut().zip(strings) {
+        *a = b;
+    }
+
+    Ok((array, flags))
+}
+
+#[cfg(feature = "vanilla")]
+pub(crate) fn localized(
+    field: &str,
+    value: Value,
+) -> Result<crate::LocalizedString, SetFieldError> {
+    let ([en_gb, ko_kr, fr_fr, de_de, en_cn, en_tw, es_es, es_mx], flags) =
+        localized_strings(field, value)?;
+
+    Ok(crate::LocalizedString {
+        en_gb,
+        ko_kr,
+        fr_fr,
+        de_de,
+        en_cn,
+        en_tw,
+        es_es,
+        es_mx,
+        flags,
+    })
+}
+
+#[cfg(any(feature = "tbc", feature = "wrath"))]
+pub(crate) fn extended_localized(
+    field: &str,
+    value: Value,
+) -> Result<crate::ExtendedLocalizedString, SetFieldError> {
+    let (
+        [en_gb, ko_kr, fr_fr, de_de, en_cn, en_tw, es_es, es_mx, ru_ru, ja_jp, pt_pt, it_it, unknown_12, unknown_13, unknown_14, unknown_15],
+        flags,
+    ) = localized_strings(field, value)?;
+
+    Ok(crate::ExtendedLocalizedString {
+        en_gb,
+        ko_kr,
+        fr_fr,
+        de_de,
+        en_cn,
+        en_tw,
+        es_es,
+        es_mx,
+        ru_ru,
+        ja_jp,
+        pt_pt,
+        it_it,
+        unknown_12,
+        unknown_13,
+        unknown_14,
+        unknown_15,
+        flags,
+    })
+}
@@ -0,0 +1,25 @@
+//! Rows shared by the tests of multiple modules.
+
+use crate::vanilla_tables::item_class::{ItemClassKey, ItemClassRow};
+use crate::vanilla_tables::spell_icon::{SpellIconKey, SpellIconRow};
+use crate::LocalizedString;
+use wow_world_base::vanilla::ItemWeaponClass;
+
+pub(crate) fn item_class_row(id: u32, name: &str) -> ItemClassRow {
+    ItemClassRow {
+        id: ItemClassKey::new(id),
+        subclass_map: 0,
+        item_class: ItemWeaponClass::Item,
+        class_name: LocalizedString {
+            en_gb: name.to_string(),
+            ..Default::default()
+        },
+    }
+}
+
+pub(crate) fn spell_icon_row(id: u32, texture_file: &str) -> SpellIconRow {
+    SpellIconRow {
+        id: SpellIconKey::new(id),
+        texture_file: texture_file.to_string(),
+    }
+}
@@ -1,16 +1,33 @@
-use crate::InvalidHeaderError;
+//! The header at the start of every DBC file.
 
+use crate::io::{ErrorKind, Read};
+use crate::prelude::*;
+use crate::{DbcError, DbcTable, InvalidHeaderError, MalformedError};
+#[cfg(feature = "std")]
+use std::io::{Seek, SeekFrom};
+
+/// Size of the header in bytes.
 pub const HEADER_SIZE: usize = 4 * 5;
 const HEADER_MAGIC: u32 = 0x43424457;
 
+/// Header of a DBC file, without the magic value.
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
 pub struct DbcHeader {
+    /// Amount of records (rows) in the file.
     pub record_count: u32,
+    /// Amount of fields in every record.
     pub field_count: u32,
+    /// Size of every record in bytes.
     pub record_size: u32,
+    /// Size of the string block after the records in bytes.
     pub string_block_size: u32,
 }
 
+/// Parses the header from the first [`HEADER_SIZE`] bytes of a DBC file.
+///
+/// # Errors
+///
+/// Will error with [`InvalidHeaderError::MagicValue`] if the magic numbers (`0x43424457`) at the start do not match.
 pub const fn parse_header(header: &[u8; HEADER_SIZE]) -> Result<DbcHeader, InvalidHeaderError> {
     let magic = u32::from_le_bytes([header[0], header[1], header[2], header[3]]);
     if magic != HEADER_MAGIC {
@@ -30,7 +47,25 @@ pub const fn parse_header(header: &[u8; HEADER_SIZE]) -> Result<DbcHeader, Inval
     })
 }
 
+/// Reads the header from the current position of `b` and seeks back to where it started.
+///
+/// Useful for finding out which table a file contains with [`identify_table`](crate::identify_table) before reading it.
+///
+/// # Errors
+///
+/// Returns [`DbcError::Malformed`] if `b` has fewer than [`HEADER_SIZE`] bytes left,
+/// and the same errors as [`parse_header`], [`Read::read`] and [`Seek::seek`].
+#[cfg(feature = "std")]
+pub fn peek_header<R: Read + Seek>(b: &mut R) -> Result<DbcHeader, DbcError> {
+    let start = b.stream_position()?;
+    let header = read_header(b);
+    b.seek(SeekFrom::Start(start))?;
+
+    header
+}
+
 impl DbcHeader {
+    /// Writes the header, including the magic value.
     pub fn write_header(&self) -> [u8; HEADER_SIZE] {
         let mut header = [0_u8; HEADER_SIZE];
 
@@ -54,3 +89,120 @@ impl DbcHeader {
         header
     }
 }
+
+impl DbcHeader {
+    /// Size in bytes of all records.
+    pub(crate) const fn records_size(&self) -> Result<usize, MalformedError> {
+        match (self.record_count as usize).checked_mul(self.record_size as usize) {
+            Some(size) => Ok(size),
+            None => Err(MalformedError::RecordsTooLarge {
+                record_count: self.record_count,
+                record_size: self.record_size,
+            }),
+        }
+    }
+}
+
+/// Reads exactly `size` bytes.
+///
+/// The buffer grows with the bytes that are actually read,
+/// so a header with a huge size can not allocate more memory than the reader contains.
+pub(crate) fn read_bytes(b: &mut impl Read, size: usize) -> Result<Vec<u8>, DbcError> {
+    const MIN_STEP: usize = 8 * 1024;
+
+    let mut v = Vec::new();
+    while v.len() < size {
+        // at most double the buffer every time
+        let start = v.len();
+        v.resize(start + (size - start).min(start.max(MIN_STEP)), 0);
+
+        match b.read(&mut v[start..]) {
+            Ok(0) => {
+                v.truncate(start);
+                break;
+            }
+            Ok(n) => v.truncate(start + n),
+            Err(e) if e.kind() == ErrorKind::Interrupted => v.truncate(start),
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    if v.len() != size {
+        return Err(MalformedError::Truncated {
+            expected: size as u64,
+            actual: v.len() as u64,
+        }
+        .into());
+    }
+
+    Ok(v)
+}
+
+/// Fills all of `buf`, same as [`Read::read_exact`] except that missing bytes return [`MalformedError::Truncated`].
+#[cfg(feature = "std")]
+pub(crate) fn fill_bytes(b: &mut impl Read, buf: &mut [u8]) -> Result<(), DbcError> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match b.read(&mut buf[filled..]) {
+            Ok(0) => {
+                return Err(MalformedError::Truncated {
+                    expected: buf.len() as u64,
+                    actual: filled as u64,
+                }
+                .into())
+            }
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads and parses the header.
+pub(crate) fn read_header(b: &mut impl Read) -> Result<DbcHeader, DbcError> {
+    let mut header = [0_u8; HEADER_SIZE];
+    header.copy_from_slice(&read_bytes(b, HEADER_SIZE)?);
+
+    Ok(parse_header(&header)?)
+}
+
+/// Reads the header and checks that it matches the table.
+pub(crate) fn read_table_header<T: DbcTable>(b: &mut impl Read) -> Result<DbcHeader, DbcError> {
+    check_table_header::<T>(read_header(b)?)
+}
+
+/// Reads the records and the string block that follow `header`.
+pub(crate) fn read_records_and_string_block(
+    b: &mut impl Read,
+    header: &DbcHeader,
+) -> Result<(Vec<u8>, Vec<u8>), DbcError> {
+    let records = read_bytes(b, header.records_size()?)?;
+    let string_block = read_bytes(b, header.string_block_size as usize)?;
+
+    Ok((records, string_block))
+}
+
+/// Parses the header and checks that it matches the table.
+pub(crate) fn parse_table_header<T: DbcTable>(header: &[u8; HEADER_SIZE]) -> Result<DbcHeader, DbcError> {
+    check_table_header::<T>(parse_header(header)?)
+}
+
+const fn check_table_header<T: DbcTable>(header: DbcHeader) -> Result<DbcHeader, DbcError> {
+    if header.record_size != T::ROW_SIZE as u32 {
+        return Err(DbcError::InvalidHeader(InvalidHeaderError::RecordSize {
+            expected: T::ROW_SIZE as u32,
+            actual: header.record_size,
+        }));
+    }
+
+    if header.field_count != T::FIELD_COUNT as u32 {
+        return Err(DbcError::InvalidHeader(InvalidHeaderError::FieldCount {
+            expected: T::FIELD_COUNT as u32,
+            actual: header.field_count,
+        }));
+    }
+
+    Ok(header)
+}
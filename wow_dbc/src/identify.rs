@@ -0,0 +1,209 @@
+use crate::header::DbcHeader;
+use crate::prelude::*;
+use crate::{DbcTable, Expansion};
+
+/// Filename and layout of a table definition, used for identifying files with [`identify_table`].
+///
+/// Lists of every table are in `TABLES` of the [`vanilla_tables`](crate::vanilla_tables), [`tbc_tables`](crate::tbc_tables),
+/// and [`wrath_tables`](crate::wrath_tables) modules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TableInfo {
+    /// Name of the DBC file _with_ `.dbc` at the end, same as [`DbcTable::FILENAME`].
+    pub filename: &'static str,
+    /// Version of the client that the table is for.
+    pub expansion: Expansion,
+    /// Same as [`DbcTable::FIELD_COUNT`].
+    pub field_count: usize,
+    /// Same as [`DbcTable::ROW_SIZE`].
+    pub row_size: usize,
+}
+
+impl TableInfo {
+    /// Creates the information for `T`.
+    pub const fn new<T: DbcTable>(expansion: Expansion) -> Self {
+        Self {
+            filename: T::FILENAME,
+            expansion,
+            field_count: T::FIELD_COUNT,
+            row_size: T::ROW_SIZE,
+        }
+    }
+
+    /// Name of the table _without_ `.dbc` at the end.
+    pub fn name(&self) -> &'static str {
+        self.filename.strip_suffix(".dbc").unwrap_or(self.filename)
+    }
+
+    /// Returns `true` if the field count and record size of `header` are the same as for the table.
+    pub const fn matches_header(&self, header: &DbcHeader) -> bool {
+        header.field_count as usize == self.field_count
+            && header.record_size as usize == self.row_size
+    }
+
+    /// Returns `true` if `filename` is the filename of the table.
+    ///
+    /// Directories and the `.dbc` extension are ignored, and case does not matter.
+    pub fn matches_filename(&self, filename: &str) -> bool {
+        let filename = filename.rsplit(['/', '\\']).next().unwrap_or(filename);
+        let filename = match filename.len().checked_sub(4) {
+            Some(i)
+                if filename.is_char_boundary(i) && filename[i..].eq_ignore_ascii_case(".dbc") =>
+            {
+                &filename[..i]
+            }
+            _ => filename,
+        };
+
+        filename.eq_ignore_ascii_case(self.name())
+    }
+}
+
+/// How well a [`TableCandidate`] matches a file.
+///
+/// Ordered from the weakest to the strongest match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TableMatch {
+    /// Only the filename matches. Reading the file as the table will fail.
+    Filename,
+    /// Only the field count and record size match.
+    /// Many tables have the same layout, so this is only a guess.
+    Header,
+    /// Both the filename and the layout match.
+    FilenameAndHeader,
+}
+
+/// A table that a file could contain. Returned by [`identify_table`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TableCandidate {
+    /// The table.
+    pub table: &'static TableInfo,
+    /// How well the table matches.
+    pub kind: TableMatch,
+}
+
+/// Every table of the enabled expansion features.
+pub fn tables() -> impl Iterator<Item = &'static TableInfo> {
+    let tables: [&'static [TableInfo]; 3] = [
+        #[cfg(feature = "vanilla")]
+        crate::vanilla_tables::TABLES,
+        #[cfg(not(feature = "vanilla"))]
+        &[],
+        #[cfg(feature = "tbc")]
+        crate::tbc_tables::TABLES,
+        #[cfg(not(feature = "tbc"))]
+        &[],
+        #[cfg(feature = "wrath")]
+        crate::wrath_tables::TABLES,
+        #[cfg(not(feature = "wrath"))]
+        &[],
+    ];
+
+    tables.into_iter().flatten()
+}
+
+/// Finds the tables that a file with `header` could contain, with the best matches first.
+///
+/// `filename` is the name of the file, if known. Files are not required to be named after their table,
+/// but the same layout is shared by many tables so the filename is the only way to tell them apart.
+///
+/// Only tables from the enabled expansion features are considered.
+/// Tables with the same [`TableMatch`] are ordered by expansion and then by name.
+pub fn identify_table(filename: Option<&str>, header: &DbcHeader) -> Vec<TableCandidate> {
+    let mut candidates = tables()
+        .filter_map(|table| {
+            let filename = filename.is_some_and(|a| table.matches_filename(a));
+            let kind = match (filename, table.matches_header(header)) {
+                (true, true) => TableMatch::FilenameAndHeader,
+                (false, true) => TableMatch::Header,
+                (true, false) => TableMatch::Filename,
+                (false, false) => return None,
+            };
+
+            Some(TableCandidate { table, kind })
+        })
+        .collect::<Vec<_>>();
+
+    candidates.sort_by_key(|a| core::cmp::Reverse(a.kind));
+
+    candidates
+}
+
+/// Finds the expansions that a set of files are from, with the most likely first.
+///
+/// Expansions are ranked by the amount of files that have a [`TableMatch::FilenameAndHeader`] match,
+/// and only expansions with at least one match are returned.
+/// Many tables have the same layout in multiple expansions,
+/// so a single file is often not enough to tell them apart.
+pub fn identify_expansion<'a>(
+    files: impl IntoIterator<Item = (&'a str, DbcHeader)>,
+) -> Vec<(Expansion, usize)> {
+    let mut matches = [
+        (Expansion::Vanilla, 0),
+        (Expansion::Tbc, 0),
+        (Expansion::Wrath, 0),
+    ];
+
+    for (filename, header) in files {
+        for (expansion, count) in &mut matches {
+            let found = tables().any(|a| {
+                a.expansion == *expansion
+                    && a.matches_filename(filename)
+                    && a.matches_header(&header)
+            });
+
+            if found {
+                *count += 1;
+            }
+        }
+    }
+
+    let mut matches = matches
+        .into_iter()
+        .filter(|(_, count)| *count > 0)
+        .collect::<Vec<_>>();
+    matches.sort_by_key(|a| core::cmp::Reverse(a.1));
+
+    matches
+}
+
+#[cfg(all(test, feature = "vanilla", feature = "tbc"))]
+mod test {
+    use super::*;
+    use crate::vanilla_tables::item_class::ItemClass;
+    use crate::vanilla_tables::spell_icon::SpellIcon;
+
+    #[test]
+    fn identify() {
+        // 8 localized strings in vanilla and 16 afterwards
+        let header = DbcHeader {
+            record_count: 0,
+            field_count: ItemClass::FIELD_COUNT as u32,
+            record_size: ItemClass::ROW_SIZE as u32,
+            string_block_size: 1,
+        };
+
+        let candidates = identify_table(Some("DBFilesClient\\itemclass.DBC"), &header);
+        assert_eq!(candidates[0].kind, TableMatch::FilenameAndHeader);
+        assert_eq!(candidates[0].table.name(), "ItemClass");
+        assert_eq!(candidates[0].table.expansion, Expansion::Vanilla);
+        assert!(candidates[1..]
+            .iter()
+            .all(|a| a.kind != TableMatch::FilenameAndHeader));
+        assert!(candidates
+            .iter()
+            .any(|a| a.kind == TableMatch::Filename && a.table.expansion == Expansion::Tbc));
+
+        let candidates = identify_table(None, &header);
+        assert!(candidates.iter().all(|a| a.kind == TableMatch::Header));
+
+        let spell_icon = DbcHeader {
+            field_count: SpellIcon::FIELD_COUNT as u32,
+            record_size: SpellIcon::ROW_SIZE as u32,
+            ..header
+        };
+        let expansions =
+            identify_expansion([("ItemClass.dbc", header), ("SpellIcon.dbc", spell_icon)]);
+        assert_eq!(expansions[0], (Expansion::Vanilla, 2));
+        assert!(expansions[1..].iter().all(|a| a.1 < 2));
+    }
+}
@@ -0,0 +1,230 @@
+use crate::util::Map;
+use crate::{DuplicateKeyError, Indexable};
+use core::ops::{Deref, DerefMut};
+
+/// Table wrapper that keeps a map from primary keys to rows for constant time lookups.
+///
+/// Created through [`Indexable::indexed`].
+/// The map is kept up to date when rows are changed through [`IndexedTable::rows_mut`] and [`IndexedTable::get_mut`],
+/// or added and removed through [`IndexedTable::push`] and [`IndexedTable::remove`].
+///
+/// The original DBCs do not guarantee that primary keys are unique.
+/// Like [`Indexable::get`] lookups will always return the first row with the key.
+///
+/// Without the `std` feature the map is a `BTreeMap`, so lookups take logarithmic time instead.
+#[derive(Debug, Clone)]
+pub struct IndexedTable<T: Indexable> {
+    table: T,
+    index: Map<T::PrimaryKey, usize>,
+}
+
+impl<T: Indexable> IndexedTable<T> {
+    /// Builds the index for `table`.
+    pub fn new(table: T) -> Self {
+        let mut s = Self {
+            table,
+            index: Map::new(),
+        };
+        s.rebuild_index();
+
+        s
+    }
+
+    /// The wrapped table.
+    pub const fn table(&self) -> &T {
+        &self.table
+    }
+
+    /// Returns the wrapped table and drops the index.
+    pub fn into_inner(self) -> T {
+        self.table
+    }
+
+    /// Array of all rows in the same order as the wrapped table.
+    pub fn rows(&self) -> &[T::Row] {
+        self.table.rows()
+    }
+
+    /// Mutable array of all rows.
+    ///
+    /// The index is rebuilt when the returned guard is dropped.
+    pub const fn rows_mut(&mut self) -> RowsMut<'_, T> {
+        RowsMut { table: self }
+    }
+
+    /// Gets the row with the primary key, if present.
+    pub fn get(&self, key: impl TryInto<T::PrimaryKey>) -> Option<&T::Row> {
+        let index = *self.index.get(&key.try_into().ok()?)?;
+
+        self.table.rows().get(index)
+    }
+
+    /// Gets the row with the primary key, if present.
+    ///
+    /// The index is updated if the primary key of the row has changed when the returned guard is dropped.
+    pub fn get_mut(&mut self, key: impl TryInto<T::PrimaryKey>) -> Option<RowMut<'_, T>> {
+        let key = key.try_into().ok()?;
+        let index = *self.index.get(&key)?;
+
+        Some(RowMut {
+            table: self,
+            index,
+            key,
+        })
+    }
+
+    /// Returns `true` if a row with the primary key exists.
+    pub fn contains_key(&self, key: impl TryInto<T::PrimaryKey>) -> bool {
+        key.try_into()
+            .is_ok_and(|key| self.index.contains_key(&key))
+    }
+
+    /// Adds `row` to the end of the table.
+    ///
+    /// # Errors
+    ///
+    /// Returns the row in a [`DuplicateKeyError`] if a row with the same primary key already exists.
+    pub fn push(&mut self, row: T::Row) -> Result<(), DuplicateKeyError<T::Row>> {
+        let key = T::primary_key(&row);
+        if self.index.contains_key(&key) {
+            return Err(DuplicateKeyError::new::<T>(row));
+        }
+
+        let rows = self.table.rows_vec_mut();
+        self.index.insert(key, rows.len());
+        rows.push(row);
+
+        Ok(())
+    }
+
+    /// Removes and returns the row with the primary key, if present.
+    ///
+    /// The order of the remaining rows is preserved.
+    pub fn remove(&mut self, key: impl TryInto<T::PrimaryKey>) -> Option<T::Row> {
+        let index = self.index.remove(&key.try_into().ok()?)?;
+        let row = self.table.rows_vec_mut().remove(index);
+
+        // all following rows have shifted and a duplicate key might now be the first
+        self.rebuild_index();
+
+        Some(row)
+    }
+
+    fn rebuild_index(&mut self) {
+        self.index.clear();
+
+        for (i, row) in self.table.rows().iter().enumerate() {
+            self.index.entry(T::primary_key(row)).or_insert(i);
+        }
+    }
+}
+
+impl<T: Indexable> From<T> for IndexedTable<T> {
+    fn from(table: T) -> Self {
+        Self::new(table)
+    }
+}
+
+/// Mutable access to all rows of an [`IndexedTable`]. Created by [`IndexedTable::rows_mut`].
+///
+/// Rebuilds the index when dropped.
+pub struct RowsMut<'a, T: Indexable> {
+    table: &'a mut IndexedTable<T>,
+}
+
+impl<T: Indexable> Deref for RowsMut<'_, T> {
+    type Target = [T::Row];
+
+    fn deref(&self) -> &Self::Target {
+        self.table.table.rows()
+    }
+}
+
+impl<T: Indexable> DerefMut for RowsMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.table.table.rows_mut()
+    }
+}
+
+impl<T: Indexable> Drop for RowsMut<'_, T> {
+    fn drop(&mut self) {
+        self.table.rebuild_index();
+    }
+}
+
+/// Mutable access to a single row of an [`IndexedTable`]. Created by [`IndexedTable::get_mut`].
+///
+/// Updates the index when dropped if the primary key was changed.
+pub struct RowMut<'a, T: Indexable> {
+    table: &'a mut IndexedTable<T>,
+    index: usize,
+    key: T::PrimaryKey,
+}
+
+impl<T: Indexable> Deref for RowMut<'_, T> {
+    type Target = T::Row;
+
+    fn deref(&self) -> &Self::Target {
+        &self.table.table.rows()[self.index]
+    }
+}
+
+impl<T: Indexable> DerefMut for RowMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.table.table.rows_mut()[self.index]
+    }
+}
+
+impl<T: Indexable> Drop for RowMut<'_, T> {
+    fn drop(&mut self) {
+        if T::primary_key(&self.table.table.rows()[self.index]) != self.key {
+            self.table.rebuild_index();
+        }
+    }
+}
+
+#[cfg(all(test, feature = "vanilla"))]
+mod test {
+    use crate::fixtures::spell_icon_row;
+    use crate::vanilla_tables::spell_icon::{SpellIcon, SpellIconKey};
+    use crate::{DbcTable, Indexable};
+
+    #[test]
+    fn index_follows_changes() {
+        let table = SpellIcon {
+            rows: vec![
+                spell_icon_row(1, "a"),
+                spell_icon_row(2, "b"),
+                spell_icon_row(2, "duplicate"),
+                spell_icon_row(3, "c"),
+            ],
+        };
+        let mut table = table.indexed();
+
+        assert_eq!(table.get(2_u32).unwrap().texture_file, "b");
+        assert!(table.get(4_u32).is_none());
+
+        table.push(spell_icon_row(4, "d")).unwrap();
+        assert_eq!(table.get(4_u32).unwrap().texture_file, "d");
+        let e = table.push(spell_icon_row(4, "duplicate")).unwrap_err();
+        assert_eq!(e.row.texture_file, "duplicate");
+        assert_eq!(table.rows().len(), 5);
+
+        // removing the first row with a key makes the duplicate visible
+        assert_eq!(table.remove(2_u32).unwrap().texture_file, "b");
+        assert_eq!(table.get(2_u32).unwrap().texture_file, "duplicate");
+        assert_eq!(table.get(3_u32).unwrap().texture_file, "c");
+
+        table.get_mut(3_u32).unwrap().id = SpellIconKey::new(5);
+        assert!(!table.contains_key(3_u32));
+        assert_eq!(table.get(5_u32).unwrap().texture_file, "c");
+
+        table.rows_mut()[0].id = SpellIconKey::new(6);
+        assert!(!table.contains_key(1_u32));
+        assert_eq!(table.get(6_u32).unwrap().texture_file, "a");
+
+        let table = table.into_inner();
+        assert_eq!(table.rows().len(), 4);
+        assert_eq!(table.get(6_u32).unwrap().texture_file, "a");
+    }
+}
@@ -0,0 +1,170 @@
+//! Readers and writers used by [`DbcTable::read`](crate::DbcTable::read) and [`DbcTable::write`](crate::DbcTable::write).
+//!
+//! With the `std` feature these are the types from `std::io`,
+//! so any `std::io::Read` and `std::io::Write` can be used.
+//!
+//! Without the `std` feature these are minimal replacements implemented for `&[u8]` and `Vec<u8>`,
+//! which can also be implemented for other readers and writers.
+
+#[cfg(feature = "std")]
+pub use std::io::{Error, ErrorKind, Read, Write};
+
+#[cfg(not(feature = "std"))]
+pub use no_std::*;
+
+#[cfg(not(feature = "std"))]
+mod no_std {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use core::fmt::{Display, Formatter};
+
+    /// Kind of an [`Error`], a subset of `std::io::ErrorKind`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub enum ErrorKind {
+        /// A parameter was incorrect, for example a row that does not match its schema.
+        InvalidInput,
+        /// The data read was not valid.
+        InvalidData,
+        /// The reader ended before all requested bytes were read.
+        UnexpectedEof,
+        /// The operation was interrupted and can be retried.
+        Interrupted,
+        /// Any other error.
+        Other,
+    }
+
+    /// Error from a [`Read`] or [`Write`], a replacement for `std::io::Error`.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Error {
+        kind: ErrorKind,
+        message: String,
+    }
+
+    impl Error {
+        /// Creates an error of `kind` with a message.
+        pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+            Self {
+                kind,
+                message: message.into(),
+            }
+        }
+
+        /// The kind of error.
+        pub const fn kind(&self) -> ErrorKind {
+            self.kind
+        }
+    }
+
+    impl Display for Error {
+        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            f.write_str(&self.message)
+        }
+    }
+
+    impl core::error::Error for Error {}
+
+    /// Source of bytes, a replacement for `std::io::Read`.
+    pub trait Read {
+        /// Reads bytes into `buf`, returning how many bytes were read.
+        ///
+        /// Returns `Ok(0)` when there are no more bytes to read or `buf` is empty.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the bytes can not be read.
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error>;
+
+        /// Reads exactly enough bytes to fill `buf`.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`ErrorKind::UnexpectedEof`] if there are not enough bytes left,
+        /// and the same errors as [`Read::read`].
+        fn read_exact(&mut self, mut buf: &mut [u8]) -> Result<(), Error> {
+            while !buf.is_empty() {
+                match self.read(buf) {
+                    Ok(0) => {
+                        return Err(Error::new(
+                            ErrorKind::UnexpectedEof,
+                            "failed to fill whole buffer",
+                        ))
+                    }
+                    Ok(n) => buf = &mut buf[n..],
+                    Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                    Err(e) => return Err(e),
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    impl Read for &[u8] {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+            let n = buf.len().min(self.len());
+            let (a, b) = self.split_at(n);
+            buf[..n].copy_from_slice(a);
+            *self = b;
+
+            Ok(n)
+        }
+    }
+
+    impl<R: Read + ?Sized> Read for &mut R {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+            (**self).read(buf)
+        }
+
+        fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), Error> {
+            (**self).read_exact(buf)
+        }
+    }
+
+    /// Destination of bytes, a replacement for `std::io::Write`.
+    pub trait Write {
+        /// Writes all of `buf`.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the bytes can not be written.
+        fn write_all(&mut self, buf: &[u8]) -> Result<(), Error>;
+    }
+
+    impl Write for Vec<u8> {
+        fn write_all(&mut self, buf: &[u8]) -> Result<(), Error> {
+            self.extend_from_slice(buf);
+
+            Ok(())
+        }
+    }
+
+    impl<W: Write + ?Sized> Write for &mut W {
+        fn write_all(&mut self, buf: &[u8]) -> Result<(), Error> {
+            (**self).write_all(buf)
+        }
+    }
+}
+
+#[cfg(all(test, not(feature = "std")))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn read_and_write() {
+        let mut r: &[u8] = &[1, 2, 3];
+        let mut buf = [0_u8; 2];
+        r.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, [1, 2]);
+        assert_eq!(r, [3]);
+
+        let err = r.read_exact(&mut buf).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+        assert!(r.is_empty());
+
+        let mut w = Vec::new();
+        w.write_all(&[1, 2]).unwrap();
+        w.write_all(&[3]).unwrap();
+        assert_eq!(w, [1, 2, 3]);
+    }
+}
@@ -0,0 +1,336 @@
+use crate::header::{read_records_and_string_block, read_table_header, HEADER_SIZE};
+use crate::io::{Read, Write};
+use crate::prelude::*;
+use crate::{DbcError, DbcTable};
+use core::fmt::{Display, Formatter};
+
+/// Table read with [`DbcTable::read_lenient`], where every row keeps the original values of the fields that had to be coerced.
+///
+/// Values that are not valid for their enum are replaced with the default enumerator instead of returning [`DbcError::Field`],
+/// and bools that are neither 0 nor 1 are read as `true`.
+/// Flags always keep all bits, even unknown ones, so they never need to be coerced.
+///
+/// The original value is stored as a [`Coercion`] in the [`LenientRow`] next to the row,
+/// so rows can be added, removed, and sorted without losing it.
+/// [`LenientTable::write`] always writes the original value of a coerced field,
+/// use [`LenientRow::remove_coercion`] in order to write the value in the row instead.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LenientTable<T: DbcTable> {
+    /// Every row in the file, in the order they were read.
+    pub rows: Vec<LenientRow<T::Row>>,
+}
+
+impl<T: DbcTable> LenientTable<T> {
+    /// Read table from bytes, coercing invalid enum and bool values.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`DbcTable::read`] except for invalid enum values.
+    pub fn read(b: &mut impl Read) -> Result<Self, DbcError> {
+        let header = read_table_header::<T>(b)?;
+        let (records, string_block) = read_records_and_string_block(b, &header)?;
+
+        let mut rows = Vec::with_capacity(records.len() / T::ROW_SIZE);
+
+        for (i, record) in records.chunks(T::ROW_SIZE).enumerate() {
+            let mut coercions = Vec::new();
+
+            let row = T::read_row_ref_with(record, &string_block, Some(&mut coercions))
+                .map_err(|e| e.with_row(i))?;
+
+            for coercion in &mut coercions {
+                coercion.row = i;
+            }
+
+            rows.push(LenientRow {
+                row: row.into(),
+                coercions,
+            });
+        }
+
+        Ok(Self { rows })
+    }
+
+    /// Every coerced value of every row.
+    pub fn coercions(&self) -> impl Iterator<Item = &Coercion> {
+        self.rows.iter().flat_map(|a| a.coercions.iter())
+    }
+
+    /// Creates the table from the rows with coerced values, dropping the original values.
+    pub fn into_table(self) -> T {
+        T::from_rows(self.rows.into_iter().map(|a| a.row).collect())
+    }
+
+    /// Write to bytes, writing the original value of every field that still has a [`Coercion`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Write::write_all`].
+    pub fn write(&self, w: &mut impl Write) -> Result<(), crate::io::Error>
+    where
+        T::Row: Clone,
+    {
+        let table = T::from_rows(self.rows.iter().map(|a| a.row.clone()).collect());
+        let mut b = Vec::new();
+        table.write(&mut b)?;
+
+        for (i, row) in self.rows.iter().enumerate() {
+            for coercion in &row.coercions {
+                let start = HEADER_SIZE + i * T::ROW_SIZE + coercion.field_offset;
+                b[start..start + coercion.size]
+                    .copy_from_slice(&coercion.value.to_le_bytes()[..coercion.size]);
+            }
+        }
+
+        w.write_all(&b)
+    }
+}
+
+/// Row of a [`LenientTable`] together with the original values of its coerced fields.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LenientRow<R> {
+    /// The row with coerced values.
+    pub row: R,
+    coercions: Vec<Coercion>,
+}
+
+impl<R> LenientRow<R> {
+    /// Creates a row without any coerced values.
+    pub const fn new(row: R) -> Self {
+        Self {
+            row,
+            coercions: Vec::new(),
+        }
+    }
+
+    /// Every coerced value of the row, in the order they were read.
+    pub fn coercions(&self) -> &[Coercion] {
+        &self.coercions
+    }
+
+    /// The coerced value of the field at `field`, if it was coerced.
+    ///
+    /// Elements of arrays are named the same as paths for [`RowFields::get`](crate::RowFields::get), like `effect_aura[1]`.
+    pub fn coercion(&self, field: &str) -> Option<&Coercion> {
+        self.coercions.iter().find(|a| a.field == field)
+    }
+
+    /// Removes the coerced value of the field at `field`, so that the value in [`LenientRow::row`] is written instead.
+    pub fn remove_coercion(&mut self, field: &str) -> Option<Coercion> {
+        let index = self.coercions.iter().position(|a| a.field == field)?;
+
+        Some(self.coercions.remove(index))
+    }
+}
+
+impl<R> From<R> for LenientRow<R> {
+    fn from(row: R) -> Self {
+        Self::new(row)
+    }
+}
+
+/// A value that could not be represented by the type of its field and was replaced while reading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Coercion {
+    row: usize,
+    field: &'static str,
+    field_offset: usize,
+    value: i64,
+    ty: &'static str,
+    size: usize,
+}
+
+impl Coercion {
+    pub(crate) const fn new(
+        field: &'static str,
+        field_offset: usize,
+        size: usize,
+        value: i64,
+        ty: &'static str,
+    ) -> Self {
+        Self {
+            row: 0,
+            field,
+            field_offset,
+            value,
+            ty,
+            size,
+        }
+    }
+
+    /// Index of the row in the file.
+    pub const fn row(&self) -> usize {
+        self.row
+    }
+
+    /// Name of the field in the row, with the index for elements of arrays like `effect_aura[1]`.
+    pub const fn field(&self) -> &'static str {
+        self.field
+    }
+
+    /// Offset in bytes of the value from the start of the row.
+    pub const fn field_offset(&self) -> usize {
+        self.field_offset
+    }
+
+    /// The value in the file.
+    pub const fn value(&self) -> i64 {
+        self.value
+    }
+
+    /// Name of the type that could not represent the value.
+    pub const fn ty(&self) -> &'static str {
+        self.ty
+    }
+}
+
+impl Display for Coercion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "row {} field '{}' (field offset {:#x}): '{}' is not a valid '{}'",
+            self.row, self.field, self.field_offset, self.value, self.ty
+        )
+    }
+}
+
+#[cfg(all(test, feature = "vanilla"))]
+mod test {
+    use crate::fixtures::item_class_row;
+    use crate::vanilla_tables::character_create_cameras::{
+        CharacterCreateCameras, CharacterCreateCamerasKey, CharacterCreateCamerasRow,
+    };
+    use crate::vanilla_tables::item_class::{ItemClass, ItemClassKey, ItemClassRow};
+    use crate::{DbcError, DbcTable, LenientTable};
+    use wow_world_base::vanilla::ItemWeaponClass;
+
+    /// Table with the `item_class` of the second row set to 77.
+    fn file(rows: Vec<ItemClassRow>) -> Vec<u8> {
+        let mut b = Vec::new();
+        ItemClass { rows }.write(&mut b).unwrap();
+
+        let position = 20 + ItemClass::ROW_SIZE + 8;
+        b[position..position + 4].copy_from_slice(&77_i32.to_le_bytes());
+
+        b
+    }
+
+    #[test]
+    fn unknown_enum() {
+        let b = file(vec![
+            item_class_row(1, "Weapon"),
+            item_class_row(2, "Armor"),
+        ]);
+
+        assert!(matches!(
+            ItemClass::read(&mut b.as_slice()),
+            Err(DbcError::Field(_))
+        ));
+
+        let mut lenient = ItemClass::read_lenient(&mut b.as_slice()).unwrap();
+        assert_eq!(lenient.coercions().count(), 1);
+        assert!(lenient.rows[0].coercions().is_empty());
+        let coercion = *lenient.rows[1].coercion("item_class").unwrap();
+        assert_eq!(coercion.row(), 1);
+        assert_eq!(coercion.field(), "item_class");
+        assert_eq!(coercion.value(), 77);
+        assert_eq!(lenient.rows[1].row.item_class, ItemWeaponClass::default());
+
+        let mut written = Vec::new();
+        lenient.write(&mut written).unwrap();
+        assert_eq!(written, b);
+
+        // the original value is written until the coercion is removed
+        lenient.rows[1].row.item_class = ItemWeaponClass::Weapon;
+        let mut written = Vec::new();
+        lenient.write(&mut written).unwrap();
+        assert_eq!(written, b);
+
+        // the default enumerator can be written on purpose
+        assert_eq!(lenient.rows[1].remove_coercion("item_class"), Some(coercion));
+        lenient.rows[1].row.item_class = ItemWeaponClass::default();
+        let mut written = Vec::new();
+        lenient.write(&mut written).unwrap();
+        assert_eq!(
+            ItemClass::read(&mut written.as_slice()).unwrap().rows[1].item_class,
+            ItemWeaponClass::default()
+        );
+    }
+
+    #[test]
+    fn moved_rows() {
+        let b = file(vec![
+            item_class_row(1, "Weapon"),
+            item_class_row(2, "Armor"),
+            item_class_row(3, "Quest"),
+        ]);
+
+        let item_classes = |lenient: &LenientTable<ItemClass>| {
+            let mut written = Vec::new();
+            lenient.write(&mut written).unwrap();
+
+            let written = ItemClass::read_lenient(&mut written.as_slice()).unwrap();
+            written
+                .rows
+                .iter()
+                .map(|a| {
+                    let value = a.coercion("item_class").map(|a| a.value());
+                    (a.row.id.id, value)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut lenient = ItemClass::read_lenient(&mut b.as_slice()).unwrap();
+        lenient.rows.remove(0);
+        assert_eq!(item_classes(&lenient), [(2, Some(77)), (3, None)]);
+
+        let mut lenient = ItemClass::read_lenient(&mut b.as_slice()).unwrap();
+        lenient.rows.reverse();
+        assert_eq!(
+            item_classes(&lenient),
+            [(3, None), (2, Some(77)), (1, None)]
+        );
+
+        // the original value moves with the row even if other fields change
+        let mut lenient = ItemClass::read_lenient(&mut b.as_slice()).unwrap();
+        lenient.rows[1].row.id = ItemClassKey::new(4);
+        lenient.rows.push(item_class_row(5, "Key").into());
+        assert_eq!(
+            item_classes(&lenient),
+            [(1, None), (4, Some(77)), (3, None), (5, None)]
+        );
+
+        // removed rows are not written
+        let mut lenient = ItemClass::read_lenient(&mut b.as_slice()).unwrap();
+        lenient.rows.remove(1);
+        assert_eq!(item_classes(&lenient), [(1, None), (3, None)]);
+    }
+
+    #[test]
+    fn array_elements() {
+        let rows = vec![CharacterCreateCamerasRow {
+            id: CharacterCreateCamerasKey::new(1),
+            unknown: [false, true],
+            unknown_2: [0.0; 3],
+        }];
+        let mut b = Vec::new();
+        CharacterCreateCameras { rows }.write(&mut b).unwrap();
+        b[20 + 4..20 + 8].copy_from_slice(&2_u32.to_le_bytes());
+        b[20 + 8..20 + 12].copy_from_slice(&3_u32.to_le_bytes());
+
+        let mut lenient = CharacterCreateCameras::read_lenient(&mut b.as_slice()).unwrap();
+        let row = &lenient.rows[0];
+        assert_eq!(row.coercions().len(), 2);
+        assert_eq!(row.coercion("unknown"), None);
+        assert_eq!(row.coercion("unknown[0]").unwrap().value(), 2);
+        assert_eq!(row.coercion("unknown[1]").unwrap().value(), 3);
+        assert_eq!(row.coercion("unknown[1]").unwrap().field_offset(), 8);
+
+        // only the removed element is written from the row
+        assert!(lenient.rows[0].remove_coercion("unknown[1]").is_some());
+        let mut written = Vec::new();
+        lenient.write(&mut written).unwrap();
+        assert_eq!(written[20 + 4..20 + 8], 2_u32.to_le_bytes());
+        assert_eq!(written[20 + 8..20 + 12], 1_u32.to_le_bytes());
+    }
+}
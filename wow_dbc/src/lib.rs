@@ -16,6 +16,19 @@
 //! Tables that have a primary key additionally have a type suffixed with `Key` ([`ItemClassKey`](`vanilla_tables::item_class::ItemClassKey`)),
 //! and the table ([`ItemClass`](`vanilla_tables::item_class::ItemClass`)) implements the [`Indexable`] trait.
 //!
+//! Every table can also be read without copying strings out of the string block through [`DbcTableRef`].
+//! This parses rows lazily and returns the borrowed row type suffixed with `RowRef`
+//! ([`ItemClassRowRef`](`vanilla_tables::item_class::ItemClassRowRef`)), which can be converted into the owned row with [`Into`].
+//!
+//! Every table module also has an `AnyTable` enum ([`vanilla_tables::AnyTable`]) with a variant for every table,
+//! which can read files whose table is only known at runtime with `AnyTable::read_by_filename`.
+//!
+//! A whole directory of DBC files can be opened with `DbcDatabase` ([`vanilla_tables::DbcDatabase`]),
+//! which reads tables the first time they are used.
+//!
+//! Files without a table definition can be read and written through [`RawDbc`], which only knows about the [`header`],
+//! or through [`DynamicTable`] with a [`Schema`] supplied at runtime.
+//!
 //! [`Gender`] and [`SizeClass`] are in the crate root because they are used in multiple tables and have been de-duplicated in order to reduce the amount of types in the crate.
 //!
 //! # Installation
@@ -34,14 +47,28 @@
 //!
 //! # Features
 //!
-//! By default no features are enabled.
+//! By default only the `std` feature is enabled.
 //! The following features are valid:
+//! * `std`, for reading and writing through `std::io`, and for `DbcDatabase`, `DbcDirectory`, and `DbcTable::iter_rows`.
+//!   Without it only `core` and `alloc` are used, and tables are read from `&[u8]` and written to `Vec<u8>` through the traits in [`io`].
+//!   The `xml`, `csv`, `tokio`, and `rayon` features enable `std`.
 //! * `vanilla`, for 1.12 client data.
 //! * `tbc`, for 2.4.3.8606 client data.
 //! * `wrath`, for 3.3.5.12340 client data.
+//! * `serde`, for serializing and deserializing tables, [`TableDiff`]s, [`Patch`]es, and [`MergeConflict`]s.
+//! * `xml`, for parsing [`Schema`]s for [`DynamicTable`] from the XML table definitions.
+//! * `csv`, for reading and writing tables as CSV files with `DbcTable::read_csv` and `DbcTable::write_csv`.
+//! * `tokio`, for reading and writing tables with async readers and writers through `AsyncDbcTable`.
+//! * `rayon`, for reading the rows of large tables in parallel in `DbcTable::read`,
+//!   and reading the files of `DbcDatabase::read_all` in parallel.
 //!
 //! To add only a specific version, remove the unneeded ones from the `features` list above.
 //!
+//! Without `std` the crate only depends on `core` and `alloc`, except for the `vanilla`, `tbc`, and `wrath` features.
+//! These depend on [`wow_world_base`] for enums and flags, which requires `std` and does not support `no_std` yet.
+//! The table definitions can therefore be used without the `std` feature on targets that have `std`, like `wasm32-unknown-unknown`,
+//! but not on targets without `std`, where only [`RawDbc`], [`DynamicTable`], and the [`header`] can be used.
+//!
 //! # Tests
 //!
 //! Published builds do not have any tests for individual tables,
@@ -50,6 +77,7 @@
 //! The DBC files must be placed in the root of the repository.
 //!
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![forbid(unsafe_code)]
@@ -68,14 +96,105 @@ clippy::unseparated_literal_suffix,
 missing_docs
 )]
 
-use std::io::{Read, Write};
+extern crate alloc;
+
+use crate::io::{Read, Write};
+use crate::prelude::*;
+#[cfg(feature = "std")]
+use std::io::Seek;
+
+pub mod io;
 
 pub(crate) mod error;
 
 pub use error::*;
 
-#[allow(unused)]
-pub(crate) mod header;
+mod borrowed;
+
+pub use borrowed::*;
+
+mod indexed;
+
+pub use indexed::*;
+
+mod lenient;
+
+pub use lenient::*;
+
+mod encoding;
+
+pub use encoding::*;
+
+mod identify;
+
+pub use identify::*;
+
+#[cfg(feature = "std")]
+mod database;
+
+#[cfg(feature = "std")]
+pub use database::*;
+
+// references are only checked and found through the `DbcDatabase` of the table modules
+#[cfg(all(
+    feature = "std",
+    any(feature = "vanilla", feature = "tbc", feature = "wrath")
+))]
+mod references;
+
+#[cfg(all(
+    feature = "std",
+    any(feature = "vanilla", feature = "tbc", feature = "wrath")
+))]
+pub use references::*;
+
+mod fields;
+
+pub use fields::*;
+
+mod diff;
+
+pub use diff::*;
+
+mod patch;
+
+pub use patch::*;
+
+mod merge;
+
+pub use merge::*;
+
+#[cfg(feature = "csv")]
+mod csv_table;
+
+#[cfg(feature = "csv")]
+pub use csv_table::*;
+
+#[cfg(feature = "tokio")]
+mod async_table;
+
+#[cfg(feature = "tokio")]
+pub use async_table::*;
+
+pub mod header;
+
+mod raw;
+
+pub use raw::*;
+
+mod preserved;
+
+pub use preserved::*;
+
+#[cfg(feature = "std")]
+mod stream;
+
+#[cfg(feature = "std")]
+pub use stream::*;
+
+mod dynamic;
+
+pub use dynamic::*;
 
 #[allow(missing_docs, clippy::unnecessary_cast)]
 #[cfg(feature = "vanilla")]
@@ -91,16 +210,36 @@ pub mod wrath_tables;
 
 mod tys;
 
+#[cfg(all(test, feature = "vanilla"))]
+mod fixtures;
+
 #[allow(unused)]
 pub use tys::*;
 
 #[allow(unused)]
 mod util;
 
+pub use util::StringCache;
+
+/// Items of the `std` prelude that are in `alloc`, which are not in scope without the `std` prelude.
+mod prelude {
+    pub(crate) use alloc::boxed::Box;
+    pub(crate) use alloc::format;
+    pub(crate) use alloc::string::{String, ToString};
+    pub(crate) use alloc::vec;
+    pub(crate) use alloc::vec::Vec;
+}
+
 /// Main trait for the crate. Implemented by all tables in [`vanilla_tables`].
 pub trait DbcTable: Sized {
     /// Will be the name of the implementing type suffixed with `Row`.
-    type Row;
+    type Row: RowFields;
+
+    /// Borrowed version of [`DbcTable::Row`] that references strings directly in the string block.
+    ///
+    /// Will be the name of the implementing type suffixed with `RowRef`,
+    /// or just [`DbcTable::Row`] for tables that do not contain any strings.
+    type RowRef<'a>: Into<Self::Row>;
 
     /// The name of the DBC file _with_ `.dbc` at the end.
     const FILENAME: &'static str;
@@ -111,10 +250,18 @@ pub trait DbcTable: Sized {
     /// The size of each row in bytes.
     const ROW_SIZE: usize;
 
+    /// Offsets in bytes from the start of the row of every string reference,
+    /// in the order they are read and written. Flags of localized strings are not included.
+    const STRING_REFERENCE_OFFSETS: &'static [usize];
+
     /// Array of all rows. Are not guaranteed to be in any order.
     fn rows(&self) -> &[Self::Row];
     /// Mutable array of all rows. Are not guaranteed to be in any order.
     fn rows_mut(&mut self) -> &mut [Self::Row];
+    /// Mutable access to the underlying storage of all rows, for adding and removing rows.
+    fn rows_vec_mut(&mut self) -> &mut Vec<Self::Row>;
+    /// Creates the table from `rows`.
+    fn from_rows(rows: Vec<Self::Row>) -> Self;
 
     /// Read table from bytes.
     ///
@@ -123,34 +270,382 @@ pub trait DbcTable: Sized {
     /// Returns the same errors as [`Read::read_exact`].
     ///
     /// Will error with [`InvalidHeaderError`] if the magic numbers (`0x43424457`) at the start of the file do not match.
+    ///
+    /// Will error with [`MalformedError`] if the file is shorter than the header says or strings point outside of the string block.
+    /// Reading never panics on malformed input.
     fn read(b: &mut impl Read) -> Result<Self, DbcError>;
+
+    /// Iterator that reads rows one at a time from `reader` instead of reading the whole table.
+    ///
+    /// See [`RowIter`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same header errors as [`DbcTable::read`], and errors from reading the string block.
+    #[cfg(feature = "std")]
+    fn iter_rows<R: Read + Seek>(reader: R) -> Result<RowIter<Self, R>, DbcError> {
+        RowIter::new(reader)
+    }
+
+    /// Read table from bytes, replacing values that are invalid for their enum instead of returning an error.
+    ///
+    /// See [`LenientTable`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`DbcTable::read`] except for invalid enum values.
+    fn read_lenient(b: &mut impl Read) -> Result<LenientTable<Self>, DbcError> {
+        LenientTable::read(b)
+    }
+
+    /// Read table from bytes, decoding strings that are not valid UTF-8 with `decoding` instead of returning an error.
+    ///
+    /// See [`DecodedTable`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`DbcTable::read`],
+    /// except for [`DbcError::String`] if `decoding` is not [`StringDecoding::Strict`].
+    fn read_decoding(
+        b: &mut impl Read,
+        decoding: StringDecoding,
+    ) -> Result<DecodedTable<Self>, DbcError> {
+        DecodedTable::read(b, decoding)
+    }
+
+    /// Read table from a CSV file with a header, see [`CsvRow`] for the columns and values.
+    ///
+    /// Columns can be in any order.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CsvError`] if the file is not valid CSV, columns are missing or unknown, or values are invalid.
+    #[cfg(feature = "csv")]
+    fn read_csv(r: impl Read) -> Result<Self, CsvError>
+    where
+        Self::Row: CsvRow,
+    {
+        csv_table::read_csv(r)
+    }
+
+    /// Read table from bytes, keeping the original string block so that it can be written back byte for byte.
+    ///
+    /// See [`PreservedTable`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`DbcTable::read`].
+    fn read_preserving(b: &mut impl Read) -> Result<PreservedTable<Self>, DbcError> {
+        PreservedTable::read(b)
+    }
+
+    /// Read a single row from `record` without copying any strings out of `string_block`.
+    ///
+    /// `record` must be exactly [`DbcTable::ROW_SIZE`] bytes.
+    /// Prefer [`DbcTableRef`] over calling this directly.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`DbcTable::read`] for the row,
+    /// and [`MalformedError::Truncated`] if `record` is shorter than [`DbcTable::ROW_SIZE`].
+    fn read_row_ref<'a>(
+        record: &'a [u8],
+        string_block: &'a [u8],
+    ) -> Result<Self::RowRef<'a>, DbcError> {
+        Self::read_row_ref_with(record, string_block, None)
+    }
+
+    /// Same as [`DbcTable::read_row_ref`], except that invalid enum and bool values are coerced
+    /// and recorded in `coercions` instead of returning an error, if `coercions` is [`Some`].
+    ///
+    /// The [`Coercion::row`] of recorded coercions is always 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`DbcTable::read`] for the row.
+    fn read_row_ref_with<'a>(
+        record: &'a [u8],
+        string_block: &'a [u8],
+        coercions: Option<&mut Vec<Coercion>>,
+    ) -> Result<Self::RowRef<'a>, DbcError>;
     /// Write to bytes.
     ///
     /// The string block will always start with a zero byte so that a string index of 0 is always an empty string.
     ///
     /// This is not guaranteed to create the exact same binary as is shipped with the game, but it will be semantically the same.
+    /// Use [`DbcTable::read_preserving`] for writing the exact same binary.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Write::write_all`].
+    fn write(&self, w: &mut impl Write) -> Result<(), io::Error> {
+        self.write_with(w, &mut StringCache::new())
+    }
+
+    /// Write to bytes, writing all strings with `encoding`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Write::write_all`].
+    fn write_encoding(
+        &self,
+        w: &mut impl Write,
+        encoding: StringEncoding,
+    ) -> Result<(), io::Error> {
+        self.write_with(w, &mut StringCache::with_encoding(encoding))
+    }
+
+    /// Write to `w` as a CSV file with a header, see [`CsvRow`] for the columns and values.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Write::write_all`] wrapped in [`CsvError::Csv`].
+    #[cfg(feature = "csv")]
+    fn write_csv(&self, w: impl Write) -> Result<(), CsvError>
+    where
+        Self::Row: CsvRow,
+    {
+        csv_table::write_csv(self, w)
+    }
+
+    /// Write to bytes, adding all strings to `string_cache` and writing it as the string block.
     ///
     /// # Errors
     ///
     /// Returns the same errors as [`Write::write_all`].
-    fn write(&self, w: &mut impl Write) -> Result<(), std::io::Error>;
+    fn write_with(
+        &self,
+        w: &mut impl Write,
+        string_cache: &mut StringCache,
+    ) -> Result<(), io::Error>;
 }
 
 /// Implemented by tables that have a primary key.
 ///
-/// This is a separate trait instead of just implementing [`std::ops::Index`] and [`std::ops::IndexMut`] since
+/// This is a separate trait instead of just implementing [`core::ops::Index`] and [`core::ops::IndexMut`] since
 /// those traits do not return [`Option`]s and only have the possibility of panicking on invalid keys.
 ///
 /// The original DBCs do not really respect primary/foreign keys, so this just seemed like it would make everything more annoying.
+///
+/// Use [`Indexable::indexed`] for constant time lookups when doing many lookups into the same table.
 pub trait Indexable: DbcTable {
     /// Key used to index into the table. Same name as the table suffixed with `Key`.
-    type PrimaryKey;
+    type PrimaryKey: Copy + Eq + Ord + core::hash::Hash;
 
-    /// Gets the primary key, if present. Internally this is just [`std::iter::Iterator::find`] since the
+    /// Returns the primary key of `row`.
+    fn primary_key(row: &Self::Row) -> Self::PrimaryKey;
+
+    /// Gets the primary key, if present. Internally this is just [`Iterator::find`] since the
     /// items are not guaranteed to be ordered nor even be present.
     fn get(&self, key: impl TryInto<Self::PrimaryKey>) -> Option<&Self::Row>;
 
-    /// Gets the primary key, if present. Internally this is just [`std::iter::Iterator::find`] since the
+    /// Gets the primary key, if present. Internally this is just [`Iterator::find`] since the
     /// items are not guaranteed to be ordered nor even be present.
     fn get_mut(&mut self, key: impl TryInto<Self::PrimaryKey>) -> Option<&mut Self::Row>;
+
+    /// Returns a primary key that is not used by any row, one larger than the largest key in the table.
+    ///
+    /// Returns 1 for empty tables, since a key of 0 does not reference any row.
+    /// Returns [`None`] if the largest key is the largest value of the key type.
+    fn next_free_key(&self) -> Option<Self::PrimaryKey>;
+
+    /// Returns `true` if a row with the primary key exists.
+    fn contains_key(&self, key: impl TryInto<Self::PrimaryKey>) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Adds `row` to the end of the table.
+    ///
+    /// # Errors
+    ///
+    /// Returns the row in a [`DuplicateKeyError`] if a row with the same primary key already exists.
+    fn push(&mut self, row: Self::Row) -> Result<(), DuplicateKeyError<Self::Row>> {
+        if self.contains_key(Self::primary_key(&row)) {
+            return Err(DuplicateKeyError::new::<Self>(row));
+        }
+
+        self.rows_vec_mut().push(row);
+
+        Ok(())
+    }
+
+    /// Adds `row` before the first row with a larger primary key, keeping tables sorted by [`Indexable::sort_by_primary_key`] sorted.
+    ///
+    /// # Errors
+    ///
+    /// Returns the row in a [`DuplicateKeyError`] if a row with the same primary key already exists.
+    fn insert_sorted(&mut self, row: Self::Row) -> Result<(), DuplicateKeyError<Self::Row>> {
+        if self.contains_key(Self::primary_key(&row)) {
+            return Err(DuplicateKeyError::new::<Self>(row));
+        }
+
+        let key = Self::primary_key(&row);
+        let index = self
+            .rows()
+            .partition_point(|a| Self::primary_key(a) < key);
+        self.rows_vec_mut().insert(index, row);
+
+        Ok(())
+    }
+
+    /// Replaces the row with the same primary key as `row` and returns the old row,
+    /// or adds `row` if there is no row with the key.
+    ///
+    /// Rows are added in the same place as [`Indexable::insert_sorted`] if the table is sorted by primary key,
+    /// and to the end of the table otherwise.
+    fn upsert(&mut self, row: Self::Row) -> Option<Self::Row> {
+        let key = Self::primary_key(&row);
+
+        match self.get_mut(key) {
+            Some(existing) => Some(core::mem::replace(existing, row)),
+            None => {
+                let rows = self.rows();
+                let index = if rows.is_sorted_by_key(Self::primary_key) {
+                    rows.partition_point(|a| Self::primary_key(a) < key)
+                } else {
+                    rows.len()
+                };
+                self.rows_vec_mut().insert(index, row);
+
+                None
+            }
+        }
+    }
+
+    /// Removes and returns the first row with the primary key, if present.
+    ///
+    /// The order of the remaining rows is preserved.
+    fn remove(&mut self, key: impl TryInto<Self::PrimaryKey>) -> Option<Self::Row> {
+        let key = key.try_into().ok()?;
+        let index = self.rows().iter().position(|a| Self::primary_key(a) == key)?;
+
+        Some(self.rows_vec_mut().remove(index))
+    }
+
+    /// Sorts the rows by primary key. Rows with the same key keep their order.
+    fn sort_by_primary_key(&mut self) {
+        self.rows_mut().sort_by_key(Self::primary_key);
+    }
+
+    /// Compares the rows of `self` against the rows of `new`, matching rows by primary key.
+    ///
+    /// See [`TableDiff`] for how changes are reported.
+    fn diff(&self, new: &Self) -> TableDiff {
+        TableDiff::new(self, new)
+    }
+
+    /// Applies the deletes, upserts, and sets of `patch`, see [`Patch`].
+    ///
+    /// The table is only changed if the whole patch applies.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PatchError`] if rows to delete or change do not exist, fields do not have the expected old value,
+    /// or values are invalid for their fields.
+    fn apply_patch(&mut self, patch: &Patch) -> Result<(), PatchError>
+    where
+        Self: Clone,
+    {
+        patch::apply_patch(self, patch)
+    }
+
+    /// Three-way merges `self` and `theirs`, which were both changed from `base`, matching rows by primary key.
+    ///
+    /// Fields changed on only one side take that change, and fields changed differently on both sides are reported
+    /// as conflicts, see [`TableMerge`].
+    fn merge(&self, base: &Self, theirs: &Self) -> TableMerge<Self>
+    where
+        Self::Row: Clone,
+    {
+        merge::merge(self, base, theirs)
+    }
+
+    /// Wraps the table in an [`IndexedTable`] which keeps a map of primary keys to rows.
+    fn indexed(self) -> IndexedTable<Self> {
+        IndexedTable::new(self)
+    }
+}
+
+/// Implemented by the primary key types of tables, which are named after the table suffixed with `Key`.
+///
+/// Foreign keys in other tables use the same type, so they can be followed with [`TableKey::resolve`]
+/// or with `DbcDatabase::resolve` ([`vanilla_tables::DbcDatabase::resolve`]).
+///
+/// A key of 0 is treated as not referencing any row, since this is how the game marks missing foreign keys.
+/// A few tables, such as `Map`, have a valid row with a key of 0 which must be looked up with [`Indexable::get`] instead.
+pub trait TableKey: Copy + Eq + core::hash::Hash {
+    /// The table that this is the primary key of.
+    type Table: Indexable<PrimaryKey = Self>;
+
+    /// Returns `true` if the key is 0.
+    fn is_none(&self) -> bool;
+
+    /// Returns the row with this key in `table`, or [`None`] if the key is 0 or the row does not exist.
+    fn resolve(self, table: &Self::Table) -> Option<&<Self::Table as DbcTable>::Row> {
+        if self.is_none() {
+            return None;
+        }
+
+        table.get(self)
+    }
+
+    /// Same as [`TableKey::resolve`] for an [`IndexedTable`].
+    fn resolve_indexed(
+        self,
+        table: &IndexedTable<Self::Table>,
+    ) -> Option<&<Self::Table as DbcTable>::Row> {
+        if self.is_none() {
+            return None;
+        }
+
+        table.get(self)
+    }
+}
+
+#[cfg(all(test, feature = "vanilla"))]
+mod test {
+    use crate::fixtures::spell_icon_row;
+    use crate::vanilla_tables::spell_icon::{SpellIcon, SpellIconKey};
+    use crate::{DbcTable, Indexable};
+
+    #[test]
+    fn add_and_remove_rows() {
+        let mut table = SpellIcon::from_rows(Vec::new());
+        assert_eq!(table.next_free_key(), Some(SpellIconKey::new(1)));
+
+        table.push(spell_icon_row(3, "c")).unwrap();
+        table.push(spell_icon_row(1, "a")).unwrap();
+        let e = table.push(spell_icon_row(3, "duplicate")).unwrap_err();
+        assert_eq!(e.row.texture_file, "duplicate");
+        assert_eq!(e.table, "SpellIcon.dbc");
+        assert_eq!(table.next_free_key(), Some(SpellIconKey::new(4)));
+
+        table.sort_by_primary_key();
+        table.insert_sorted(spell_icon_row(2, "b")).unwrap();
+        assert!(table.insert_sorted(spell_icon_row(2, "duplicate")).is_err());
+        let ids = table.rows().iter().map(|a| a.id.id).collect::<Vec<_>>();
+        assert_eq!(ids, [1, 2, 3]);
+
+        assert!(table.upsert(spell_icon_row(4, "d")).is_none());
+        assert_eq!(table.upsert(spell_icon_row(2, "new")).unwrap().texture_file, "b");
+        assert_eq!(table.get(2_u32).unwrap().texture_file, "new");
+
+        // sorted tables stay sorted, other tables get the row at the end
+        let mut sorted = SpellIcon::from_rows(vec![spell_icon_row(1, "a"), spell_icon_row(3, "c")]);
+        assert!(sorted.upsert(spell_icon_row(2, "b")).is_none());
+        let ids = sorted.rows().iter().map(|a| a.id.id).collect::<Vec<_>>();
+        assert_eq!(ids, [1, 2, 3]);
+        sorted.rows.reverse();
+        assert!(sorted.upsert(spell_icon_row(0, "z")).is_none());
+        let ids = sorted.rows().iter().map(|a| a.id.id).collect::<Vec<_>>();
+        assert_eq!(ids, [3, 2, 1, 0]);
+
+        assert_eq!(table.remove(1_u32).unwrap().texture_file, "a");
+        assert!(table.remove(1_u32).is_none());
+        let ids = table.rows().iter().map(|a| a.id.id).collect::<Vec<_>>();
+        assert_eq!(ids, [2, 3, 4]);
+
+        table.push(spell_icon_row(u32::MAX, "max")).unwrap();
+        assert_eq!(table.next_free_key(), None);
+    }
 }
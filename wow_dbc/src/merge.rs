@@ -0,0 +1,309 @@
+use crate::fields::{identical, leaves};
+use crate::prelude::*;
+use crate::util::{Map, Set};
+use crate::{Indexable, RowFields, Value};
+use core::fmt::{Display, Formatter};
+
+/// Result of a three-way merge. Created by [`Indexable::merge`].
+///
+/// Rows are matched by primary key, and changes are merged per field with
+/// strings of localized strings and elements of arrays merged separately.
+/// If multiple rows have the same key only the first row with the key is used from the base and their table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableMerge<T> {
+    /// The merged table.
+    ///
+    /// Conflicts are resolved by keeping our side, so the table can be written as-is
+    /// or have the conflicts resolved differently first, for example with [`RowFields::set`].
+    pub table: T,
+    /// Every change that was made differently on both sides, in the order of our table.
+    pub conflicts: Vec<MergeConflict>,
+}
+
+impl<T> TableMerge<T> {
+    /// Returns `true` if the tables merged without conflicts.
+    pub const fn is_clean(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+}
+
+/// Side of a [`MergeConflict`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MergeSide {
+    /// The table [`Indexable::merge`] is called on.
+    Ours,
+    /// The table passed as `theirs`.
+    Theirs,
+}
+
+impl Display for MergeSide {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Ours => "ours",
+            Self::Theirs => "theirs",
+        })
+    }
+}
+
+/// A change that was made differently on both sides of a merge.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MergeConflict {
+    /// The same field was changed to different values on both sides.
+    Field {
+        /// Primary key of the row.
+        id: i64,
+        /// Name of the field, using the same names as [`FieldChange::field`](crate::FieldChange::field).
+        field: String,
+        /// Value in the base table, or `None` if the row was added on both sides.
+        base: Option<Value>,
+        /// Value in our table, which is used in the merged table.
+        ours: Value,
+        /// Value in their table.
+        theirs: Value,
+    },
+    /// One side deleted a row that the other side changed.
+    ///
+    /// The merged table keeps our side, so the row is missing if we deleted it.
+    Deleted {
+        /// Primary key of the row.
+        id: i64,
+        /// The side that deleted the row.
+        deleted_by: MergeSide,
+    },
+}
+
+impl MergeConflict {
+    /// Primary key of the row.
+    pub const fn id(&self) -> i64 {
+        match self {
+            Self::Field { id, .. } | Self::Deleted { id, .. } => *id,
+        }
+    }
+}
+
+impl Display for MergeConflict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Field {
+                id,
+                field,
+                base,
+                ours,
+                theirs,
+            } => {
+                write!(f, "row {id} field '{field}' changed to {ours:?} by ours and to {theirs:?} by theirs")?;
+                match base {
+                    Some(base) => write!(f, " from {base:?}"),
+                    None => f.write_str(" in a row added by both"),
+                }
+            }
+            Self::Deleted { id, deleted_by } => {
+                write!(
+                    f,
+                    "row {id} deleted by {deleted_by} but changed by the other side"
+                )
+            }
+        }
+    }
+}
+
+pub(crate) fn merge<T: Indexable>(ours: &T, base: &T, theirs: &T) -> TableMerge<T>
+where
+    T::Row: Clone,
+{
+    let base_rows = first_rows(base);
+    let theirs_rows = first_rows(theirs);
+
+    let mut rows = Vec::with_capacity(ours.rows().len());
+    let mut conflicts = Vec::new();
+    let mut our_keys = Set::new();
+
+    for mut row in ours.rows().iter().cloned() {
+        let key = T::primary_key(&row);
+        our_keys.insert(key);
+
+        match (base_rows.get(&key), theirs_rows.get(&key)) {
+            // added by us
+            (None, None) => {}
+            // deleted by them
+            (Some(base), None) => {
+                if is_unchanged(*base, &row) {
+                    continue;
+                }
+
+                conflicts.push(MergeConflict::Deleted {
+                    id: id(&row),
+                    deleted_by: MergeSide::Theirs,
+                });
+            }
+            (base, Some(theirs)) => merge_row(&mut row, base.copied(), *theirs, &mut conflicts),
+        }
+
+        rows.push(row);
+    }
+
+    for row in theirs.rows() {
+        let key = T::primary_key(row);
+        if !our_keys.insert(key) {
+            continue;
+        }
+
+        match base_rows.get(&key) {
+            // added by them
+            None => rows.push(row.clone()),
+            // deleted by us
+            Some(base) => {
+                if !is_unchanged(*base, row) {
+                    conflicts.push(MergeConflict::Deleted {
+                        id: id(row),
+                        deleted_by: MergeSide::Ours,
+                    });
+                }
+            }
+        }
+    }
+
+    TableMerge {
+        table: T::from_rows(rows),
+        conflicts,
+    }
+}
+
+/// Merges the changes from `theirs` into `ours`, with `base` being `None` for rows added on both sides.
+fn merge_row<R: RowFields>(
+    ours: &mut R,
+    base: Option<&R>,
+    theirs: &R,
+    conflicts: &mut Vec<MergeConflict>,
+) {
+    let base = base.map(leaves);
+    for (i, ((field, our_value), (_, their_value))) in
+        leaves(ours).into_iter().zip(leaves(theirs)).enumerate()
+    {
+        if identical(&our_value, &their_value) {
+            continue;
+        }
+
+        let base = base.as_ref().map(|a| &a[i].1);
+        match base {
+            // only changed by them, setting can only fail if the row type is inconsistent
+            Some(base)
+                if identical(base, &our_value) && ours.set(&field, their_value.clone()).is_ok() =>
+            {
+                continue;
+            }
+            // only changed by us
+            Some(base) if identical(base, &their_value) => continue,
+            _ => {}
+        }
+
+        conflicts.push(MergeConflict::Field {
+            id: id(ours),
+            field,
+            base: base.cloned(),
+            ours: our_value,
+            theirs: their_value,
+        });
+    }
+}
+
+fn first_rows<T: Indexable>(table: &T) -> Map<T::PrimaryKey, &T::Row> {
+    let mut rows = Map::new();
+    for row in table.rows() {
+        rows.entry(T::primary_key(row)).or_insert(row);
+    }
+
+    rows
+}
+
+fn is_unchanged<R: RowFields>(base: &R, row: &R) -> bool {
+    leaves(base)
+        .iter()
+        .zip(leaves(row).iter())
+        .all(|((_, a), (_, b))| identical(a, b))
+}
+
+/// Only tables with a primary key can be merged.
+fn id<R: RowFields>(row: &R) -> i64 {
+    row.id().unwrap_or_default()
+}
+
+#[cfg(all(test, feature = "vanilla"))]
+mod test {
+    use super::*;
+    use crate::fixtures::item_class_row;
+    use crate::vanilla_tables::item_class::ItemClass;
+
+    #[test]
+    fn merge() {
+        let base = ItemClass {
+            rows: vec![
+                item_class_row(1, "Weapon"),
+                item_class_row(2, "Armor"),
+                item_class_row(3, "Quest"),
+                item_class_row(4, "Key"),
+            ],
+        };
+
+        let mut ours = base.clone();
+        ours.get_mut(1_u32).unwrap().class_name.de_de = "Waffe".to_string();
+        ours.get_mut(2_u32).unwrap().subclass_map = 1;
+        ours.get_mut(3_u32).unwrap().subclass_map = 1;
+        ours.remove(4_u32).unwrap();
+        ours.push(item_class_row(5, "Reagent")).unwrap();
+
+        let mut theirs = base.clone();
+        theirs.get_mut(1_u32).unwrap().class_name.fr_fr = "Arme".to_string();
+        theirs.get_mut(2_u32).unwrap().subclass_map = 2;
+        theirs.remove(3_u32).unwrap();
+        theirs.get_mut(4_u32).unwrap().subclass_map = 2;
+        theirs.push(item_class_row(6, "Projectile")).unwrap();
+
+        let merge = ours.merge(&base, &theirs);
+        assert!(!merge.is_clean());
+        assert_eq!(
+            merge.conflicts,
+            [
+                MergeConflict::Field {
+                    id: 2,
+                    field: "subclass_map".to_string(),
+                    base: Some(Value::Int(0)),
+                    ours: Value::Int(1),
+                    theirs: Value::Int(2),
+                },
+                MergeConflict::Deleted {
+                    id: 3,
+                    deleted_by: MergeSide::Theirs,
+                },
+                MergeConflict::Deleted {
+                    id: 4,
+                    deleted_by: MergeSide::Ours,
+                },
+            ]
+        );
+
+        let mut weapon = item_class_row(1, "Weapon");
+        weapon.class_name.de_de = "Waffe".to_string();
+        weapon.class_name.fr_fr = "Arme".to_string();
+        let mut armor = item_class_row(2, "Armor");
+        armor.subclass_map = 1;
+        let mut quest = item_class_row(3, "Quest");
+        quest.subclass_map = 1;
+        assert_eq!(
+            merge.table.rows,
+            [
+                weapon,
+                armor,
+                quest,
+                item_class_row(5, "Reagent"),
+                item_class_row(6, "Projectile")
+            ]
+        );
+
+        let merge = base.merge(&base, &theirs);
+        assert!(merge.is_clean());
+        assert_eq!(merge.table, theirs);
+    }
+}
@@ -0,0 +1,462 @@
+use crate::fields::LOCALES;
+use crate::prelude::*;
+use crate::{Indexable, PatchError, RowFields, SetFieldError, Value};
+use alloc::collections::BTreeMap;
+
+/// Edits to a table that are applied with [`Indexable::apply_patch`].
+///
+/// With the `serde` feature patches can be stored in a human editable format like TOML or JSON instead of as binary DBC files:
+///
+/// ```toml
+/// delete = [3]
+///
+/// [[upsert]]
+/// id = 4
+/// subclass_map = 0
+/// item_class = 0
+/// class_name = { en_gb = "Key" }
+///
+/// [[set]]
+/// id = 1
+/// field = "class_name.de_de"
+/// value = "Waffe"
+/// old = ""
+/// ```
+///
+/// Deletes are applied first, then upserts, and then sets.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Patch {
+    /// Primary keys of rows to delete. The rows must exist.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub delete: Vec<i64>,
+    /// Rows to add, or to replace if a row with the same primary key exists.
+    /// Rows are added in the same place as with [`Indexable::upsert`].
+    ///
+    /// Every row must have a value for every field.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub upsert: Vec<BTreeMap<String, PatchValue>>,
+    /// Single fields to change in existing rows.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub set: Vec<PatchSet>,
+}
+
+/// Change of a single field. Part of [`Patch`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PatchSet {
+    /// Primary key of the row. The row must exist.
+    pub id: i64,
+    /// Name of the field, in the same format as [`RowFields::set`].
+    pub field: String,
+    /// The new value.
+    pub value: PatchValue,
+    /// The value that the field is expected to have before the change.
+    ///
+    /// If the field has a different value, the patch fails with [`PatchError::OldValueMismatch`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub old: Option<PatchValue>,
+}
+
+/// Value of a field in a [`Patch`].
+///
+/// Unlike [`Value`] this does not record the type of the value, so it can be written without type annotations in formats like TOML.
+/// Values are converted into the type of the field when the patch is applied.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum PatchValue {
+    /// All integer types, including bools, enums, flags, and keys.
+    Int(i64),
+    /// Floating point numbers.
+    Float(f64),
+    /// Strings.
+    String(String),
+    /// Arrays.
+    Array(Vec<PatchValue>),
+    /// Localized strings, with the locales of `LocalizedString` as keys and an optional `flags`.
+    /// Missing locales are empty.
+    LocalizedString(BTreeMap<String, PatchValue>),
+}
+
+impl PatchValue {
+    /// Converts into a [`Value`], with `field` being the name of the field for errors.
+    fn into_value(self, field: &str) -> Result<Value, SetFieldError> {
+        Ok(match self {
+            PatchValue::Int(v) => Value::Int(v),
+            PatchValue::Float(v) => Value::Float(v as f32),
+            PatchValue::String(v) => Value::String(v),
+            PatchValue::Array(v) => Value::Array(
+                v.into_iter()
+                    .enumerate()
+                    .map(|(i, a)| a.into_value(&format!("{field}[{i}]")))
+                    .collect::<Result<_, _>>()?,
+            ),
+            PatchValue::LocalizedString(v) => {
+                let mut strings = Vec::new();
+                let mut flags = 0;
+
+                for (locale, value) in v {
+                    let field = format!("{field}.{locale}");
+                    let value = value.into_value(&field)?;
+
+                    if locale == "flags" {
+                        flags = crate::fields::int(&field, value)?;
+                        continue;
+                    }
+
+                    let Some(i) = LOCALES.iter().position(|a| *a == locale) else {
+                        return Err(SetFieldError::UnknownField { field });
+                    };
+                    if strings.len() <= i {
+                        strings.resize(i + 1, String::new());
+                    }
+                    strings[i] = crate::fields::string(&field, value)?;
+                }
+
+                Value::LocalizedString { strings, flags }
+            }
+        })
+    }
+}
+
+impl From<Value> for PatchValue {
+    fn from(v: Value) -> Self {
+        match v {
+            Value::Int(v) | Value::Key(v) => PatchValue::Int(v),
+            Value::Float(v) => PatchValue::Float(v.into()),
+            Value::String(v) => PatchValue::String(v),
+            Value::LocalizedString { strings, flags } => {
+                let mut map = LOCALES
+                    .iter()
+                    .zip(strings)
+                    .map(|(locale, s)| (locale.to_string(), PatchValue::String(s)))
+                    .collect::<BTreeMap<_, _>>();
+                map.insert("flags".to_string(), PatchValue::Int(flags.into()));
+
+                PatchValue::LocalizedString(map)
+            }
+            Value::Array(v) => PatchValue::Array(v.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+/// Applies `patch` to `table`. `table` is only changed if the whole patch applies.
+///
+/// Rows are found through a map of primary keys to row indices that is built once per step,
+/// instead of searching the table for every edit.
+pub(crate) fn apply_patch<T: Indexable + Clone>(
+    table: &mut T,
+    patch: &Patch,
+) -> Result<(), PatchError> {
+    let mut patched = table.clone();
+
+    if !patch.delete.is_empty() {
+        let mut index = row_indices(patched.rows());
+        let mut deleted = vec![false; patched.rows().len()];
+        for &id in &patch.delete {
+            let row = index
+                .remove(&id)
+                .ok_or(PatchError::DeleteMissingRow { id })?;
+            deleted[row] = true;
+        }
+
+        let mut deleted = deleted.into_iter();
+        patched
+            .rows_vec_mut()
+            .retain(|_| !deleted.next().unwrap_or_default());
+    }
+
+    // added rows are sorted in afterwards, same as `Indexable::upsert`
+    let sorted = patched.rows().is_sorted_by_key(T::primary_key);
+    let mut added = false;
+
+    let mut index = row_indices(patched.rows());
+    for (i, row) in patch.upsert.iter().enumerate() {
+        let row = upsert_row::<T::Row>(row)
+            .map_err(|error| PatchError::InvalidUpsert { index: i, error })?;
+
+        match row.id().and_then(|id| index.get(&id)) {
+            Some(&existing) => patched.rows_mut()[existing] = row,
+            None => {
+                if let Some(id) = row.id() {
+                    index.insert(id, patched.rows().len());
+                }
+                patched.rows_vec_mut().push(row);
+                added = true;
+            }
+        }
+    }
+
+    if sorted && added {
+        patched.sort_by_primary_key();
+        index = row_indices(patched.rows());
+    }
+
+    for set in &patch.set {
+        let id = set.id;
+        let Some(&row) = index.get(&id) else {
+            return Err(PatchError::SetMissingRow {
+                id,
+                field: set.field.clone(),
+            });
+        };
+        let row = &mut patched.rows_mut()[row];
+
+        let invalid = |error| PatchError::InvalidSet { id, error };
+        let unknown = || {
+            invalid(SetFieldError::UnknownField {
+                field: set.field.clone(),
+            })
+        };
+
+        if let Some(old) = &set.old {
+            let expected = old.clone().into_value(&set.field).map_err(invalid)?;
+            let actual = row.get(&set.field).ok_or_else(unknown)?;
+
+            if !same_value(&expected, &actual) {
+                return Err(PatchError::OldValueMismatch {
+                    id,
+                    field: set.field.clone(),
+                    expected,
+                    actual,
+                });
+            }
+        }
+
+        let value = set.value.clone().into_value(&set.field).map_err(invalid)?;
+        row.set(&set.field, value).map_err(invalid)?;
+    }
+
+    *table = patched;
+
+    Ok(())
+}
+
+/// Index of the first row with every primary key.
+fn row_indices<R: RowFields>(rows: &[R]) -> BTreeMap<i64, usize> {
+    let mut index = BTreeMap::new();
+    for (i, row) in rows.iter().enumerate() {
+        if let Some(id) = row.id() {
+            index.entry(id).or_insert(i);
+        }
+    }
+
+    index
+}
+
+fn upsert_row<R: RowFields>(row: &BTreeMap<String, PatchValue>) -> Result<R, SetFieldError> {
+    if let Some(field) = row.keys().find(|a| !R::FIELDS.contains(&a.as_str())) {
+        return Err(SetFieldError::UnknownField {
+            field: field.clone(),
+        });
+    }
+
+    let values = R::FIELDS
+        .iter()
+        .map(|field| match row.get(*field) {
+            Some(value) => value.clone().into_value(field),
+            None => Err(SetFieldError::MissingField {
+                field: field.to_string(),
+            }),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    R::from_values(values)
+}
+
+/// Compares values from a patch, which do not know the difference between keys and integers,
+/// or how many strings a localized string has.
+fn same_value(patch: &Value, table: &Value) -> bool {
+    match (patch, table) {
+        (Value::Int(a), Value::Key(b)) => a == b,
+        (Value::Int(a), Value::Float(b)) => *a as f32 == *b,
+        (Value::Float(a), Value::Float(b)) => a.to_bits() == b.to_bits(),
+        (
+            Value::LocalizedString { strings, flags },
+            Value::LocalizedString {
+                strings: table_strings,
+                flags: table_flags,
+            },
+        ) => {
+            flags == table_flags
+                && strings.len() <= table_strings.len()
+                && table_strings
+                    .iter()
+                    .enumerate()
+                    .all(|(i, s)| strings.get(i).map_or(s.is_empty(), |a| a == s))
+        }
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| same_value(a, b))
+        }
+        (a, b) => a == b,
+    }
+}
+
+#[cfg(all(test, feature = "vanilla"))]
+mod test {
+    use super::*;
+    use crate::fixtures::item_class_row;
+    use crate::vanilla_tables::item_class::{ItemClass, ItemClassRow};
+    use crate::DbcTable;
+    use wow_world_base::vanilla::ItemWeaponClass;
+
+    fn set(id: i64, field: &str, value: PatchValue, old: Option<PatchValue>) -> PatchSet {
+        PatchSet {
+            id,
+            field: field.to_string(),
+            value,
+            old,
+        }
+    }
+
+    #[test]
+    fn apply_patch() {
+        let original = ItemClass {
+            rows: vec![
+                item_class_row(1, "Weapon"),
+                item_class_row(2, "Armor"),
+                item_class_row(3, "Quest"),
+            ],
+        };
+
+        let patch = Patch {
+            delete: vec![3],
+            upsert: vec![BTreeMap::from([
+                ("id".to_string(), PatchValue::Int(4)),
+                ("subclass_map".to_string(), PatchValue::Int(0)),
+                ("item_class".to_string(), PatchValue::Int(1)),
+                (
+                    "class_name".to_string(),
+                    PatchValue::LocalizedString(BTreeMap::from([(
+                        "en_gb".to_string(),
+                        PatchValue::String("Key".to_string()),
+                    )])),
+                ),
+            ])],
+            set: vec![
+                set(
+                    1,
+                    "class_name.de_de",
+                    PatchValue::String("Waffe".to_string()),
+                    Some(PatchValue::String(String::new())),
+                ),
+                set(2, "item_class", PatchValue::Int(1), None),
+            ],
+        };
+
+        let mut table = original.clone();
+        ItemClass::apply_patch(&mut table, &patch).unwrap();
+
+        let mut expected = ItemClass {
+            rows: vec![
+                item_class_row(1, "Weapon"),
+                item_class_row(2, "Armor"),
+                item_class_row(4, "Key"),
+            ],
+        };
+        expected.rows[0].class_name.de_de = "Waffe".to_string();
+        expected.rows[1].item_class = ItemWeaponClass::Weapon;
+        expected.rows[2].item_class = ItemWeaponClass::Weapon;
+        assert_eq!(table, expected);
+
+        // the deleted row no longer exists, and the table is left unchanged
+        assert_eq!(
+            table.apply_patch(&patch),
+            Err(PatchError::DeleteMissingRow { id: 3 })
+        );
+        assert_eq!(table, expected);
+
+        let conflict = Patch {
+            set: vec![set(
+                1,
+                "class_name.de_de",
+                PatchValue::String("Schwert".to_string()),
+                Some(PatchValue::String(String::new())),
+            )],
+            ..Default::default()
+        };
+        assert!(matches!(
+            table.apply_patch(&conflict),
+            Err(PatchError::OldValueMismatch { id: 1, .. })
+        ));
+
+        let invalid = Patch {
+            set: vec![set(1, "item_class", PatchValue::Int(100), None)],
+            ..Default::default()
+        };
+        assert!(matches!(
+            table.apply_patch(&invalid),
+            Err(PatchError::InvalidSet {
+                id: 1,
+                error: SetFieldError::InvalidValue { .. }
+            })
+        ));
+
+        let missing = Patch {
+            upsert: vec![BTreeMap::from([("id".to_string(), PatchValue::Int(5))])],
+            ..Default::default()
+        };
+        assert_eq!(
+            table.apply_patch(&missing),
+            Err(PatchError::InvalidUpsert {
+                index: 0,
+                error: SetFieldError::MissingField {
+                    field: "subclass_map".to_string()
+                }
+            })
+        );
+        assert_eq!(table, expected);
+        assert_eq!(table.rows().len(), 3);
+
+        // rows are added in order to sorted tables
+        let row = item_class_row(3, "Quest");
+        let upsert = Patch {
+            upsert: vec![ItemClassRow::FIELDS
+                .iter()
+                .zip(row.values())
+                .map(|(field, value)| (field.to_string(), value.into()))
+                .collect()],
+            ..Default::default()
+        };
+        table.apply_patch(&upsert).unwrap();
+        let ids = table.rows().iter().map(|a| a.id.id).collect::<Vec<_>>();
+        assert_eq!(ids, [1, 2, 3, 4]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn toml() {
+        let patch: Patch = toml::from_str(
+            r#"
+delete = [3]
+
+[[upsert]]
+id = 4
+subclass_map = 0
+item_class = 0
+class_name = { en_gb = "Key" }
+
+[[set]]
+id = 1
+field = "class_name.de_de"
+value = "Waffe"
+old = ""
+"#,
+        )
+        .unwrap();
+
+        let mut table = ItemClass {
+            rows: vec![item_class_row(1, "Weapon"), item_class_row(3, "Quest")],
+        };
+        table.apply_patch(&patch).unwrap();
+
+        let ids = table.rows().iter().map(|a| a.id.id).collect::<Vec<_>>();
+        assert_eq!(ids, [1, 4]);
+        assert_eq!(table.rows()[0].class_name.de_de, "Waffe");
+        assert_eq!(table.rows()[1].class_name.en_gb, "Key");
+    }
+}
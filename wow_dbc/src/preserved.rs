@@ -0,0 +1,126 @@
+use crate::header::{read_records_and_string_block, read_table_header};
+use crate::io::{Read, Write};
+use crate::prelude::*;
+use crate::{DbcError, DbcTable, StringCache};
+
+/// Table read with [`DbcTable::read_preserving`], together with the original string block.
+///
+/// [`DbcTable::write`] deduplicates strings differently from the files shipped with the game,
+/// so writing an unchanged table does not give back the same bytes.
+/// [`PreservedTable::write`] instead keeps the original string block and the original offset of every unchanged string,
+/// and only appends new or changed strings to the end of the string block.
+/// Writing an unchanged table gives back the exact same bytes.
+///
+/// Strings are matched to their original offsets by their position in the table,
+/// so removing or reordering rows will make strings reuse any existing copy in the string block instead.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreservedTable<T: DbcTable> {
+    /// The table that was read.
+    pub table: T,
+    string_block: Vec<u8>,
+    string_offsets: Vec<u32>,
+}
+
+impl<T: DbcTable> PreservedTable<T> {
+    /// Read table from bytes, keeping the string block and all string references.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`DbcTable::read`].
+    pub fn read(b: &mut impl Read) -> Result<Self, DbcError> {
+        let header = read_table_header::<T>(b)?;
+        let (records, string_block) = read_records_and_string_block(b, &header)?;
+
+        let mut rows = Vec::with_capacity(records.len() / T::ROW_SIZE);
+        let mut string_offsets =
+            Vec::with_capacity(rows.capacity() * T::STRING_REFERENCE_OFFSETS.len());
+
+        for (i, record) in records.chunks(T::ROW_SIZE).enumerate() {
+            let row = T::read_row_ref(record, &string_block).map_err(|e| e.with_row(i))?;
+            rows.push(row.into());
+
+            for &offset in T::STRING_REFERENCE_OFFSETS {
+                string_offsets.push(u32::from_le_bytes([
+                    record[offset],
+                    record[offset + 1],
+                    record[offset + 2],
+                    record[offset + 3],
+                ]));
+            }
+        }
+
+        Ok(Self {
+            table: T::from_rows(rows),
+            string_block,
+            string_offsets,
+        })
+    }
+
+    /// Write to bytes, keeping the original string block and string offsets.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Write::write_all`].
+    pub fn write(&self, w: &mut impl Write) -> Result<(), crate::io::Error> {
+        let mut string_cache =
+            StringCache::preserving(self.string_block.clone(), self.string_offsets.clone());
+
+        self.table.write_with(w, &mut string_cache)
+    }
+
+    /// The original string block.
+    pub fn string_block(&self) -> &[u8] {
+        &self.string_block
+    }
+}
+
+#[cfg(all(test, feature = "vanilla"))]
+mod test {
+    use crate::fixtures::spell_icon_row;
+    use crate::header::DbcHeader;
+    use crate::vanilla_tables::spell_icon::SpellIcon;
+    use crate::DbcTable;
+
+    #[test]
+    fn byte_exact() {
+        // "bc" is not deduplicated as a suffix of "abc" like the string cache would do
+        let string_block = b"\0abc\0bc\0".to_vec();
+        let header = DbcHeader {
+            record_count: 2,
+            field_count: SpellIcon::FIELD_COUNT as u32,
+            record_size: SpellIcon::ROW_SIZE as u32,
+            string_block_size: string_block.len() as u32,
+        };
+
+        let mut b = header.write_header().to_vec();
+        for (id, offset) in [(1_u32, 1_u32), (2, 5)] {
+            b.extend_from_slice(&id.to_le_bytes());
+            b.extend_from_slice(&offset.to_le_bytes());
+        }
+        b.extend_from_slice(&string_block);
+
+        let table = SpellIcon::read(&mut b.as_slice()).unwrap();
+        let mut written = Vec::new();
+        table.write(&mut written).unwrap();
+        assert_ne!(written, b);
+
+        let mut preserved = SpellIcon::read_preserving(&mut b.as_slice()).unwrap();
+        assert_eq!(preserved.table, table);
+        let mut written = Vec::new();
+        preserved.write(&mut written).unwrap();
+        assert_eq!(written, b);
+
+        // only the changed string is appended
+        preserved.table.rows[0].texture_file = "new".to_string();
+        preserved.table.rows.push(spell_icon_row(3, "c"));
+        let mut written = Vec::new();
+        preserved.write(&mut written).unwrap();
+
+        let records_end = 20 + 3 * SpellIcon::ROW_SIZE;
+        assert_eq!(&written[records_end..], b"\0abc\0bc\0new\0");
+        assert_eq!(&written[20 + 4..20 + 8], &8_u32.to_le_bytes());
+        assert_eq!(&written[20 + 12..20 + 16], &5_u32.to_le_bytes());
+        // suffix of an existing string
+        assert_eq!(&written[20 + 20..20 + 24], &3_u32.to_le_bytes());
+    }
+}
@@ -0,0 +1,303 @@
+use crate::header::{read_header, read_records_and_string_block, DbcHeader};
+use crate::io::{Read, Write};
+use crate::prelude::*;
+use crate::{DbcError, MalformedError, PushRecordError};
+
+/// Untyped DBC file for tables that do not have a definition in this crate.
+///
+/// Records are kept as raw bytes and can be accessed either as byte slices or as little endian `u32` words.
+/// Strings are looked up by their offset into the string block with [`RawDbc::string`].
+///
+/// ```rust,ignore
+/// let mut file = std::fs::File::open("Custom.dbc")?;
+/// let dbc = RawDbc::read(&mut file)?;
+///
+/// for i in 0..dbc.len() {
+///     let id = dbc.word(i, 0).unwrap();
+///     let name = dbc.string(dbc.word(i, 1).unwrap())?;
+///     println!("{id}: {name}");
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RawDbc {
+    record_count: u32,
+    field_count: u32,
+    record_size: u32,
+    records: Vec<u8>,
+    string_block: Vec<u8>,
+}
+
+impl RawDbc {
+    /// Creates a file without any records and with a string block that only contains the empty string.
+    pub fn new(field_count: u32, record_size: u32) -> Self {
+        Self {
+            record_count: 0,
+            field_count,
+            record_size,
+            records: Vec::new(),
+            string_block: vec![0],
+        }
+    }
+
+    /// Read file from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Read::read_exact`].
+    ///
+    /// Will error with [`InvalidHeaderError`](crate::InvalidHeaderError) if the magic numbers (`0x43424457`) at the start of the file do not match,
+    /// and with [`MalformedError::EmptyRecords`] if the header has records with a size of 0.
+    pub fn read(b: &mut impl Read) -> Result<Self, DbcError> {
+        let header = read_header(b)?;
+        if header.record_size == 0 && header.record_count != 0 {
+            return Err(MalformedError::EmptyRecords {
+                record_count: header.record_count,
+            }
+            .into());
+        }
+        let (records, string_block) = read_records_and_string_block(b, &header)?;
+
+        Ok(Self {
+            record_count: header.record_count,
+            field_count: header.field_count,
+            record_size: header.record_size,
+            records,
+            string_block,
+        })
+    }
+
+    /// Write to bytes.
+    ///
+    /// Records and the string block are written exactly as they are.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Write::write_all`].
+    pub fn write(&self, w: &mut impl Write) -> Result<(), crate::io::Error> {
+        w.write_all(&self.header().write_header())?;
+        w.write_all(&self.records)?;
+        w.write_all(&self.string_block)?;
+
+        Ok(())
+    }
+
+    /// Header that will be written for the current contents.
+    pub const fn header(&self) -> DbcHeader {
+        DbcHeader {
+            record_count: self.record_count,
+            field_count: self.field_count,
+            record_size: self.record_size,
+            string_block_size: self.string_block.len() as u32,
+        }
+    }
+
+    /// Amount of fields in every record, as stated by the header.
+    pub const fn field_count(&self) -> u32 {
+        self.field_count
+    }
+
+    /// Size of every record in bytes.
+    pub const fn record_size(&self) -> u32 {
+        self.record_size
+    }
+
+    /// Amount of records in the file.
+    pub const fn len(&self) -> usize {
+        self.record_count as usize
+    }
+
+    /// Returns `true` if the file has no records.
+    pub const fn is_empty(&self) -> bool {
+        self.record_count == 0
+    }
+
+    /// Bytes of the record at `index`, or [`None`] if `index` is out of bounds.
+    pub fn record(&self, index: usize) -> Option<&[u8]> {
+        let range = self.record_range(index)?;
+
+        self.records.get(range)
+    }
+
+    /// Mutable bytes of the record at `index`, or [`None`] if `index` is out of bounds.
+    pub fn record_mut(&mut self, index: usize) -> Option<&mut [u8]> {
+        let range = self.record_range(index)?;
+
+        self.records.get_mut(range)
+    }
+
+    /// Iterator over the bytes of every record in order.
+    pub fn records(&self) -> impl ExactSizeIterator<Item = &[u8]> + '_ {
+        let size = self.record_size as usize;
+
+        (0..self.len()).map(move |i| &self.records[i * size..(i + 1) * size])
+    }
+
+    /// The record at `index` as little endian `u32` words, or [`None`] if `index` is out of bounds.
+    ///
+    /// Trailing bytes of records with a size that is not a multiple of 4 are skipped.
+    pub fn words(&self, index: usize) -> Option<impl ExactSizeIterator<Item = u32> + '_> {
+        Some(
+            self.record(index)?
+                .chunks_exact(4)
+                .map(|a| u32::from_le_bytes([a[0], a[1], a[2], a[3]])),
+        )
+    }
+
+    /// The little endian `u32` at word `word` of the record at `index`.
+    ///
+    /// Returns [`None`] if either is out of bounds.
+    pub fn word(&self, index: usize, word: usize) -> Option<u32> {
+        let start = word.checked_mul(4)?;
+        let a = self.record(index)?.get(start..start.checked_add(4)?)?;
+
+        Some(u32::from_le_bytes([a[0], a[1], a[2], a[3]]))
+    }
+
+    /// Adds `record` to the end of the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PushRecordError::EmptyRecords`] if [`RawDbc::record_size`] is 0, since the file could not be read back,
+    /// [`PushRecordError::RecordSize`] if the length of `record` is not [`RawDbc::record_size`],
+    /// and [`PushRecordError::TooManyRecords`] if the amount of records does not fit in the header.
+    pub fn push_record(&mut self, record: &[u8]) -> Result<(), PushRecordError> {
+        if self.record_size == 0 {
+            return Err(PushRecordError::EmptyRecords);
+        }
+
+        if record.len() != self.record_size as usize {
+            return Err(PushRecordError::RecordSize {
+                expected: self.record_size,
+                actual: record.len(),
+            });
+        }
+
+        self.record_count = self
+            .record_count
+            .checked_add(1)
+            .ok_or(PushRecordError::TooManyRecords)?;
+        self.records.extend_from_slice(record);
+
+        Ok(())
+    }
+
+    /// The string block containing all NUL terminated strings.
+    pub fn string_block(&self) -> &[u8] {
+        &self.string_block
+    }
+
+    /// Gets the string at `offset` in the string block.
+    ///
+    /// An offset of 0 is always the empty string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbcError::String`] if the string is not valid UTF-8.
+    pub fn string(&self, offset: u32) -> Result<&str, DbcError> {
+        crate::util::str_at(offset, &self.string_block)
+    }
+
+    /// Appends `s` to the string block and returns its offset.
+    ///
+    /// Empty strings always return 0 without changing the string block.
+    pub fn add_string(&mut self, s: &str) -> u32 {
+        if s.is_empty() {
+            return 0;
+        }
+
+        let offset = self.string_block.len() as u32;
+        self.string_block.extend_from_slice(s.as_bytes());
+        self.string_block.push(0);
+
+        offset
+    }
+
+    fn record_range(&self, index: usize) -> Option<core::ops::Range<usize>> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let size = self.record_size as usize;
+        let start = index.checked_mul(size)?;
+
+        Some(start..start + size)
+    }
+}
+
+#[cfg(all(test, feature = "vanilla"))]
+mod test {
+    use super::*;
+    use crate::fixtures::spell_icon_row;
+    use crate::vanilla_tables::spell_icon::SpellIcon;
+    use crate::DbcTable;
+
+    #[test]
+    fn raw_round_trip() {
+        let table = SpellIcon {
+            rows: vec![
+                spell_icon_row(1, "Interface\\Icons\\Temp"),
+                spell_icon_row(2, ""),
+            ],
+        };
+        let mut b = Vec::new();
+        table.write(&mut b).unwrap();
+
+        let raw = RawDbc::read(&mut b.as_slice()).unwrap();
+        assert_eq!(raw.len(), 2);
+        assert_eq!(raw.field_count(), SpellIcon::FIELD_COUNT as u32);
+        assert_eq!(raw.word(1, 0), Some(2));
+        assert_eq!(raw.word(2, 0), None);
+        assert_eq!(raw.words(0).unwrap().len(), 2);
+        assert_eq!(
+            raw.string(raw.word(0, 1).unwrap()).unwrap(),
+            "Interface\\Icons\\Temp"
+        );
+
+        let mut written = Vec::new();
+        raw.write(&mut written).unwrap();
+        assert_eq!(written, b);
+
+        let mut raw = RawDbc::new(raw.field_count(), raw.record_size());
+        let offset = raw.add_string("Interface\\Icons\\Temp");
+        raw.push_record(&[1, 0, 0, 0, offset as u8, 0, 0, 0])
+            .unwrap();
+        raw.push_record(&[2, 0, 0, 0, 0, 0, 0, 0]).unwrap();
+        assert_eq!(
+            raw.push_record(&[3, 0, 0, 0]),
+            Err(PushRecordError::RecordSize {
+                expected: 8,
+                actual: 4
+            })
+        );
+        assert_eq!(raw.len(), 2);
+
+        let mut written = Vec::new();
+        raw.write(&mut written).unwrap();
+        assert_eq!(SpellIcon::read(&mut written.as_slice()).unwrap(), table);
+    }
+
+    #[test]
+    fn empty_records() {
+        // found by the `raw` fuzz target, `len` and `records` took a very long time to iterate
+        let b = [
+            0x57, 0x44, 0x42, 0x43, 0x00, 0x00, 0x00, 0x57, 0x44, 0x42, 0x43, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x34, 0x04, 0x00, 0x00, 0x00, 0x43,
+            0x6f, 0x6e, 0x8e,
+        ];
+
+        assert!(matches!(
+            RawDbc::read(&mut b.as_slice()),
+            Err(DbcError::Malformed(MalformedError::EmptyRecords {
+                record_count: 0x5700_0000,
+            }))
+        ));
+
+        // files that can not be read back can not be created either
+        let mut raw = RawDbc::new(0, 0);
+        assert_eq!(raw.push_record(&[]), Err(PushRecordError::EmptyRecords));
+
+        let mut written = Vec::new();
+        raw.write(&mut written).unwrap();
+        assert!(RawDbc::read(&mut written.as_slice()).unwrap().is_empty());
+    }
+}
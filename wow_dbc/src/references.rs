@@ -0,0 +1,314 @@
+use crate::{DbcTable, Indexable, TableKey};
+use std::any::{Any, TypeId};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+/// Options for `DbcDatabase::check_references` ([`vanilla_tables::DbcDatabase::check_references`](crate::vanilla_tables::DbcDatabase::check_references)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ReferenceCheck {
+    /// Do not report foreign keys of 0, which the game uses for no reference. Defaults to `true`.
+    pub ignore_zero: bool,
+    /// Do not report foreign keys of -1, which some tables use for no reference. Defaults to `true`.
+    ///
+    /// This is the value of the field as it is stored in the file, so `0xFFFFFFFF` for unsigned fields is not -1.
+    pub ignore_minus_one: bool,
+}
+
+impl Default for ReferenceCheck {
+    fn default() -> Self {
+        Self {
+            ignore_zero: true,
+            ignore_minus_one: true,
+        }
+    }
+}
+
+/// A foreign key that references a row that does not exist.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BrokenReference {
+    /// Name of the DBC file with the foreign key.
+    pub table: &'static str,
+    /// Index of the row with the foreign key.
+    pub row: usize,
+    /// Primary key of the row with the foreign key, if the table has a primary key.
+    pub id: Option<i64>,
+    /// Name of the foreign key field.
+    pub field: &'static str,
+    /// Index of the element for arrays of foreign keys.
+    pub index: Option<usize>,
+    /// Name of the DBC file that the foreign key references.
+    pub target_table: &'static str,
+    /// The value of the foreign key as it is stored in the file.
+    pub key: i64,
+}
+
+impl Display for BrokenReference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}' row {}", self.table, self.row)?;
+        if let Some(id) = self.id {
+            write!(f, " (id {id})")?;
+        }
+
+        write!(f, " field '{}", self.field)?;
+        if let Some(index) = self.index {
+            write!(f, "[{index}]")?;
+        }
+
+        write!(
+            f,
+            "' references missing key {} in '{}'",
+            self.key, self.target_table
+        )
+    }
+}
+
+/// A row with a foreign key to another row.
+///
+/// Returned by `DbcDatabase::referrers_of` ([`vanilla_tables::DbcDatabase::referrers_of`](crate::vanilla_tables::DbcDatabase::referrers_of)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Referrer {
+    /// Name of the DBC file with the foreign key.
+    pub table: &'static str,
+    /// Index of the row with the foreign key.
+    pub row: usize,
+    /// Primary key of the row with the foreign key, if the table has a primary key.
+    pub id: Option<i64>,
+    /// Name of the foreign key field.
+    pub field: &'static str,
+    /// Index of the element for arrays of foreign keys.
+    pub index: Option<usize>,
+}
+
+impl Display for Referrer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}' row {}", self.table, self.row)?;
+        if let Some(id) = self.id {
+            write!(f, " (id {id})")?;
+        }
+
+        write!(f, " field '{}", self.field)?;
+        if let Some(index) = self.index {
+            write!(f, "[{index}]")?;
+        }
+
+        write!(f, "'")
+    }
+}
+
+/// Collects [`BrokenReference`]s, keeping a set of the primary keys of every target table.
+#[derive(Debug)]
+pub(crate) struct ReferenceChecker {
+    options: ReferenceCheck,
+    keys: HashMap<TypeId, Box<dyn Any>>,
+    broken: Vec<BrokenReference>,
+}
+
+impl ReferenceChecker {
+    pub(crate) fn new(options: ReferenceCheck) -> Self {
+        Self {
+            options,
+            keys: HashMap::new(),
+            broken: Vec::new(),
+        }
+    }
+
+    /// Checks that `key` is in `target`, with `raw` being the value in the file
+    /// and `index` the element of arrays of foreign keys.
+    ///
+    /// References to tables that could not be found, `target` being [`None`], are not checked.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn check<K: TableKey + 'static>(
+        &mut self,
+        target: Option<&K::Table>,
+        table: &'static str,
+        row: usize,
+        id: Option<i64>,
+        field: &'static str,
+        index: Option<usize>,
+        key: K,
+        raw: i64,
+    ) {
+        if raw == 0 && self.options.ignore_zero || raw == -1 && self.options.ignore_minus_one {
+            return;
+        }
+
+        let Some(target) = target else {
+            return;
+        };
+
+        let keys = self.keys.entry(TypeId::of::<K>()).or_insert_with(|| {
+            Box::new(
+                target
+                    .rows()
+                    .iter()
+                    .map(K::Table::primary_key)
+                    .collect::<HashSet<K>>(),
+            )
+        });
+
+        let exists = keys
+            .downcast_ref::<HashSet<K>>()
+            .is_none_or(|keys| keys.contains(&key));
+        if !exists {
+            self.broken.push(BrokenReference {
+                table,
+                row,
+                id,
+                field,
+                index,
+                target_table: <K::Table as DbcTable>::FILENAME,
+                key: raw,
+            });
+        }
+    }
+
+    pub(crate) fn finish(self) -> Vec<BrokenReference> {
+        self.broken
+    }
+}
+
+#[cfg(all(test, feature = "vanilla"))]
+mod test {
+    use super::*;
+    use crate::vanilla_tables::char_base_info::{CharBaseInfo, CharBaseInfoRow};
+    use crate::vanilla_tables::chr_classes::{ChrClasses, ChrClassesKey};
+    use crate::vanilla_tables::chr_races::ChrRacesKey;
+    use crate::vanilla_tables::ground_effect_doodad::{
+        GroundEffectDoodad, GroundEffectDoodadKey, GroundEffectDoodadRow,
+    };
+    use crate::vanilla_tables::ground_effect_texture::{
+        GroundEffectTexture, GroundEffectTextureKey, GroundEffectTextureRow,
+    };
+    use crate::vanilla_tables::terrain_type::TerrainTypeKey;
+    use crate::vanilla_tables::DbcDatabase;
+
+    #[test]
+    fn check_references() {
+        let dir = std::env::temp_dir().join(format!("wow_dbc_references_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let char_base_info = CharBaseInfo {
+            rows: [0, 1]
+                .into_iter()
+                .map(|class| CharBaseInfoRow {
+                    race: ChrRacesKey::new(1),
+                    class: ChrClassesKey::new(class),
+                })
+                .collect(),
+        };
+        let mut b = Vec::new();
+        char_base_info.write(&mut b).unwrap();
+        std::fs::write(dir.join("CharBaseInfo.dbc"), &b).unwrap();
+
+        let mut b = Vec::new();
+        ChrClasses::from_rows(Vec::new()).write(&mut b).unwrap();
+        std::fs::write(dir.join("ChrClasses.dbc"), &b).unwrap();
+
+        let db = DbcDatabase::open(&dir).unwrap();
+
+        // ChrRaces.dbc does not exist, so race is not checked
+        let broken = db.check_references(ReferenceCheck::default()).unwrap();
+        assert_eq!(
+            broken,
+            [BrokenReference {
+                table: "CharBaseInfo.dbc",
+                row: 1,
+                id: None,
+                field: "class",
+                index: None,
+                target_table: "ChrClasses.dbc",
+                key: 1,
+            }]
+        );
+
+        let broken = db
+            .check_references(ReferenceCheck {
+                ignore_zero: false,
+                ignore_minus_one: true,
+            })
+            .unwrap();
+        assert_eq!(broken.len(), 2);
+
+        assert_eq!(
+            db.referrers_of(ChrClassesKey::new(1)).unwrap(),
+            [Referrer {
+                table: "CharBaseInfo.dbc",
+                row: 1,
+                id: None,
+                field: "class",
+                index: None,
+            }]
+        );
+        assert_eq!(db.referrers_of(ChrClassesKey::new(0)).unwrap(), []);
+        assert_eq!(db.referrers_of(ChrRacesKey::new(1)).unwrap().len(), 2);
+        assert_eq!(db.referrers_of(ChrRacesKey::new(2)).unwrap(), []);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn array_references() {
+        let dir =
+            std::env::temp_dir().join(format!("wow_dbc_array_references_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let ground_effect_texture = GroundEffectTexture {
+            rows: vec![GroundEffectTextureRow {
+                id: GroundEffectTextureKey::new(1),
+                doodad: [1, 0, 2, 1],
+                density: 0,
+                terrain_type: TerrainTypeKey::new(0),
+            }],
+        };
+        let mut b = Vec::new();
+        ground_effect_texture.write(&mut b).unwrap();
+        std::fs::write(dir.join("GroundEffectTexture.dbc"), &b).unwrap();
+
+        let ground_effect_doodad = GroundEffectDoodad {
+            rows: vec![GroundEffectDoodadRow {
+                id: GroundEffectDoodadKey::new(1),
+                internal_id: 0,
+                doodad_path: String::new(),
+            }],
+        };
+        let mut b = Vec::new();
+        ground_effect_doodad.write(&mut b).unwrap();
+        std::fs::write(dir.join("GroundEffectDoodad.dbc"), &b).unwrap();
+
+        let db = DbcDatabase::open(&dir).unwrap();
+
+        let broken = db.check_references(ReferenceCheck::default()).unwrap();
+        assert_eq!(
+            broken,
+            [BrokenReference {
+                table: "GroundEffectTexture.dbc",
+                row: 0,
+                id: Some(1),
+                field: "doodad",
+                index: Some(2),
+                target_table: "GroundEffectDoodad.dbc",
+                key: 2,
+            }]
+        );
+        assert_eq!(
+            broken[0].to_string(),
+            "'GroundEffectTexture.dbc' row 0 (id 1) field 'doodad[2]' references missing key 2 in 'GroundEffectDoodad.dbc'"
+        );
+
+        let referrers = db.referrers_of(GroundEffectDoodadKey::new(1)).unwrap();
+        assert_eq!(
+            referrers
+                .iter()
+                .map(|a| (a.field, a.index))
+                .collect::<Vec<_>>(),
+            [("doodad", Some(0)), ("doodad", Some(3))]
+        );
+        assert_eq!(
+            referrers[1].to_string(),
+            "'GroundEffectTexture.dbc' row 0 (id 1) field 'doodad[3]'"
+        );
+        assert_eq!(db.referrers_of(GroundEffectDoodadKey::new(3)).unwrap(), []);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,182 @@
+use crate::header::{fill_bytes, read_bytes, read_table_header, HEADER_SIZE};
+use crate::{DbcError, DbcTable};
+use std::io::{Read, Seek, SeekFrom};
+use std::marker::PhantomData;
+
+/// Iterator that reads rows one at a time. Created by [`DbcTable::iter_rows`].
+///
+/// Only the string block and a single record are kept in memory,
+/// so large tables can be scanned without reading the whole file.
+/// A record that is cut off returns [`MalformedError::Truncated`](crate::MalformedError::Truncated), the same as [`DbcTable::read`].
+///
+/// ```rust,ignore
+/// let file = std::io::BufReader::new(std::fs::File::open("Spell.dbc")?);
+///
+/// for row in Spell::iter_rows(file)? {
+///     let row = row?;
+///     println!("{}", row.name.en_gb);
+/// }
+/// ```
+pub struct RowIter<T: DbcTable, R: Read + Seek> {
+    reader: R,
+    string_block: Vec<u8>,
+    record: Vec<u8>,
+    index: usize,
+    record_count: usize,
+    table: PhantomData<fn() -> T>,
+}
+
+impl<T: DbcTable, R: Read + Seek> RowIter<T, R> {
+    /// Reads the header and the string block, and seeks back to the first record.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same header errors as [`DbcTable::read`], and the same errors as [`Read::read_exact`] and [`Seek::seek`].
+    pub fn new(mut reader: R) -> Result<Self, DbcError> {
+        let start = reader.stream_position()?;
+
+        let header = read_table_header::<T>(&mut reader)?;
+
+        let records_size = header.records_size()?;
+        reader.seek(SeekFrom::Current(records_size as i64))?;
+        let string_block = read_bytes(&mut reader, header.string_block_size as usize)?;
+
+        reader.seek(SeekFrom::Start(start + HEADER_SIZE as u64))?;
+
+        Ok(Self {
+            reader,
+            string_block,
+            record: vec![0_u8; T::ROW_SIZE],
+            index: 0,
+            record_count: header.record_count as usize,
+            table: PhantomData,
+        })
+    }
+
+    /// The string block of the file.
+    pub fn string_block(&self) -> &[u8] {
+        &self.string_block
+    }
+
+    /// Returns the underlying reader, positioned after the last row that was read.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    fn read_row(&mut self) -> Result<T::Row, DbcError> {
+        fill_bytes(&mut self.reader, &mut self.record)?;
+
+        let row = T::read_row_ref(&self.record, &self.string_block)
+            .map_err(|e| e.with_row(self.index))?;
+
+        Ok(row.into())
+    }
+}
+
+impl<T: DbcTable, R: Read + Seek> Iterator for RowIter<T, R> {
+    type Item = Result<T::Row, DbcError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.record_count {
+            return None;
+        }
+
+        let row = self.read_row();
+        self.index += 1;
+
+        // the reader can not be trusted to be at the next record after an error
+        if row.is_err() {
+            self.index = self.record_count;
+        }
+
+        Some(row)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.record_count - self.index;
+
+        (0, Some(remaining))
+    }
+}
+
+impl<T: DbcTable, R: Read + Seek> std::iter::FusedIterator for RowIter<T, R> {}
+
+#[cfg(all(test, feature = "vanilla"))]
+mod test {
+    use crate::fixtures::spell_icon_row;
+    use crate::header::HEADER_SIZE;
+    use crate::vanilla_tables::character_create_cameras::{
+        CharacterCreateCameras, CharacterCreateCamerasKey, CharacterCreateCamerasRow,
+    };
+    use crate::vanilla_tables::spell_icon::SpellIcon;
+    use crate::{DbcError, DbcTable, MalformedError};
+    use std::io::{Cursor, Seek, Write};
+
+    #[test]
+    fn stream_rows() {
+        let table = SpellIcon {
+            rows: (1..=3)
+                .map(|id| spell_icon_row(id, &format!("Interface\\Icons\\{id}")))
+                .collect(),
+        };
+
+        // rows are read from the current position
+        let mut b = Cursor::new(Vec::new());
+        b.write_all(b"prefix").unwrap();
+        table.write(&mut b).unwrap();
+        b.seek(std::io::SeekFrom::Start(6)).unwrap();
+
+        let rows = SpellIcon::iter_rows(&mut b)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(rows, table.rows);
+
+        // the string block is read up front
+        let mut b = Vec::new();
+        table.write(&mut b).unwrap();
+        b.pop();
+        assert!(matches!(
+            SpellIcon::iter_rows(Cursor::new(b)),
+            Err(DbcError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn truncated_records() {
+        let table = CharacterCreateCameras {
+            rows: (1..=2)
+                .map(|id| CharacterCreateCamerasRow {
+                    id: CharacterCreateCamerasKey::new(id),
+                    unknown: [false; 2],
+                    unknown_2: [0.0; 3],
+                })
+                .collect(),
+        };
+
+        // without a string block the second record is cut off in the middle
+        let mut b = Vec::new();
+        table.write(&mut b).unwrap();
+        b[16..20].copy_from_slice(&0_u32.to_le_bytes());
+        b.truncate(HEADER_SIZE + CharacterCreateCameras::ROW_SIZE + 10);
+
+        let rows = CharacterCreateCameras::iter_rows(Cursor::new(&b))
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].as_ref().unwrap(), &table.rows[0]);
+        assert!(matches!(
+            rows[1],
+            Err(DbcError::Malformed(MalformedError::Truncated {
+                expected: 24,
+                actual: 10
+            }))
+        ));
+
+        // same error as reading the whole table
+        assert!(matches!(
+            CharacterCreateCameras::read(&mut b.as_slice()),
+            Err(DbcError::Malformed(MalformedError::Truncated { .. }))
+        ));
+    }
+}
@@ -1,5 +1,18 @@
+use crate::prelude::*;
 use crate::util::StringCache;
 
+/// Version of the game client that the DBC files are from.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Expansion {
+    /// 1.12 client data.
+    Vanilla,
+    /// 2.4.3.8606 client data.
+    Tbc,
+    /// 3.3.5.12340 client data.
+    Wrath,
+}
+
 /// DBCs from the English version of the game will only have English version strings, while other localizations will have other languages.
 ///
 /// You are most likely interested in, [`LocalizedString::en_gb`], the English version.
@@ -46,50 +59,6 @@ pub struct ExtendedLocalizedString {
     pub flags: u32,
 }
 
-#[cfg(any(feature = "tbc", feature = "wrath"))]
-impl ExtendedLocalizedString {
-    #[allow(clippy::too_many_arguments)]
-    pub(crate) const fn new(
-        en_gb: String,
-        ko_kr: String,
-        fr_fr: String,
-        de_de: String,
-        en_cn: String,
-        en_tw: String,
-        es_es: String,
-        es_mx: String,
-        ru_ru: String,
-        ja_jp: String,
-        pt_pt: String,
-        it_it: String,
-        unknown_12: String,
-        unknown_13: String,
-        unknown_14: String,
-        unknown_15: String,
-        flags: u32,
-    ) -> Self {
-        Self {
-            en_gb,
-            ko_kr,
-            fr_fr,
-            de_de,
-            en_cn,
-            en_tw,
-            es_es,
-            es_mx,
-            ru_ru,
-            ja_jp,
-            pt_pt,
-            it_it,
-            unknown_12,
-            unknown_13,
-            unknown_14,
-            unknown_15,
-            flags,
-        }
-    }
-}
-
 #[cfg(any(feature = "tbc", feature = "wrath"))]
 impl WritableString for ExtendedLocalizedString {
     fn strings(&self) -> Box<[&str]> {
@@ -118,6 +87,85 @@ impl WritableString for ExtendedLocalizedString {
     }
 }
 
+#[cfg(any(feature = "tbc", feature = "wrath"))]
+impl From<&ExtendedLocalizedString> for crate::Value {
+    fn from(v: &ExtendedLocalizedString) -> Self {
+        crate::Value::LocalizedString {
+            strings: v.strings().iter().map(|a| a.to_string()).collect(),
+            flags: v.flags,
+        }
+    }
+}
+
+/// Borrowed version of [`ExtendedLocalizedString`] that points directly into the string block.
+///
+/// Created through [`crate::DbcTableRef`] and converted into [`ExtendedLocalizedString`] with [`From`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg(any(feature = "tbc", feature = "wrath"))]
+pub struct ExtendedLocalizedStringRef<'a> {
+    /// See [`ExtendedLocalizedString::en_gb`].
+    pub en_gb: &'a str,
+    /// See [`ExtendedLocalizedString::ko_kr`].
+    pub ko_kr: &'a str,
+    /// See [`ExtendedLocalizedString::fr_fr`].
+    pub fr_fr: &'a str,
+    /// See [`ExtendedLocalizedString::de_de`].
+    pub de_de: &'a str,
+    /// See [`ExtendedLocalizedString::en_cn`].
+    pub en_cn: &'a str,
+    /// See [`ExtendedLocalizedString::en_tw`].
+    pub en_tw: &'a str,
+    /// See [`ExtendedLocalizedString::es_es`].
+    pub es_es: &'a str,
+    /// See [`ExtendedLocalizedString::es_mx`].
+    pub es_mx: &'a str,
+    /// See [`ExtendedLocalizedString::ru_ru`].
+    pub ru_ru: &'a str,
+    /// See [`ExtendedLocalizedString::ja_jp`].
+    pub ja_jp: &'a str,
+    /// See [`ExtendedLocalizedString::pt_pt`].
+    pub pt_pt: &'a str,
+    /// See [`ExtendedLocalizedString::it_it`].
+    pub it_it: &'a str,
+    /// See [`ExtendedLocalizedString::unknown_12`].
+    pub unknown_12: &'a str,
+    /// See [`ExtendedLocalizedString::unknown_13`].
+    pub unknown_13: &'a str,
+    /// See [`ExtendedLocalizedString::unknown_14`].
+    pub unknown_14: &'a str,
+    /// See [`ExtendedLocalizedString::unknown_15`].
+    pub unknown_15: &'a str,
+
+    /// Unknown flags.
+    pub flags: u32,
+}
+
+#[cfg(any(feature = "tbc", feature = "wrath"))]
+impl From<ExtendedLocalizedStringRef<'_>> for ExtendedLocalizedString {
+    fn from(v: ExtendedLocalizedStringRef<'_>) -> Self {
+        Self {
+            en_gb: v.en_gb.to_string(),
+            ko_kr: v.ko_kr.to_string(),
+            fr_fr: v.fr_fr.to_string(),
+            de_de: v.de_de.to_string(),
+            en_cn: v.en_cn.to_string(),
+            en_tw: v.en_tw.to_string(),
+            es_es: v.es_es.to_string(),
+            es_mx: v.es_mx.to_string(),
+            ru_ru: v.ru_ru.to_string(),
+            ja_jp: v.ja_jp.to_string(),
+            pt_pt: v.pt_pt.to_string(),
+            it_it: v.it_it.to_string(),
+            unknown_12: v.unknown_12.to_string(),
+            unknown_13: v.unknown_13.to_string(),
+            unknown_14: v.unknown_14.to_string(),
+            unknown_15: v.unknown_15.to_string(),
+            flags: v.flags,
+        }
+    }
+}
+
 /// DBCs from the English version of the game will only have English version strings, while other localizations will have other languages.
 ///
 /// You are most likely interested in, [`LocalizedString::en_gb`], the English version.
@@ -145,34 +193,6 @@ pub struct LocalizedString {
     pub flags: u32,
 }
 
-#[cfg(feature = "vanilla")]
-impl LocalizedString {
-    #[allow(clippy::too_many_arguments)]
-    pub(crate) const fn new(
-        en_gb: String,
-        ko_kr: String,
-        fr_fr: String,
-        de_de: String,
-        en_cn: String,
-        en_tw: String,
-        es_es: String,
-        es_mx: String,
-        flags: u32,
-    ) -> Self {
-        Self {
-            en_gb,
-            ko_kr,
-            fr_fr,
-            de_de,
-            en_cn,
-            en_tw,
-            es_es,
-            es_mx,
-            flags,
-        }
-    }
-}
-
 #[cfg(feature = "vanilla")]
 impl WritableString for LocalizedString {
     fn strings(&self) -> Box<[&str]> {
@@ -193,6 +213,61 @@ impl WritableString for LocalizedString {
     }
 }
 
+#[cfg(feature = "vanilla")]
+impl From<&LocalizedString> for crate::Value {
+    fn from(v: &LocalizedString) -> Self {
+        crate::Value::LocalizedString {
+            strings: v.strings().iter().map(|a| a.to_string()).collect(),
+            flags: v.flags,
+        }
+    }
+}
+
+/// Borrowed version of [`LocalizedString`] that points directly into the string block.
+///
+/// Created through [`crate::DbcTableRef`] and converted into [`LocalizedString`] with [`From`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg(feature = "vanilla")]
+pub struct LocalizedStringRef<'a> {
+    /// See [`LocalizedString::en_gb`].
+    pub en_gb: &'a str,
+    /// See [`LocalizedString::ko_kr`].
+    pub ko_kr: &'a str,
+    /// See [`LocalizedString::fr_fr`].
+    pub fr_fr: &'a str,
+    /// See [`LocalizedString::de_de`].
+    pub de_de: &'a str,
+    /// See [`LocalizedString::en_cn`].
+    pub en_cn: &'a str,
+    /// See [`LocalizedString::en_tw`].
+    pub en_tw: &'a str,
+    /// See [`LocalizedString::es_es`].
+    pub es_es: &'a str,
+    /// See [`LocalizedString::es_mx`].
+    pub es_mx: &'a str,
+
+    /// Unknown flags.
+    pub flags: u32,
+}
+
+#[cfg(feature = "vanilla")]
+impl From<LocalizedStringRef<'_>> for LocalizedString {
+    fn from(v: LocalizedStringRef<'_>) -> Self {
+        Self {
+            en_gb: v.en_gb.to_string(),
+            ko_kr: v.ko_kr.to_string(),
+            fr_fr: v.fr_fr.to_string(),
+            de_de: v.de_de.to_string(),
+            en_cn: v.en_cn.to_string(),
+            en_tw: v.en_tw.to_string(),
+            es_es: v.es_es.to_string(),
+            es_mx: v.es_mx.to_string(),
+            flags: v.flags,
+        }
+    }
+}
+
 pub(crate) trait WritableString {
 
     /// Returns a slice of strings, represented by the implementing type.
@@ -1,209 +1,490 @@
-use std::collections::HashMap;
+use crate::prelude::*;
+use crate::{Codepage, Coercion, StringEncoding};
 #[cfg(any(feature = "tbc", feature = "wrath"))]
-use crate::tys::ExtendedLocalizedString;
+use crate::tys::ExtendedLocalizedStringRef;
 
-use std::io::Read;
+use crate::io::Read;
 
-pub fn read_u8_le(b: &mut &[u8]) -> Result<u8, std::io::Error> {
+/// Map used internally, a `HashMap` with `std` and a `BTreeMap` without since `alloc` does not have hash maps.
+#[cfg(feature = "std")]
+pub(crate) type Map<K, V> = std::collections::HashMap<K, V>;
+#[cfg(not(feature = "std"))]
+pub(crate) type Map<K, V> = alloc::collections::BTreeMap<K, V>;
+
+/// Set used internally, see [`Map`].
+#[cfg(feature = "std")]
+pub(crate) type Set<T> = std::collections::HashSet<T>;
+#[cfg(not(feature = "std"))]
+pub(crate) type Set<T> = alloc::collections::BTreeSet<T>;
+
+pub fn read_u8_le(b: &mut &[u8]) -> Result<u8, crate::io::Error> {
     let mut buf = [0_u8; 1];
     b.read_exact(&mut buf)?;
 
     Ok(buf[0])
 }
 
-pub fn read_u32_le(b: &mut &[u8]) -> Result<u32, std::io::Error> {
+pub fn read_u32_le(b: &mut &[u8]) -> Result<u32, crate::io::Error> {
     let mut buf = [0_u8; 4];
     b.read_exact(&mut buf)?;
 
     Ok(u32::from_le_bytes(buf))
 }
 
-pub fn read_i8_le(b: &mut &[u8]) -> Result<i8, std::io::Error> {
+pub fn read_u16_le(b: &mut &[u8]) -> Result<u16, crate::io::Error> {
+    let mut buf = [0_u8; 2];
+    b.read_exact(&mut buf)?;
+
+    Ok(u16::from_le_bytes(buf))
+}
+
+pub fn read_i8_le(b: &mut &[u8]) -> Result<i8, crate::io::Error> {
     let mut buf = [0_u8; 1];
     b.read_exact(&mut buf)?;
 
     Ok(i8::from_le_bytes(buf))
 }
 
-pub fn read_i32_le(b: &mut &[u8]) -> Result<i32, std::io::Error> {
+pub fn read_i16_le(b: &mut &[u8]) -> Result<i16, crate::io::Error> {
+    let mut buf = [0_u8; 2];
+    b.read_exact(&mut buf)?;
+
+    Ok(i16::from_le_bytes(buf))
+}
+
+pub fn read_i32_le(b: &mut &[u8]) -> Result<i32, crate::io::Error> {
     let mut buf = [0_u8; 4];
     b.read_exact(&mut buf)?;
 
     Ok(i32::from_le_bytes(buf))
 }
 
-pub fn read_f32_le(b: &mut &[u8]) -> Result<f32, std::io::Error> {
+pub fn read_f32_le(b: &mut &[u8]) -> Result<f32, crate::io::Error> {
     let mut buf = [0_u8; 4];
     b.read_exact(&mut buf)?;
 
     Ok(f32::from_le_bytes(buf))
 }
 
-pub fn get_string_as_vec(b: &mut &[u8], string_block: &[u8]) -> Result<Vec<u8>, std::io::Error> {
-    let start = read_u32_le(b)? as usize;
-
-    if start == 0 {
-        return Ok(Vec::new());
+/// Wraps errors from reading `field` in the location of the field.
+pub fn field_error<T: crate::DbcTable, E: Into<crate::DbcError>>(
+    field: &'static str,
+    field_offset: usize,
+) -> impl FnOnce(E) -> crate::DbcError {
+    move |e| {
+        crate::DbcError::Field(Box::new(crate::FieldError {
+            table: T::FILENAME.into(),
+            row: None,
+            field: field.into(),
+            field_offset,
+            record_size: T::ROW_SIZE,
+            error: e.into(),
+        }))
     }
-
-    let end = {
-        let mut i = 0;
-        while string_block[start + i] != 0 {
-            i += 1;
-        }
-        start + i
-    };
-
-    Ok(string_block[start..end].to_vec())
 }
 
-#[cfg(feature = "vanilla")]
-pub fn read_localized_string(
-    chunk: &mut &[u8],
-    string_block: &[u8],
-) -> Result<crate::tys::LocalizedString, crate::DbcError> {
-    let en_gb = get_string_as_vec(chunk, string_block)?;
-    let en_gb = String::from_utf8(en_gb)?;
+/// Tables with fewer rows than this are always read on the current thread.
+#[cfg(feature = "rayon")]
+const PARALLEL_MIN_ROWS: usize = 4096;
 
-    let ko_kr = get_string_as_vec(chunk, string_block)?;
-    let ko_kr = String::from_utf8(ko_kr)?;
+/// Rows read by each task when reading in parallel.
+#[cfg(feature = "rayon")]
+const PARALLEL_CHUNK_ROWS: usize = 1024;
 
-    let fr_fr = get_string_as_vec(chunk, string_block)?;
-    let fr_fr = String::from_utf8(fr_fr)?;
+/// Reads every record in `records` as a row of `T`, for [`DbcTable::read`](crate::DbcTable::read).
+///
+/// With the `rayon` feature large tables are read in chunks in parallel.
+/// The rows are in the same order and the error is for the same row as when reading on a single thread.
+pub fn read_rows<T: crate::DbcTable>(
+    records: &[u8],
+    string_block: &[u8],
+) -> Result<Vec<T::Row>, crate::DbcError>
+where
+    T::Row: Send,
+{
+    #[cfg(feature = "rayon")]
+    if records.len() / T::ROW_SIZE >= PARALLEL_MIN_ROWS {
+        use rayon::prelude::*;
+
+        // every chunk stops at its first error, so the first error of the first failing chunk is the first error overall
+        let chunks = records
+            .par_chunks(PARALLEL_CHUNK_ROWS * T::ROW_SIZE)
+            .enumerate()
+            .map(|(i, chunk)| read_chunk::<T>(chunk, string_block, i * PARALLEL_CHUNK_ROWS))
+            .collect::<Vec<_>>();
+
+        let mut rows = Vec::with_capacity(records.len() / T::ROW_SIZE);
+        for chunk in chunks {
+            rows.extend(chunk?);
+        }
 
-    let de_de = get_string_as_vec(chunk, string_block)?;
-    let de_de = String::from_utf8(de_de)?;
+        return Ok(rows);
+    }
 
-    let en_cn = get_string_as_vec(chunk, string_block)?;
-    let en_cn = String::from_utf8(en_cn)?;
+    read_chunk::<T>(records, string_block, 0)
+}
 
-    let en_tw = get_string_as_vec(chunk, string_block)?;
-    let en_tw = String::from_utf8(en_tw)?;
+/// Reads `records` as rows of `T`, where the first record is row `first_row` of the table.
+fn read_chunk<T: crate::DbcTable>(
+    records: &[u8],
+    string_block: &[u8],
+    first_row: usize,
+) -> Result<Vec<T::Row>, crate::DbcError> {
+    // the capacity is based on the bytes that were actually read, not the header
+    let mut rows = Vec::with_capacity(records.len() / T::ROW_SIZE);
+
+    for (i, record) in records.chunks(T::ROW_SIZE).enumerate() {
+        rows.push(
+            T::read_row_ref(record, string_block)
+                .map_err(|e| e.with_row(first_row + i))?
+                .into(),
+        );
+    }
 
-    let es_es = get_string_as_vec(chunk, string_block)?;
-    let es_es = String::from_utf8(es_es)?;
+    Ok(rows)
+}
 
-    let es_mx = get_string_as_vec(chunk, string_block)?;
-    let es_mx = String::from_utf8(es_mx)?;
+/// Converts `raw` into the enum, or records the raw value in a [`Coercion`] and uses the default value if `coercions` is set.
+#[cfg(any(feature = "vanilla", feature = "tbc", feature = "wrath"))]
+pub fn read_enum<T, E, R>(
+    raw: R,
+    field: &'static str,
+    field_offset: usize,
+    coercions: &mut Option<&mut Vec<Coercion>>,
+) -> Result<E, crate::DbcError>
+where
+    T: crate::DbcTable,
+    E: TryFrom<R, Error = wow_world_base::EnumError> + Default,
+    R: Copy + Into<i64>,
+{
+    match E::try_from(raw) {
+        Ok(e) => Ok(e),
+        Err(e) => match coercions {
+            Some(coercions) => {
+                coercions.push(Coercion::new(
+                    field,
+                    field_offset,
+                    core::mem::size_of::<R>(),
+                    raw.into(),
+                    e.name,
+                ));
+                Ok(E::default())
+            }
+            None => Err(field_error::<T, _>(field, field_offset)(e)),
+        },
+    }
+}
 
-    let flags = read_u32_le(chunk)?;
+/// Converts `raw` into a bool, and records a [`Coercion`] for values other than 0 and 1 if `coercions` is set.
+pub fn read_bool<R: Copy + Into<i64>>(
+    raw: R,
+    field: &'static str,
+    field_offset: usize,
+    coercions: &mut Option<&mut Vec<Coercion>>,
+) -> bool {
+    let raw = raw.into();
+
+    if let Some(coercions) = coercions {
+        if raw != 0 && raw != 1 {
+            coercions.push(Coercion::new(
+                field,
+                field_offset,
+                core::mem::size_of::<R>(),
+                raw,
+                "bool",
+            ));
+        }
+    }
 
-    Ok(crate::tys::LocalizedString::new(
-        en_gb, ko_kr, fr_fr, de_de, en_cn, en_tw, es_es, es_mx, flags,
-    ))
+    raw != 0
 }
 
-#[cfg(any(feature = "tbc", feature = "wrath"))]
-pub fn read_extended_localized_string(
-    chunk: &mut &[u8],
-    string_block: &[u8],
-) -> Result<ExtendedLocalizedString, crate::DbcError> {
-    let en_gb = get_string_as_vec(chunk, string_block)?;
-    let en_gb = String::from_utf8(en_gb)?;
+/// Gets the NUL terminated string starting at `offset` in the string block.
+pub fn str_at(offset: u32, string_block: &[u8]) -> Result<&str, crate::DbcError> {
+    let s = bytes_at(offset, string_block)?;
+    match core::str::from_utf8(s) {
+        Ok(s) => Ok(s),
+        // go through `String` in order to keep returning the same error as the owned strings
+        Err(_) => Err(String::from_utf8(s.to_vec()).unwrap_err().into()),
+    }
+}
 
-    let ko_kr = get_string_as_vec(chunk, string_block)?;
-    let ko_kr = String::from_utf8(ko_kr)?;
+/// Gets the bytes of the NUL terminated string starting at `offset` in the string block, without the NUL.
+pub fn bytes_at(offset: u32, string_block: &[u8]) -> Result<&[u8], crate::MalformedError> {
+    if offset == 0 {
+        return Ok(&[]);
+    }
 
-    let fr_fr = get_string_as_vec(chunk, string_block)?;
-    let fr_fr = String::from_utf8(fr_fr)?;
+    let s = string_block.get(offset as usize..).ok_or(
+        crate::MalformedError::StringOffsetOutOfBounds {
+            offset,
+            string_block_size: string_block.len() as u32,
+        },
+    )?;
+    let end = s
+        .iter()
+        .position(|&a| a == 0)
+        .ok_or(crate::MalformedError::UnterminatedString { offset })?;
+
+    Ok(&s[..end])
+}
 
-    let de_de = get_string_as_vec(chunk, string_block)?;
-    let de_de = String::from_utf8(de_de)?;
+/// Gets the first `N` bytes of `record`, which is the only bounds check needed for reading a row.
+pub fn record<const N: usize>(record: &[u8]) -> Result<Record<'_, N>, crate::MalformedError> {
+    record
+        .first_chunk()
+        .map(Record)
+        .ok_or(crate::MalformedError::Truncated {
+            expected: N as u64,
+            actual: record.len() as u64,
+        })
+}
 
-    let en_cn = get_string_as_vec(chunk, string_block)?;
-    let en_cn = String::from_utf8(en_cn)?;
+/// The `N` bytes of a row, returned from [`record`].
+///
+/// Fields are read at offsets that are const generics in the generated code,
+/// and every field is asserted to be inside of the record when compiling.
+/// The ranges that are read are therefore constants that never have to be checked at runtime.
+#[derive(Debug, Clone, Copy)]
+pub struct Record<'a, const N: usize>(&'a [u8; N]);
 
-    let en_tw = get_string_as_vec(chunk, string_block)?;
-    let en_tw = String::from_utf8(en_tw)?;
+impl<'a, const N: usize> Record<'a, N> {
+    fn field_bytes<const OFFSET: usize, const S: usize>(self) -> [u8; S] {
+        const { assert!(OFFSET + S <= N, "field is outside of the record") };
 
-    let es_es = get_string_as_vec(chunk, string_block)?;
-    let es_es = String::from_utf8(es_es)?;
+        let mut b = [0_u8; S];
+        b.copy_from_slice(&self.0[OFFSET..OFFSET + S]);
 
-    let es_mx = get_string_as_vec(chunk, string_block)?;
-    let es_mx = String::from_utf8(es_mx)?;
+        b
+    }
 
-    let ru_ru = get_string_as_vec(chunk, string_block)?;
-    let ru_ru = String::from_utf8(ru_ru)?;
+    /// The `S` four byte values starting at `OFFSET`.
+    fn words<const OFFSET: usize, const S: usize>(self) -> [[u8; 4]; S] {
+        const { assert!(OFFSET + S * 4 <= N, "field is outside of the record") };
 
-    let ja_jp = get_string_as_vec(chunk, string_block)?;
-    let ja_jp = String::from_utf8(ja_jp)?;
+        let mut words = [[0_u8; 4]; S];
+        for (word, b) in words
+            .iter_mut()
+            .zip(self.0[OFFSET..OFFSET + S * 4].chunks_exact(4))
+        {
+            word.copy_from_slice(b);
+        }
 
-    let pt_pt = get_string_as_vec(chunk, string_block)?;
-    let pt_pt = String::from_utf8(pt_pt)?;
+        words
+    }
 
-    let it_it = get_string_as_vec(chunk, string_block)?;
-    let it_it = String::from_utf8(it_it)?;
+    pub fn u8_at<const OFFSET: usize>(self) -> u8 {
+        u8::from_le_bytes(self.field_bytes::<OFFSET, 1>())
+    }
 
-    let unknown_12 = get_string_as_vec(chunk, string_block)?;
-    let unknown_12 = String::from_utf8(unknown_12)?;
+    pub fn u16_at<const OFFSET: usize>(self) -> u16 {
+        u16::from_le_bytes(self.field_bytes::<OFFSET, 2>())
+    }
 
-    let unknown_13 = get_string_as_vec(chunk, string_block)?;
-    let unknown_13 = String::from_utf8(unknown_13)?;
+    pub fn u32_at<const OFFSET: usize>(self) -> u32 {
+        u32::from_le_bytes(self.field_bytes::<OFFSET, 4>())
+    }
 
-    let unknown_14 = get_string_as_vec(chunk, string_block)?;
-    let unknown_14 = String::from_utf8(unknown_14)?;
+    pub fn i8_at<const OFFSET: usize>(self) -> i8 {
+        i8::from_le_bytes(self.field_bytes::<OFFSET, 1>())
+    }
 
-    let unknown_15 = get_string_as_vec(chunk, string_block)?;
-    let unknown_15 = String::from_utf8(unknown_15)?;
+    pub fn i16_at<const OFFSET: usize>(self) -> i16 {
+        i16::from_le_bytes(self.field_bytes::<OFFSET, 2>())
+    }
 
-    let flags = read_u32_le(chunk)?;
+    pub fn i32_at<const OFFSET: usize>(self) -> i32 {
+        i32::from_le_bytes(self.field_bytes::<OFFSET, 4>())
+    }
 
-    Ok(ExtendedLocalizedString::new(
-        en_gb, ko_kr, fr_fr, de_de, en_cn, en_tw, es_es, es_mx, ru_ru, ja_jp, pt_pt, it_it,
-        unknown_12, unknown_13, unknown_14, unknown_15, flags,
-    ))
-}
+    pub fn f32_at<const OFFSET: usize>(self) -> f32 {
+        f32::from_le_bytes(self.field_bytes::<OFFSET, 4>())
+    }
 
-pub fn read_array_f32<const N: usize>(chunk: &mut &[u8]) -> Result<[f32; N], std::io::Error> {
-    let mut arr = [f32::default(); N];
-    for i in arr.iter_mut() {
-        *i = read_f32_le(chunk)?;
+    /// Gets the string that the string reference at `OFFSET` points to.
+    pub fn str_ref_at<const OFFSET: usize>(
+        self,
+        string_block: &[u8],
+    ) -> Result<&str, crate::DbcError> {
+        str_at(self.u32_at::<OFFSET>(), string_block)
     }
 
-    Ok(arr)
-}
+    #[cfg(feature = "vanilla")]
+    pub fn localized_string_at<'b, const OFFSET: usize>(
+        self,
+        string_block: &'b [u8],
+    ) -> Result<crate::tys::LocalizedStringRef<'b>, crate::DbcError> {
+        let [en_gb, ko_kr, fr_fr, de_de, en_cn, en_tw, es_es, es_mx, flags] =
+            self.words::<OFFSET, 9>().map(u32::from_le_bytes);
+        let s = |offset: u32| str_at(offset, string_block);
+
+        Ok(crate::tys::LocalizedStringRef {
+            en_gb: s(en_gb)?,
+            ko_kr: s(ko_kr)?,
+            fr_fr: s(fr_fr)?,
+            de_de: s(de_de)?,
+            en_cn: s(en_cn)?,
+            en_tw: s(en_tw)?,
+            es_es: s(es_es)?,
+            es_mx: s(es_mx)?,
+            flags,
+        })
+    }
 
-pub fn read_array_u32<const N: usize>(chunk: &mut &[u8]) -> Result<[u32; N], std::io::Error> {
-    let mut arr = [u32::default(); N];
-    for i in arr.iter_mut() {
-        *i = read_u32_le(chunk)?;
+    #[cfg(any(feature = "tbc", feature = "wrath"))]
+    pub fn extended_localized_string_at<'b, const OFFSET: usize>(
+        self,
+        string_block: &'b [u8],
+    ) -> Result<ExtendedLocalizedStringRef<'b>, crate::DbcError> {
+        let [en_gb, ko_kr, fr_fr, de_de, en_cn, en_tw, es_es, es_mx, ru_ru, ja_jp, pt_pt, it_it, unknown_12, unknown_13, unknown_14, unknown_15, flags] =
+            self.words::<OFFSET, 17>().map(u32::from_le_bytes);
+        let s = |offset: u32| str_at(offset, string_block);
+
+        Ok(ExtendedLocalizedStringRef {
+            en_gb: s(en_gb)?,
+            ko_kr: s(ko_kr)?,
+            fr_fr: s(fr_fr)?,
+            de_de: s(de_de)?,
+            en_cn: s(en_cn)?,
+            en_tw: s(en_tw)?,
+            es_es: s(es_es)?,
+            es_mx: s(es_mx)?,
+            ru_ru: s(ru_ru)?,
+            ja_jp: s(ja_jp)?,
+            pt_pt: s(pt_pt)?,
+            it_it: s(it_it)?,
+            unknown_12: s(unknown_12)?,
+            unknown_13: s(unknown_13)?,
+            unknown_14: s(unknown_14)?,
+            unknown_15: s(unknown_15)?,
+            flags,
+        })
     }
 
-    Ok(arr)
-}
+    pub fn array_f32_at<const OFFSET: usize, const S: usize>(self) -> [f32; S] {
+        self.words::<OFFSET, S>().map(f32::from_le_bytes)
+    }
 
-pub fn read_array_i32<const N: usize>(chunk: &mut &[u8]) -> Result<[i32; N], std::io::Error> {
-    let mut arr = [i32::default(); N];
-    for i in arr.iter_mut() {
-        *i = read_i32_le(chunk)?;
+    pub fn array_u32_at<const OFFSET: usize, const S: usize>(self) -> [u32; S] {
+        self.words::<OFFSET, S>().map(u32::from_le_bytes)
     }
 
-    Ok(arr)
+    pub fn array_i32_at<const OFFSET: usize, const S: usize>(self) -> [i32; S] {
+        self.words::<OFFSET, S>().map(i32::from_le_bytes)
+    }
 }
 
 /// This struct implements a string cache for writing the string block of a DBC file.
 ///
 /// It maintains an in-memory buffer of the final string block and a mapping of strings to their offset in the buffer.
+/// Passed to [`DbcTable::write_with`](crate::DbcTable::write_with).
+#[derive(Debug, Clone)]
 pub struct StringCache {
-    /// Mapping of already written strings to their offsets in the buffer.
-    offsets: HashMap<String, u32>,
+    /// Mapping of already written strings, as they are encoded in the buffer, to their offsets in the buffer.
+    offsets: Map<Vec<u8>, u32>,
 
     /// The buffer that contains the final string block.
     buffer: Vec<u8>,
+
+    /// Offsets from the original file in the order that strings are added, only used when preserving a string block.
+    original_offsets: Vec<u32>,
+
+    /// Amount of strings added so far, the index into `original_offsets`.
+    added: usize,
+
+    /// How strings are written to the buffer.
+    encoding: StringEncoding,
+
+    /// Strings that are written in a codepage instead of `encoding`, by the index they are added in,
+    /// only used when writing a [`DecodedTable`](crate::DecodedTable).
+    /// A string is only written in the codepage if it is still the same as the string that was decoded.
+    decoded: Map<usize, String>,
+
+    /// The codepage for `decoded`.
+    decoded_encoding: StringEncoding,
 }
 
+impl Default for StringCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl StringCache {
     /// Creates a new `StringCache`.
     pub fn new() -> Self {
+        Self::with_encoding(StringEncoding::Utf8)
+    }
+
+    /// Creates a new `StringCache` that writes strings with `encoding`.
+    pub fn with_encoding(encoding: StringEncoding) -> Self {
         let mut new = Self {
-            offsets: HashMap::new(),
+            offsets: Map::new(),
             buffer: Vec::new(),
+            original_offsets: Vec::new(),
+            added: 0,
+            encoding,
+            decoded: Map::new(),
+            decoded_encoding: encoding,
         };
 
         // add an empty string, which makes empty strings reference 0
         new.buffer.push(0_u8);
-        new.offsets.insert("".to_string(), 0);
+        new.offsets.insert(Vec::new(), 0);
+
+        new
+    }
+
+    /// Creates a `StringCache` that starts with `string_block` and reuses the original offsets.
+    ///
+    /// `original_offsets` are the string references from the file in the order they are read,
+    /// which is the same order that they are added when writing.
+    /// A string that is still the same as the string at its original offset is written with that offset,
+    /// other strings reuse any existing string in the block before being appended to the end.
+    pub(crate) fn preserving(string_block: Vec<u8>, original_offsets: Vec<u32>) -> Self {
+        let mut offsets = Map::new();
+
+        let mut start = 0;
+        for s in string_block.split(|&a| a == 0) {
+            // the last split is after the final NUL and is not terminated
+            if start + s.len() >= string_block.len() {
+                break;
+            }
+
+            if let Ok(s) = core::str::from_utf8(s) {
+                for i in (0..s.len()).filter(|&i| s.is_char_boundary(i)) {
+                    offsets
+                        .entry(s.as_bytes()[i..].to_vec())
+                        .or_insert((start + i) as u32);
+                }
+                // empty strings and the empty suffix point to the NUL
+                offsets
+                    .entry(Vec::new())
+                    .or_insert((start + s.len()) as u32);
+            }
+
+            start += s.len() + 1;
+        }
+
+        Self {
+            offsets,
+            buffer: string_block,
+            original_offsets,
+            added: 0,
+            encoding: StringEncoding::Utf8,
+            decoded: Map::new(),
+            decoded_encoding: StringEncoding::Utf8,
+        }
+    }
+
+    /// Creates a `StringCache` that writes UTF-8, except for the strings in `decoded` which are written in `codepage`.
+    ///
+    /// `decoded` maps the index of a string, in the order that strings are added when writing, to the string that was decoded.
+    /// The string is only written in `codepage` if it has not been changed.
+    pub(crate) fn decoded(codepage: Codepage, decoded: Map<usize, String>) -> Self {
+        let mut new = Self::new();
+        new.decoded = decoded;
+        new.decoded_encoding = StringEncoding::Codepage(codepage);
 
         new
     }
@@ -212,15 +493,30 @@ impl StringCache {
     ///
     /// If the string already exists in the cache, it returns the existing offset.
     pub fn add_string(&mut self, s: &str) -> u32 {
+        let original = self.original_offsets.get(self.added).copied();
+        let encoding = match self.decoded.get(&self.added) {
+            Some(decoded) if decoded == s => self.decoded_encoding,
+            _ => self.encoding,
+        };
+        self.added += 1;
+
+        let encoded = encoding.encode(s);
+
+        // keep the original offset if the string has not changed
+        if let Some(offset) = original {
+            if offset == 0 && s.is_empty() || self.string_at(offset) == Some(encoded.as_ref()) {
+                return offset;
+            }
+        }
 
         // if offset already exists, return it
-        if let Some(offset) = self.offsets.get(s) {
+        if let Some(offset) = self.offsets.get(encoded.as_ref()) {
             return *offset;
         }
 
         // if the string does not exist, we add it to the cache
         let offset = self.buffer.len() as u32;
-        self.buffer.extend_from_slice(s.as_bytes());
+        self.buffer.extend_from_slice(&encoded);
 
         // null-terminate the string
         self.buffer.push(0_u8);
@@ -230,23 +526,21 @@ impl StringCache {
         // the original dbc files do this as well but not as thoroughly
         // there are instances where suffixes stand alone, like "23" after "123" is already in the cache
         // this means that bit perfect recreation is not possible
-        for i in 0..s.len() {
-            // we need to check if the resulting substring is a valid UTF-8 string, if not, we skip it
-            // this happens if we are dealing with a multibyte character in a localized string
-
-            if !s.is_char_boundary(i) {
-                continue;
-            }
-
-            let suffix = &s[i..];
-            self.offsets.insert(suffix.to_string(), offset + i as u32);
+        // only the start of every character is a valid suffix, which is not the same as the byte index for codepages
+        let mut encoded_index = 0;
+        for c in s.chars() {
+            let suffix = &encoded[encoded_index..];
+            self.offsets
+                .insert(suffix.to_vec(), offset + encoded_index as u32);
+
+            encoded_index += encoding.encoded_len(c);
         }
 
         offset
     }
 
     /// Returns the size of the string block.
-    pub fn size(&self) -> u32 {
+    pub const fn size(&self) -> u32 {
         self.buffer.len() as u32
     }
 
@@ -254,6 +548,13 @@ impl StringCache {
     pub fn buffer(&self) -> &[u8] {
         &self.buffer
     }
+
+    fn string_at(&self, offset: u32) -> Option<&[u8]> {
+        let s = self.buffer.get(offset as usize..)?;
+        let end = s.iter().position(|&a| a == 0)?;
+
+        Some(&s[..end])
+    }
 }
 
 
@@ -306,4 +607,32 @@ mod tests {
         assert_eq!(cache.size(), 16);
         assert_eq!(cache.buffer().len(), 16);
     }
+
+    #[cfg(feature = "vanilla")]
+    #[test]
+    fn test_read_rows() {
+        use crate::fixtures::spell_icon_row;
+        use crate::vanilla_tables::spell_icon::SpellIcon;
+        use crate::{DbcError, DbcTable};
+
+        // enough rows to be read in parallel with the `rayon` feature
+        let table = SpellIcon {
+            rows: (0..10_000)
+                .map(|i| spell_icon_row(i, &format!("Interface\\Icons\\{i}")))
+                .collect(),
+        };
+        let mut b = Vec::new();
+        table.write(&mut b).unwrap();
+        assert_eq!(SpellIcon::read(&mut b.as_slice()).unwrap(), table);
+
+        // the first invalid row is reported no matter which chunk finishes first
+        for row in [9_000, 5_000] {
+            let offset = crate::header::HEADER_SIZE + row * SpellIcon::ROW_SIZE + 4;
+            b[offset..offset + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        }
+        let Err(DbcError::Field(e)) = SpellIcon::read(&mut b.as_slice()) else {
+            panic!("invalid string offset was read");
+        };
+        assert_eq!(e.row, Some(5_000));
+    }
 }
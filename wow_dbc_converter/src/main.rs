@@ -6,8 +6,8 @@ mod wrath_tables_sqlite;
 pub(crate) use error::*;
 use std::fmt::{Display, Formatter};
 
-use std::fs::{create_dir_all, read, read_dir};
-use std::path::PathBuf;
+use std::fs::{create_dir_all, read, read_dir, File};
+use std::path::{Path, PathBuf};
 use std::process::exit;
 
 use clap::Parser;
@@ -37,9 +37,9 @@ struct Args {
 
     /// Which expansion version to read the DBC files as.
     ///
-    /// Must be correct otherwise errors will likely occur.
+    /// Detected from the filenames and headers of the DBC files if not set.
     #[arg(value_enum)]
-    dbc_version: Expansion,
+    dbc_version: Option<Expansion>,
 
     /// Exits on all errors instead of continuing.
     #[arg(short = 's', long)]
@@ -67,6 +67,16 @@ impl Display for Expansion {
     }
 }
 
+impl From<wow_dbc::Expansion> for Expansion {
+    fn from(value: wow_dbc::Expansion) -> Self {
+        match value {
+            wow_dbc::Expansion::Vanilla => Self::Vanilla,
+            wow_dbc::Expansion::Tbc => Self::BurningCrusade,
+            wow_dbc::Expansion::Wrath => Self::Wrath,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 struct Options {
     input_path: PathBuf,
@@ -79,13 +89,19 @@ fn main() {
     let args = Args::parse();
     let options = options(args);
 
-    let files = if options.input_path.is_dir() {
-        let input_directory = match read_dir(&options.input_path) {
+    let files = dbc_files(&options.input_path);
+
+    files.iter().for_each(|a| apply_file(&options, a));
+}
+
+fn dbc_files(input_path: &Path) -> Vec<PathBuf> {
+    if input_path.is_dir() {
+        let input_directory = match read_dir(input_path) {
             Ok(e) => e,
             Err(e) => {
                 fatal_error(format!(
                     "Unable to open directory for reading: '{}' with error '{}'",
-                    options.input_path.display(),
+                    input_path.display(),
                     e
                 ));
             }
@@ -107,16 +123,51 @@ fn main() {
                 }
             })
             .collect::<Vec<_>>()
-    } else if options.input_path.is_file() {
-        vec![options.input_path.clone()]
+    } else if input_path.is_file() {
+        vec![input_path.to_path_buf()]
     } else {
         fatal_error(format!(
             "Input directory '{}' is not either a directory or a file.",
-            options.input_path.display()
+            input_path.display()
         ));
-    };
+    }
+}
 
-    files.iter().for_each(|a| apply_file(&options, a));
+fn detect_expansion(files: &[PathBuf]) -> Expansion {
+    let headers = files
+        .iter()
+        .filter_map(|file| {
+            let header = wow_dbc::header::peek_header(&mut File::open(file).ok()?).ok()?;
+            let file_name = file.file_name()?.to_string_lossy().to_string();
+
+            Some((file_name, header))
+        })
+        .collect::<Vec<_>>();
+
+    let expansions = wow_dbc::identify_expansion(
+        headers
+            .iter()
+            .map(|(name, header)| (name.as_str(), *header)),
+    );
+
+    for (expansion, count) in &expansions {
+        println!(
+            "{} of {} files match {}",
+            count,
+            headers.len(),
+            Expansion::from(*expansion)
+        );
+    }
+
+    match expansions.as_slice() {
+        [] => fatal_error(
+            "Unable to detect the expansion of the DBC files. Set 'dbc_version' to choose one.",
+        ),
+        [(_, first), (_, second), ..] if first == second => fatal_error(
+            "Unable to choose between expansions with the same amount of matching files. Set 'dbc_version' to choose one.",
+        ),
+        [(expansion, _), ..] => (*expansion).into(),
+    }
 }
 
 fn apply_file(options: &Options, file: &PathBuf) {
@@ -260,7 +311,12 @@ fn options(args: Args) -> Options {
         cwd.clone()
     };
 
-    let default_file_name = match args.dbc_version {
+    let expansion = match args.dbc_version {
+        Some(e) => e,
+        None => detect_expansion(&dbc_files(&input_path)),
+    };
+
+    let default_file_name = match expansion {
         Expansion::Vanilla => "vanilla_dbcs.sqlite",
         Expansion::BurningCrusade => "burning_crusade_dbcs.sqlite",
         Expansion::Wrath => "wrath_dbcs.sqlite",
@@ -328,12 +384,12 @@ fn options(args: Args) -> Options {
             "Disabled"
         }
     );
-    println!("DBC Version: {}", args.dbc_version);
+    println!("DBC Version: {}", expansion);
 
     Options {
         input_path,
         output_path,
-        expansion: args.dbc_version,
+        expansion,
         strict_mode: args.strict_mode,
     }
 }
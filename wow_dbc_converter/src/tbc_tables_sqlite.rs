@@ -6,9 +6,12 @@ use wow_dbc::tbc_tables::*;
 pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_contents: &mut &[u8]) -> Result<(), SqliteError> {
     let tx = conn.transaction()?;
 
-    match file_name {
-        "AnimationData.dbc" => {
-            let data = animation_data::AnimationData::read(file_contents)?;
+    let Some(data) = AnyTable::read_by_filename(file_name, file_contents)? else {
+        return Err(SqliteError::FilenameNotFound { name: file_name.to_string() });
+    };
+
+    match data {
+        AnyTable::AnimationData(data) => {
             let (table, insert) = AnimationData();
             tx.execute(table, ())?;
 
@@ -24,8 +27,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "AreaPOI.dbc" => {
-            let data = area_poi::AreaPOI::read(file_contents)?;
+        AnyTable::AreaPOI(data) => {
             let (table, insert) = AreaPOI();
             tx.execute(table, ())?;
 
@@ -79,8 +81,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "AreaTable.dbc" => {
-            let data = area_table::AreaTable::read(file_contents)?;
+        AnyTable::AreaTable(data) => {
             let (table, insert) = AreaTable();
             tx.execute(table, ())?;
 
@@ -124,8 +125,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "AreaTrigger.dbc" => {
-            let data = area_trigger::AreaTrigger::read(file_contents)?;
+        AnyTable::AreaTrigger(data) => {
             let (table, insert) = AreaTrigger();
             tx.execute(table, ())?;
 
@@ -144,8 +144,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "AttackAnimKits.dbc" => {
-            let data = attack_anim_kits::AttackAnimKits::read(file_contents)?;
+        AnyTable::AttackAnimKits(data) => {
             let (table, insert) = AttackAnimKits();
             tx.execute(table, ())?;
 
@@ -159,8 +158,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "AttackAnimTypes.dbc" => {
-            let data = attack_anim_types::AttackAnimTypes::read(file_contents)?;
+        AnyTable::AttackAnimTypes(data) => {
             let (table, insert) = AttackAnimTypes();
             tx.execute(table, ())?;
 
@@ -171,8 +169,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "AuctionHouse.dbc" => {
-            let data = auction_house::AuctionHouse::read(file_contents)?;
+        AnyTable::AuctionHouse(data) => {
             let (table, insert) = AuctionHouse();
             tx.execute(table, ())?;
 
@@ -202,8 +199,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "BankBagSlotPrices.dbc" => {
-            let data = bank_bag_slot_prices::BankBagSlotPrices::read(file_contents)?;
+        AnyTable::BankBagSlotPrices(data) => {
             let (table, insert) = BankBagSlotPrices();
             tx.execute(table, ())?;
 
@@ -214,8 +210,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "BattlemasterList.dbc" => {
-            let data = battlemaster_list::BattlemasterList::read(file_contents)?;
+        AnyTable::BattlemasterList(data) => {
             let (table, insert) = BattlemasterList();
             tx.execute(table, ())?;
 
@@ -257,8 +252,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CameraShakes.dbc" => {
-            let data = camera_shakes::CameraShakes::read(file_contents)?;
+        AnyTable::CameraShakes(data) => {
             let (table, insert) = CameraShakes();
             tx.execute(table, ())?;
 
@@ -275,8 +269,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Cfg_Categories.dbc" => {
-            let data = cfg_categories::Cfg_Categories::read(file_contents)?;
+        AnyTable::Cfg_Categories(data) => {
             let (table, insert) = Cfg_Categories();
             tx.execute(table, ())?;
 
@@ -306,8 +299,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Cfg_Configs.dbc" => {
-            let data = cfg_configs::Cfg_Configs::read(file_contents)?;
+        AnyTable::Cfg_Configs(data) => {
             let (table, insert) = Cfg_Configs();
             tx.execute(table, ())?;
 
@@ -320,8 +312,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CharBaseInfo.dbc" => {
-            let data = char_base_info::CharBaseInfo::read(file_contents)?;
+        AnyTable::CharBaseInfo(data) => {
             let (table, insert) = CharBaseInfo();
             tx.execute(table, ())?;
 
@@ -332,8 +323,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CharHairGeosets.dbc" => {
-            let data = char_hair_geosets::CharHairGeosets::read(file_contents)?;
+        AnyTable::CharHairGeosets(data) => {
             let (table, insert) = CharHairGeosets();
             tx.execute(table, ())?;
 
@@ -348,8 +338,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CharHairTextures.dbc" => {
-            let data = char_hair_textures::CharHairTextures::read(file_contents)?;
+        AnyTable::CharHairTextures(data) => {
             let (table, insert) = CharHairTextures();
             tx.execute(table, ())?;
 
@@ -366,8 +355,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CharSections.dbc" => {
-            let data = char_sections::CharSections::read(file_contents)?;
+        AnyTable::CharSections(data) => {
             let (table, insert) = CharSections();
             tx.execute(table, ())?;
 
@@ -386,8 +374,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CharStartOutfit.dbc" => {
-            let data = char_start_outfit::CharStartOutfit::read(file_contents)?;
+        AnyTable::CharStartOutfit(data) => {
             let (table, insert) = CharStartOutfit();
             tx.execute(table, ())?;
 
@@ -437,8 +424,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CharTitles.dbc" => {
-            let data = char_titles::CharTitles::read(file_contents)?;
+        AnyTable::CharTitles(data) => {
             let (table, insert) = CharTitles();
             tx.execute(table, ())?;
 
@@ -484,8 +470,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CharVariations.dbc" => {
-            let data = char_variations::CharVariations::read(file_contents)?;
+        AnyTable::CharVariations(data) => {
             let (table, insert) = CharVariations();
             tx.execute(table, ())?;
 
@@ -500,8 +485,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CharacterFacialHairStyles.dbc" => {
-            let data = character_facial_hair_styles::CharacterFacialHairStyles::read(file_contents)?;
+        AnyTable::CharacterFacialHairStyles(data) => {
             let (table, insert) = CharacterFacialHairStyles();
             tx.execute(table, ())?;
 
@@ -521,8 +505,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ChatChannels.dbc" => {
-            let data = chat_channels::ChatChannels::read(file_contents)?;
+        AnyTable::ChatChannels(data) => {
             let (table, insert) = ChatChannels();
             tx.execute(table, ())?;
 
@@ -568,8 +551,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ChatProfanity.dbc" => {
-            let data = chat_profanity::ChatProfanity::read(file_contents)?;
+        AnyTable::ChatProfanity(data) => {
             let (table, insert) = ChatProfanity();
             tx.execute(table, ())?;
 
@@ -581,8 +563,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ChrClasses.dbc" => {
-            let data = chr_classes::ChrClasses::read(file_contents)?;
+        AnyTable::ChrClasses(data) => {
             let (table, insert) = ChrClasses();
             tx.execute(table, ())?;
 
@@ -649,8 +630,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ChrRaces.dbc" => {
-            let data = chr_races::ChrRaces::read(file_contents)?;
+        AnyTable::ChrRaces(data) => {
             let (table, insert) = ChrRaces();
             tx.execute(table, ())?;
 
@@ -728,8 +708,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CinematicCamera.dbc" => {
-            let data = cinematic_camera::CinematicCamera::read(file_contents)?;
+        AnyTable::CinematicCamera(data) => {
             let (table, insert) = CinematicCamera();
             tx.execute(table, ())?;
 
@@ -745,8 +724,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CinematicSequences.dbc" => {
-            let data = cinematic_sequences::CinematicSequences::read(file_contents)?;
+        AnyTable::CinematicSequences(data) => {
             let (table, insert) = CinematicSequences();
             tx.execute(table, ())?;
 
@@ -765,8 +743,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CreatureDisplayInfo.dbc" => {
-            let data = creature_display_info::CreatureDisplayInfo::read(file_contents)?;
+        AnyTable::CreatureDisplayInfo(data) => {
             let (table, insert) = CreatureDisplayInfo();
             tx.execute(table, ())?;
 
@@ -789,8 +766,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CreatureDisplayInfoExtra.dbc" => {
-            let data = creature_display_info_extra::CreatureDisplayInfoExtra::read(file_contents)?;
+        AnyTable::CreatureDisplayInfoExtra(data) => {
             let (table, insert) = CreatureDisplayInfoExtra();
             tx.execute(table, ())?;
 
@@ -820,8 +796,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CreatureFamily.dbc" => {
-            let data = creature_family::CreatureFamily::read(file_contents)?;
+        AnyTable::CreatureFamily(data) => {
             let (table, insert) = CreatureFamily();
             tx.execute(table, ())?;
 
@@ -856,8 +831,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CreatureModelData.dbc" => {
-            let data = creature_model_data::CreatureModelData::read(file_contents)?;
+        AnyTable::CreatureModelData(data) => {
             let (table, insert) = CreatureModelData();
             tx.execute(table, ())?;
 
@@ -890,8 +864,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CreatureSoundData.dbc" => {
-            let data = creature_sound_data::CreatureSoundData::read(file_contents)?;
+        AnyTable::CreatureSoundData(data) => {
             let (table, insert) = CreatureSoundData();
             tx.execute(table, ())?;
 
@@ -937,8 +910,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CreatureSpellData.dbc" => {
-            let data = creature_spell_data::CreatureSpellData::read(file_contents)?;
+        AnyTable::CreatureSpellData(data) => {
             let (table, insert) = CreatureSpellData();
             tx.execute(table, ())?;
 
@@ -956,8 +928,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CreatureType.dbc" => {
-            let data = creature_type::CreatureType::read(file_contents)?;
+        AnyTable::CreatureType(data) => {
             let (table, insert) = CreatureType();
             tx.execute(table, ())?;
 
@@ -985,8 +956,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "DeathThudLookups.dbc" => {
-            let data = death_thud_lookups::DeathThudLookups::read(file_contents)?;
+        AnyTable::DeathThudLookups(data) => {
             let (table, insert) = DeathThudLookups();
             tx.execute(table, ())?;
 
@@ -1000,8 +970,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "DeclinedWord.dbc" => {
-            let data = declined_word::DeclinedWord::read(file_contents)?;
+        AnyTable::DeclinedWord(data) => {
             let (table, insert) = DeclinedWord();
             tx.execute(table, ())?;
 
@@ -1012,8 +981,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "DeclinedWordCases.dbc" => {
-            let data = declined_word_cases::DeclinedWordCases::read(file_contents)?;
+        AnyTable::DeclinedWordCases(data) => {
             let (table, insert) = DeclinedWordCases();
             tx.execute(table, ())?;
 
@@ -1026,8 +994,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "DurabilityCosts.dbc" => {
-            let data = durability_costs::DurabilityCosts::read(file_contents)?;
+        AnyTable::DurabilityCosts(data) => {
             let (table, insert) = DurabilityCosts();
             tx.execute(table, ())?;
 
@@ -1066,8 +1033,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "DurabilityQuality.dbc" => {
-            let data = durability_quality::DurabilityQuality::read(file_contents)?;
+        AnyTable::DurabilityQuality(data) => {
             let (table, insert) = DurabilityQuality();
             tx.execute(table, ())?;
 
@@ -1078,8 +1044,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Emotes.dbc" => {
-            let data = emotes::Emotes::read(file_contents)?;
+        AnyTable::Emotes(data) => {
             let (table, insert) = Emotes();
             tx.execute(table, ())?;
 
@@ -1095,8 +1060,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "EmotesText.dbc" => {
-            let data = emotes_text::EmotesText::read(file_contents)?;
+        AnyTable::EmotesText(data) => {
             let (table, insert) = EmotesText();
             tx.execute(table, ())?;
 
@@ -1124,8 +1088,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "EmotesTextData.dbc" => {
-            let data = emotes_text_data::EmotesTextData::read(file_contents)?;
+        AnyTable::EmotesTextData(data) => {
             let (table, insert) = EmotesTextData();
             tx.execute(table, ())?;
 
@@ -1152,8 +1115,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "EmotesTextSound.dbc" => {
-            let data = emotes_text_sound::EmotesTextSound::read(file_contents)?;
+        AnyTable::EmotesTextSound(data) => {
             let (table, insert) = EmotesTextSound();
             tx.execute(table, ())?;
 
@@ -1167,8 +1129,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "EnvironmentalDamage.dbc" => {
-            let data = environmental_damage::EnvironmentalDamage::read(file_contents)?;
+        AnyTable::EnvironmentalDamage(data) => {
             let (table, insert) = EnvironmentalDamage();
             tx.execute(table, ())?;
 
@@ -1180,8 +1141,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Exhaustion.dbc" => {
-            let data = exhaustion::Exhaustion::read(file_contents)?;
+        AnyTable::Exhaustion(data) => {
             let (table, insert) = Exhaustion();
             tx.execute(table, ())?;
 
@@ -1213,8 +1173,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Faction.dbc" => {
-            let data = faction::Faction::read(file_contents)?;
+        AnyTable::Faction(data) => {
             let (table, insert) = Faction();
             tx.execute(table, ())?;
 
@@ -1276,8 +1235,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "FactionGroup.dbc" => {
-            let data = faction_group::FactionGroup::read(file_contents)?;
+        AnyTable::FactionGroup(data) => {
             let (table, insert) = FactionGroup();
             tx.execute(table, ())?;
 
@@ -1306,8 +1264,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "FactionTemplate.dbc" => {
-            let data = faction_template::FactionTemplate::read(file_contents)?;
+        AnyTable::FactionTemplate(data) => {
             let (table, insert) = FactionTemplate();
             tx.execute(table, ())?;
 
@@ -1330,8 +1287,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "FootprintTextures.dbc" => {
-            let data = footprint_textures::FootprintTextures::read(file_contents)?;
+        AnyTable::FootprintTextures(data) => {
             let (table, insert) = FootprintTextures();
             tx.execute(table, ())?;
 
@@ -1342,8 +1298,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "FootstepTerrainLookup.dbc" => {
-            let data = footstep_terrain_lookup::FootstepTerrainLookup::read(file_contents)?;
+        AnyTable::FootstepTerrainLookup(data) => {
             let (table, insert) = FootstepTerrainLookup();
             tx.execute(table, ())?;
 
@@ -1357,8 +1312,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GMSurveyCurrentSurvey.dbc" => {
-            let data = gm_survey_current_survey::GMSurveyCurrentSurvey::read(file_contents)?;
+        AnyTable::GMSurveyCurrentSurvey(data) => {
             let (table, insert) = GMSurveyCurrentSurvey();
             tx.execute(table, ())?;
 
@@ -1369,8 +1323,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GMSurveyQuestions.dbc" => {
-            let data = gm_survey_questions::GMSurveyQuestions::read(file_contents)?;
+        AnyTable::GMSurveyQuestions(data) => {
             let (table, insert) = GMSurveyQuestions();
             tx.execute(table, ())?;
 
@@ -1397,8 +1350,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GMSurveySurveys.dbc" => {
-            let data = gm_survey_surveys::GMSurveySurveys::read(file_contents)?;
+        AnyTable::GMSurveySurveys(data) => {
             let (table, insert) = GMSurveySurveys();
             tx.execute(table, ())?;
 
@@ -1418,8 +1370,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GMTicketCategory.dbc" => {
-            let data = gm_ticket_category::GMTicketCategory::read(file_contents)?;
+        AnyTable::GMTicketCategory(data) => {
             let (table, insert) = GMTicketCategory();
             tx.execute(table, ())?;
 
@@ -1446,8 +1397,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GameObjectArtKit.dbc" => {
-            let data = game_object_art_kit::GameObjectArtKit::read(file_contents)?;
+        AnyTable::GameObjectArtKit(data) => {
             let (table, insert) = GameObjectArtKit();
             tx.execute(table, ())?;
 
@@ -1464,8 +1414,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GameObjectDisplayInfo.dbc" => {
-            let data = game_object_display_info::GameObjectDisplayInfo::read(file_contents)?;
+        AnyTable::GameObjectDisplayInfo(data) => {
             let (table, insert) = GameObjectDisplayInfo();
             tx.execute(table, ())?;
 
@@ -1492,8 +1441,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GameTables.dbc" => {
-            let data = game_tables::GameTables::read(file_contents)?;
+        AnyTable::GameTables(data) => {
             let (table, insert) = GameTables();
             tx.execute(table, ())?;
 
@@ -1505,8 +1453,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GameTips.dbc" => {
-            let data = game_tips::GameTips::read(file_contents)?;
+        AnyTable::GameTips(data) => {
             let (table, insert) = GameTips();
             tx.execute(table, ())?;
 
@@ -1533,8 +1480,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GemProperties.dbc" => {
-            let data = gem_properties::GemProperties::read(file_contents)?;
+        AnyTable::GemProperties(data) => {
             let (table, insert) = GemProperties();
             tx.execute(table, ())?;
 
@@ -1548,8 +1494,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GroundEffectDoodad.dbc" => {
-            let data = ground_effect_doodad::GroundEffectDoodad::read(file_contents)?;
+        AnyTable::GroundEffectDoodad(data) => {
             let (table, insert) = GroundEffectDoodad();
             tx.execute(table, ())?;
 
@@ -1561,8 +1506,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GroundEffectTexture.dbc" => {
-            let data = ground_effect_texture::GroundEffectTexture::read(file_contents)?;
+        AnyTable::GroundEffectTexture(data) => {
             let (table, insert) = GroundEffectTexture();
             tx.execute(table, ())?;
 
@@ -1578,8 +1522,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "HelmetGeosetVisData.dbc" => {
-            let data = helmet_geoset_vis_data::HelmetGeosetVisData::read(file_contents)?;
+        AnyTable::HelmetGeosetVisData(data) => {
             let (table, insert) = HelmetGeosetVisData();
             tx.execute(table, ())?;
 
@@ -1596,8 +1539,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Item.dbc" => {
-            let data = item::Item::read(file_contents)?;
+        AnyTable::Item(data) => {
             let (table, insert) = Item();
             tx.execute(table, ())?;
 
@@ -1610,8 +1552,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemBagFamily.dbc" => {
-            let data = item_bag_family::ItemBagFamily::read(file_contents)?;
+        AnyTable::ItemBagFamily(data) => {
             let (table, insert) = ItemBagFamily();
             tx.execute(table, ())?;
 
@@ -1638,8 +1579,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemClass.dbc" => {
-            let data = item_class::ItemClass::read(file_contents)?;
+        AnyTable::ItemClass(data) => {
             let (table, insert) = ItemClass();
             tx.execute(table, ())?;
 
@@ -1668,8 +1608,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemCondExtCosts.dbc" => {
-            let data = item_cond_ext_costs::ItemCondExtCosts::read(file_contents)?;
+        AnyTable::ItemCondExtCosts(data) => {
             let (table, insert) = ItemCondExtCosts();
             tx.execute(table, ())?;
 
@@ -1682,8 +1621,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemDisplayInfo.dbc" => {
-            let data = item_display_info::ItemDisplayInfo::read(file_contents)?;
+        AnyTable::ItemDisplayInfo(data) => {
             let (table, insert) = ItemDisplayInfo();
             tx.execute(table, ())?;
 
@@ -1717,8 +1655,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemExtendedCost.dbc" => {
-            let data = item_extended_cost::ItemExtendedCost::read(file_contents)?;
+        AnyTable::ItemExtendedCost(data) => {
             let (table, insert) = ItemExtendedCost();
             tx.execute(table, ())?;
 
@@ -1741,8 +1678,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemGroupSounds.dbc" => {
-            let data = item_group_sounds::ItemGroupSounds::read(file_contents)?;
+        AnyTable::ItemGroupSounds(data) => {
             let (table, insert) = ItemGroupSounds();
             tx.execute(table, ())?;
 
@@ -1756,8 +1692,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemPetFood.dbc" => {
-            let data = item_pet_food::ItemPetFood::read(file_contents)?;
+        AnyTable::ItemPetFood(data) => {
             let (table, insert) = ItemPetFood();
             tx.execute(table, ())?;
 
@@ -1784,8 +1719,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemRandomProperties.dbc" => {
-            let data = item_random_properties::ItemRandomProperties::read(file_contents)?;
+        AnyTable::ItemRandomProperties(data) => {
             let (table, insert) = ItemRandomProperties();
             tx.execute(table, ())?;
 
@@ -1818,8 +1752,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemRandomSuffix.dbc" => {
-            let data = item_random_suffix::ItemRandomSuffix::read(file_contents)?;
+        AnyTable::ItemRandomSuffix(data) => {
             let (table, insert) = ItemRandomSuffix();
             tx.execute(table, ())?;
 
@@ -1853,8 +1786,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemSet.dbc" => {
-            let data = item_set::ItemSet::read(file_contents)?;
+        AnyTable::ItemSet(data) => {
             let (table, insert) = ItemSet();
             tx.execute(table, ())?;
 
@@ -1916,8 +1848,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemSubClass.dbc" => {
-            let data = item_sub_class::ItemSubClass::read(file_contents)?;
+        AnyTable::ItemSubClass(data) => {
             let (table, insert) = ItemSubClass();
             tx.execute(table, ())?;
 
@@ -1970,8 +1901,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemSubClassMask.dbc" => {
-            let data = item_sub_class_mask::ItemSubClassMask::read(file_contents)?;
+        AnyTable::ItemSubClassMask(data) => {
             let (table, insert) = ItemSubClassMask();
             tx.execute(table, ())?;
 
@@ -1999,8 +1929,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemVisualEffects.dbc" => {
-            let data = item_visual_effects::ItemVisualEffects::read(file_contents)?;
+        AnyTable::ItemVisualEffects(data) => {
             let (table, insert) = ItemVisualEffects();
             tx.execute(table, ())?;
 
@@ -2011,8 +1940,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemVisuals.dbc" => {
-            let data = item_visuals::ItemVisuals::read(file_contents)?;
+        AnyTable::ItemVisuals(data) => {
             let (table, insert) = ItemVisuals();
             tx.execute(table, ())?;
 
@@ -2027,8 +1955,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LFGDungeons.dbc" => {
-            let data = lfg_dungeons::LFGDungeons::read(file_contents)?;
+        AnyTable::LFGDungeons(data) => {
             let (table, insert) = LFGDungeons();
             tx.execute(table, ())?;
 
@@ -2061,8 +1988,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LanguageWords.dbc" => {
-            let data = language_words::LanguageWords::read(file_contents)?;
+        AnyTable::LanguageWords(data) => {
             let (table, insert) = LanguageWords();
             tx.execute(table, ())?;
 
@@ -2074,8 +2000,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Languages.dbc" => {
-            let data = languages::Languages::read(file_contents)?;
+        AnyTable::Languages(data) => {
             let (table, insert) = Languages();
             tx.execute(table, ())?;
 
@@ -2102,8 +2027,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Light.dbc" => {
-            let data = light::Light::read(file_contents)?;
+        AnyTable::Light(data) => {
             let (table, insert) = Light();
             tx.execute(table, ())?;
 
@@ -2124,8 +2048,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LightFloatBand.dbc" => {
-            let data = light_float_band::LightFloatBand::read(file_contents)?;
+        AnyTable::LightFloatBand(data) => {
             let (table, insert) = LightFloatBand();
             tx.execute(table, ())?;
 
@@ -2168,8 +2091,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LightIntBand.dbc" => {
-            let data = light_int_band::LightIntBand::read(file_contents)?;
+        AnyTable::LightIntBand(data) => {
             let (table, insert) = LightIntBand();
             tx.execute(table, ())?;
 
@@ -2212,8 +2134,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LightParams.dbc" => {
-            let data = light_params::LightParams::read(file_contents)?;
+        AnyTable::LightParams(data) => {
             let (table, insert) = LightParams();
             tx.execute(table, ())?;
 
@@ -2231,8 +2152,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LightSkybox.dbc" => {
-            let data = light_skybox::LightSkybox::read(file_contents)?;
+        AnyTable::LightSkybox(data) => {
             let (table, insert) = LightSkybox();
             tx.execute(table, ())?;
 
@@ -2244,8 +2164,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LiquidType.dbc" => {
-            let data = liquid_type::LiquidType::read(file_contents)?;
+        AnyTable::LiquidType(data) => {
             let (table, insert) = LiquidType();
             tx.execute(table, ())?;
 
@@ -2258,8 +2177,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LoadingScreenTaxiSplines.dbc" => {
-            let data = loading_screen_taxi_splines::LoadingScreenTaxiSplines::read(file_contents)?;
+        AnyTable::LoadingScreenTaxiSplines(data) => {
             let (table, insert) = LoadingScreenTaxiSplines();
             tx.execute(table, ())?;
 
@@ -2287,8 +2205,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LoadingScreens.dbc" => {
-            let data = loading_screens::LoadingScreens::read(file_contents)?;
+        AnyTable::LoadingScreens(data) => {
             let (table, insert) = LoadingScreens();
             tx.execute(table, ())?;
 
@@ -2300,8 +2217,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Lock.dbc" => {
-            let data = lock::Lock::read(file_contents)?;
+        AnyTable::Lock(data) => {
             let (table, insert) = Lock();
             tx.execute(table, ())?;
 
@@ -2343,8 +2259,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LockType.dbc" => {
-            let data = lock_type::LockType::read(file_contents)?;
+        AnyTable::LockType(data) => {
             let (table, insert) = LockType();
             tx.execute(table, ())?;
 
@@ -2406,8 +2321,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "MailTemplate.dbc" => {
-            let data = mail_template::MailTemplate::read(file_contents)?;
+        AnyTable::MailTemplate(data) => {
             let (table, insert) = MailTemplate();
             tx.execute(table, ())?;
 
@@ -2451,8 +2365,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Map.dbc" => {
-            let data = map::Map::read(file_contents)?;
+        AnyTable::Map(data) => {
             let (table, insert) = Map();
             tx.execute(table, ())?;
 
@@ -2586,8 +2499,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Material.dbc" => {
-            let data = material::Material::read(file_contents)?;
+        AnyTable::Material(data) => {
             let (table, insert) = Material();
             tx.execute(table, ())?;
 
@@ -2601,8 +2513,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "NPCSounds.dbc" => {
-            let data = npc_sounds::NPCSounds::read(file_contents)?;
+        AnyTable::NPCSounds(data) => {
             let (table, insert) = NPCSounds();
             tx.execute(table, ())?;
 
@@ -2616,8 +2527,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "NameGen.dbc" => {
-            let data = name_gen::NameGen::read(file_contents)?;
+        AnyTable::NameGen(data) => {
             let (table, insert) = NameGen();
             tx.execute(table, ())?;
 
@@ -2630,8 +2540,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "NamesProfanity.dbc" => {
-            let data = names_profanity::NamesProfanity::read(file_contents)?;
+        AnyTable::NamesProfanity(data) => {
             let (table, insert) = NamesProfanity();
             tx.execute(table, ())?;
 
@@ -2643,8 +2552,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "NamesReserved.dbc" => {
-            let data = names_reserved::NamesReserved::read(file_contents)?;
+        AnyTable::NamesReserved(data) => {
             let (table, insert) = NamesReserved();
             tx.execute(table, ())?;
 
@@ -2656,8 +2564,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Package.dbc" => {
-            let data = package::Package::read(file_contents)?;
+        AnyTable::Package(data) => {
             let (table, insert) = Package();
             tx.execute(table, ())?;
 
@@ -2686,8 +2593,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "PageTextMaterial.dbc" => {
-            let data = page_text_material::PageTextMaterial::read(file_contents)?;
+        AnyTable::PageTextMaterial(data) => {
             let (table, insert) = PageTextMaterial();
             tx.execute(table, ())?;
 
@@ -2698,8 +2604,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "PaperDollItemFrame.dbc" => {
-            let data = paper_doll_item_frame::PaperDollItemFrame::read(file_contents)?;
+        AnyTable::PaperDollItemFrame(data) => {
             let (table, insert) = PaperDollItemFrame();
             tx.execute(table, ())?;
 
@@ -2711,8 +2616,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ParticleColor.dbc" => {
-            let data = particle_color::ParticleColor::read(file_contents)?;
+        AnyTable::ParticleColor(data) => {
             let (table, insert) = ParticleColor();
             tx.execute(table, ())?;
 
@@ -2731,8 +2635,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "PetLoyalty.dbc" => {
-            let data = pet_loyalty::PetLoyalty::read(file_contents)?;
+        AnyTable::PetLoyalty(data) => {
             let (table, insert) = PetLoyalty();
             tx.execute(table, ())?;
 
@@ -2759,8 +2662,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "PetPersonality.dbc" => {
-            let data = pet_personality::PetPersonality::read(file_contents)?;
+        AnyTable::PetPersonality(data) => {
             let (table, insert) = PetPersonality();
             tx.execute(table, ())?;
 
@@ -2796,8 +2698,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "PetitionType.dbc" => {
-            let data = petition_type::PetitionType::read(file_contents)?;
+        AnyTable::PetitionType(data) => {
             let (table, insert) = PetitionType();
             tx.execute(table, ())?;
 
@@ -2809,8 +2710,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "QuestInfo.dbc" => {
-            let data = quest_info::QuestInfo::read(file_contents)?;
+        AnyTable::QuestInfo(data) => {
             let (table, insert) = QuestInfo();
             tx.execute(table, ())?;
 
@@ -2837,8 +2737,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "QuestSort.dbc" => {
-            let data = quest_sort::QuestSort::read(file_contents)?;
+        AnyTable::QuestSort(data) => {
             let (table, insert) = QuestSort();
             tx.execute(table, ())?;
 
@@ -2865,8 +2764,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "RandPropPoints.dbc" => {
-            let data = rand_prop_points::RandPropPoints::read(file_contents)?;
+        AnyTable::RandPropPoints(data) => {
             let (table, insert) = RandPropPoints();
             tx.execute(table, ())?;
 
@@ -2891,8 +2789,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Resistances.dbc" => {
-            let data = resistances::Resistances::read(file_contents)?;
+        AnyTable::Resistances(data) => {
             let (table, insert) = Resistances();
             tx.execute(table, ())?;
 
@@ -2921,8 +2818,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ServerMessages.dbc" => {
-            let data = server_messages::ServerMessages::read(file_contents)?;
+        AnyTable::ServerMessages(data) => {
             let (table, insert) = ServerMessages();
             tx.execute(table, ())?;
 
@@ -2949,8 +2845,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SheatheSoundLookups.dbc" => {
-            let data = sheathe_sound_lookups::SheatheSoundLookups::read(file_contents)?;
+        AnyTable::SheatheSoundLookups(data) => {
             let (table, insert) = SheatheSoundLookups();
             tx.execute(table, ())?;
 
@@ -2966,8 +2861,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SkillCostsData.dbc" => {
-            let data = skill_costs_data::SkillCostsData::read(file_contents)?;
+        AnyTable::SkillCostsData(data) => {
             let (table, insert) = SkillCostsData();
             tx.execute(table, ())?;
 
@@ -2981,8 +2875,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SkillLine.dbc" => {
-            let data = skill_line::SkillLine::read(file_contents)?;
+        AnyTable::SkillLine(data) => {
             let (table, insert) = SkillLine();
             tx.execute(table, ())?;
 
@@ -3029,8 +2922,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SkillLineAbility.dbc" => {
-            let data = skill_line_ability::SkillLineAbility::read(file_contents)?;
+        AnyTable::SkillLineAbility(data) => {
             let (table, insert) = SkillLineAbility();
             tx.execute(table, ())?;
 
@@ -3054,8 +2946,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SkillLineCategory.dbc" => {
-            let data = skill_line_category::SkillLineCategory::read(file_contents)?;
+        AnyTable::SkillLineCategory(data) => {
             let (table, insert) = SkillLineCategory();
             tx.execute(table, ())?;
 
@@ -3083,8 +2974,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SkillRaceClassInfo.dbc" => {
-            let data = skill_race_class_info::SkillRaceClassInfo::read(file_contents)?;
+        AnyTable::SkillRaceClassInfo(data) => {
             let (table, insert) = SkillRaceClassInfo();
             tx.execute(table, ())?;
 
@@ -3101,8 +2991,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SkillTiers.dbc" => {
-            let data = skill_tiers::SkillTiers::read(file_contents)?;
+        AnyTable::SkillTiers(data) => {
             let (table, insert) = SkillTiers();
             tx.execute(table, ())?;
 
@@ -3144,8 +3033,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SoundAmbience.dbc" => {
-            let data = sound_ambience::SoundAmbience::read(file_contents)?;
+        AnyTable::SoundAmbience(data) => {
             let (table, insert) = SoundAmbience();
             tx.execute(table, ())?;
 
@@ -3157,8 +3045,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SoundEntries.dbc" => {
-            let data = sound_entries::SoundEntries::read(file_contents)?;
+        AnyTable::SoundEntries(data) => {
             let (table, insert) = SoundEntries();
             tx.execute(table, ())?;
 
@@ -3196,8 +3083,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SoundProviderPreferences.dbc" => {
-            let data = sound_provider_preferences::SoundProviderPreferences::read(file_contents)?;
+        AnyTable::SoundProviderPreferences(data) => {
             let (table, insert) = SoundProviderPreferences();
             tx.execute(table, ())?;
 
@@ -3230,8 +3116,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SoundSamplePreferences.dbc" => {
-            let data = sound_sample_preferences::SoundSamplePreferences::read(file_contents)?;
+        AnyTable::SoundSamplePreferences(data) => {
             let (table, insert) = SoundSamplePreferences();
             tx.execute(table, ())?;
 
@@ -3257,8 +3142,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SoundWaterType.dbc" => {
-            let data = sound_water_type::SoundWaterType::read(file_contents)?;
+        AnyTable::SoundWaterType(data) => {
             let (table, insert) = SoundWaterType();
             tx.execute(table, ())?;
 
@@ -3271,8 +3155,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpamMessages.dbc" => {
-            let data = spam_messages::SpamMessages::read(file_contents)?;
+        AnyTable::SpamMessages(data) => {
             let (table, insert) = SpamMessages();
             tx.execute(table, ())?;
 
@@ -3283,8 +3166,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Spell.dbc" => {
-            let data = spell::Spell::read(file_contents)?;
+        AnyTable::Spell(data) => {
             let (table, insert) = Spell();
             tx.execute(table, ())?;
 
@@ -3509,8 +3391,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellCastTimes.dbc" => {
-            let data = spell_cast_times::SpellCastTimes::read(file_contents)?;
+        AnyTable::SpellCastTimes(data) => {
             let (table, insert) = SpellCastTimes();
             tx.execute(table, ())?;
 
@@ -3523,8 +3404,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellCategory.dbc" => {
-            let data = spell_category::SpellCategory::read(file_contents)?;
+        AnyTable::SpellCategory(data) => {
             let (table, insert) = SpellCategory();
             tx.execute(table, ())?;
 
@@ -3535,8 +3415,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellChainEffects.dbc" => {
-            let data = spell_chain_effects::SpellChainEffects::read(file_contents)?;
+        AnyTable::SpellChainEffects(data) => {
             let (table, insert) = SpellChainEffects();
             tx.execute(table, ())?;
 
@@ -3592,8 +3471,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellDispelType.dbc" => {
-            let data = spell_dispel_type::SpellDispelType::read(file_contents)?;
+        AnyTable::SpellDispelType(data) => {
             let (table, insert) = SpellDispelType();
             tx.execute(table, ())?;
 
@@ -3623,8 +3501,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellDuration.dbc" => {
-            let data = spell_duration::SpellDuration::read(file_contents)?;
+        AnyTable::SpellDuration(data) => {
             let (table, insert) = SpellDuration();
             tx.execute(table, ())?;
 
@@ -3637,8 +3514,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellEffectCameraShakes.dbc" => {
-            let data = spell_effect_camera_shakes::SpellEffectCameraShakes::read(file_contents)?;
+        AnyTable::SpellEffectCameraShakes(data) => {
             let (table, insert) = SpellEffectCameraShakes();
             tx.execute(table, ())?;
 
@@ -3651,8 +3527,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellFocusObject.dbc" => {
-            let data = spell_focus_object::SpellFocusObject::read(file_contents)?;
+        AnyTable::SpellFocusObject(data) => {
             let (table, insert) = SpellFocusObject();
             tx.execute(table, ())?;
 
@@ -3679,8 +3554,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellIcon.dbc" => {
-            let data = spell_icon::SpellIcon::read(file_contents)?;
+        AnyTable::SpellIcon(data) => {
             let (table, insert) = SpellIcon();
             tx.execute(table, ())?;
 
@@ -3691,8 +3565,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellItemEnchantment.dbc" => {
-            let data = spell_item_enchantment::SpellItemEnchantment::read(file_contents)?;
+        AnyTable::SpellItemEnchantment(data) => {
             let (table, insert) = SpellItemEnchantment();
             tx.execute(table, ())?;
 
@@ -3735,8 +3608,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellItemEnchantmentCondition.dbc" => {
-            let data = spell_item_enchantment_condition::SpellItemEnchantmentCondition::read(file_contents)?;
+        AnyTable::SpellItemEnchantmentCondition(data) => {
             let (table, insert) = SpellItemEnchantmentCondition();
             tx.execute(table, ())?;
 
@@ -3776,8 +3648,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellMechanic.dbc" => {
-            let data = spell_mechanic::SpellMechanic::read(file_contents)?;
+        AnyTable::SpellMechanic(data) => {
             let (table, insert) = SpellMechanic();
             tx.execute(table, ())?;
 
@@ -3804,8 +3675,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellMissileMotion.dbc" => {
-            let data = spell_missile_motion::SpellMissileMotion::read(file_contents)?;
+        AnyTable::SpellMissileMotion(data) => {
             let (table, insert) = SpellMissileMotion();
             tx.execute(table, ())?;
 
@@ -3819,8 +3689,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellRadius.dbc" => {
-            let data = spell_radius::SpellRadius::read(file_contents)?;
+        AnyTable::SpellRadius(data) => {
             let (table, insert) = SpellRadius();
             tx.execute(table, ())?;
 
@@ -3833,8 +3702,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellRange.dbc" => {
-            let data = spell_range::SpellRange::read(file_contents)?;
+        AnyTable::SpellRange(data) => {
             let (table, insert) = SpellRange();
             tx.execute(table, ())?;
 
@@ -3881,8 +3749,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellShapeshiftForm.dbc" => {
-            let data = spell_shapeshift_form::SpellShapeshiftForm::read(file_contents)?;
+        AnyTable::SpellShapeshiftForm(data) => {
             let (table, insert) = SpellShapeshiftForm();
             tx.execute(table, ())?;
 
@@ -3926,8 +3793,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellVisual.dbc" => {
-            let data = spell_visual::SpellVisual::read(file_contents)?;
+        AnyTable::SpellVisual(data) => {
             let (table, insert) = SpellVisual();
             tx.execute(table, ())?;
 
@@ -3961,8 +3827,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellVisualEffectName.dbc" => {
-            let data = spell_visual_effect_name::SpellVisualEffectName::read(file_contents)?;
+        AnyTable::SpellVisualEffectName(data) => {
             let (table, insert) = SpellVisualEffectName();
             tx.execute(table, ())?;
 
@@ -3976,8 +3841,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellVisualKit.dbc" => {
-            let data = spell_visual_kit::SpellVisualKit::read(file_contents)?;
+        AnyTable::SpellVisualKit(data) => {
             let (table, insert) = SpellVisualKit();
             tx.execute(table, ())?;
 
@@ -4024,8 +3888,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellVisualPrecastTransitions.dbc" => {
-            let data = spell_visual_precast_transitions::SpellVisualPrecastTransitions::read(file_contents)?;
+        AnyTable::SpellVisualPrecastTransitions(data) => {
             let (table, insert) = SpellVisualPrecastTransitions();
             tx.execute(table, ())?;
 
@@ -4037,8 +3900,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "StableSlotPrices.dbc" => {
-            let data = stable_slot_prices::StableSlotPrices::read(file_contents)?;
+        AnyTable::StableSlotPrices(data) => {
             let (table, insert) = StableSlotPrices();
             tx.execute(table, ())?;
 
@@ -4049,8 +3911,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Startup_Strings.dbc" => {
-            let data = startup_strings::Startup_Strings::read(file_contents)?;
+        AnyTable::Startup_Strings(data) => {
             let (table, insert) = Startup_Strings();
             tx.execute(table, ())?;
 
@@ -4078,8 +3939,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Stationery.dbc" => {
-            let data = stationery::Stationery::read(file_contents)?;
+        AnyTable::Stationery(data) => {
             let (table, insert) = Stationery();
             tx.execute(table, ())?;
 
@@ -4092,8 +3952,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "StringLookups.dbc" => {
-            let data = string_lookups::StringLookups::read(file_contents)?;
+        AnyTable::StringLookups(data) => {
             let (table, insert) = StringLookups();
             tx.execute(table, ())?;
 
@@ -4104,8 +3963,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SummonProperties.dbc" => {
-            let data = summon_properties::SummonProperties::read(file_contents)?;
+        AnyTable::SummonProperties(data) => {
             let (table, insert) = SummonProperties();
             tx.execute(table, ())?;
 
@@ -4120,8 +3978,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Talent.dbc" => {
-            let data = talent::Talent::read(file_contents)?;
+        AnyTable::Talent(data) => {
             let (table, insert) = Talent();
             tx.execute(table, ())?;
 
@@ -4151,8 +4008,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TalentTab.dbc" => {
-            let data = talent_tab::TalentTab::read(file_contents)?;
+        AnyTable::TalentTab(data) => {
             let (table, insert) = TalentTab();
             tx.execute(table, ())?;
 
@@ -4184,8 +4040,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TaxiNodes.dbc" => {
-            let data = taxi_nodes::TaxiNodes::read(file_contents)?;
+        AnyTable::TaxiNodes(data) => {
             let (table, insert) = TaxiNodes();
             tx.execute(table, ())?;
 
@@ -4218,8 +4073,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TaxiPath.dbc" => {
-            let data = taxi_path::TaxiPath::read(file_contents)?;
+        AnyTable::TaxiPath(data) => {
             let (table, insert) = TaxiPath();
             tx.execute(table, ())?;
 
@@ -4232,8 +4086,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TaxiPathNode.dbc" => {
-            let data = taxi_path_node::TaxiPathNode::read(file_contents)?;
+        AnyTable::TaxiPathNode(data) => {
             let (table, insert) = TaxiPathNode();
             tx.execute(table, ())?;
 
@@ -4253,8 +4106,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TerrainType.dbc" => {
-            let data = terrain_type::TerrainType::read(file_contents)?;
+        AnyTable::TerrainType(data) => {
             let (table, insert) = TerrainType();
             tx.execute(table, ())?;
 
@@ -4269,8 +4121,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TerrainTypeSounds.dbc" => {
-            let data = terrain_type_sounds::TerrainTypeSounds::read(file_contents)?;
+        AnyTable::TerrainTypeSounds(data) => {
             let (table, insert) = TerrainTypeSounds();
             tx.execute(table, ())?;
 
@@ -4280,8 +4131,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TotemCategory.dbc" => {
-            let data = totem_category::TotemCategory::read(file_contents)?;
+        AnyTable::TotemCategory(data) => {
             let (table, insert) = TotemCategory();
             tx.execute(table, ())?;
 
@@ -4310,8 +4160,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TransportAnimation.dbc" => {
-            let data = transport_animation::TransportAnimation::read(file_contents)?;
+        AnyTable::TransportAnimation(data) => {
             let (table, insert) = TransportAnimation();
             tx.execute(table, ())?;
 
@@ -4327,8 +4176,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TransportPhysics.dbc" => {
-            let data = transport_physics::TransportPhysics::read(file_contents)?;
+        AnyTable::TransportPhysics(data) => {
             let (table, insert) = TransportPhysics();
             tx.execute(table, ())?;
 
@@ -4348,8 +4196,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "UISoundLookups.dbc" => {
-            let data = ui_sound_lookups::UISoundLookups::read(file_contents)?;
+        AnyTable::UISoundLookups(data) => {
             let (table, insert) = UISoundLookups();
             tx.execute(table, ())?;
 
@@ -4361,8 +4208,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "UnitBlood.dbc" => {
-            let data = unit_blood::UnitBlood::read(file_contents)?;
+        AnyTable::UnitBlood(data) => {
             let (table, insert) = UnitBlood();
             tx.execute(table, ())?;
 
@@ -4381,8 +4227,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "UnitBloodLevels.dbc" => {
-            let data = unit_blood_levels::UnitBloodLevels::read(file_contents)?;
+        AnyTable::UnitBloodLevels(data) => {
             let (table, insert) = UnitBloodLevels();
             tx.execute(table, ())?;
 
@@ -4395,8 +4240,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "VideoHardware.dbc" => {
-            let data = video_hardware::VideoHardware::read(file_contents)?;
+        AnyTable::VideoHardware(data) => {
             let (table, insert) = VideoHardware();
             tx.execute(table, ())?;
 
@@ -4428,8 +4272,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "VocalUISounds.dbc" => {
-            let data = vocal_ui_sounds::VocalUISounds::read(file_contents)?;
+        AnyTable::VocalUISounds(data) => {
             let (table, insert) = VocalUISounds();
             tx.execute(table, ())?;
 
@@ -4445,8 +4288,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WMOAreaTable.dbc" => {
-            let data = wmo_area_table::WMOAreaTable::read(file_contents)?;
+        AnyTable::WMOAreaTable(data) => {
             let (table, insert) = WMOAreaTable();
             tx.execute(table, ())?;
 
@@ -4483,8 +4325,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WeaponImpactSounds.dbc" => {
-            let data = weapon_impact_sounds::WeaponImpactSounds::read(file_contents)?;
+        AnyTable::WeaponImpactSounds(data) => {
             let (table, insert) = WeaponImpactSounds();
             tx.execute(table, ())?;
 
@@ -4516,8 +4357,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WeaponSwingSounds2.dbc" => {
-            let data = weapon_swing_sounds2::WeaponSwingSounds2::read(file_contents)?;
+        AnyTable::WeaponSwingSounds2(data) => {
             let (table, insert) = WeaponSwingSounds2();
             tx.execute(table, ())?;
 
@@ -4530,8 +4370,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Weather.dbc" => {
-            let data = weather::Weather::read(file_contents)?;
+        AnyTable::Weather(data) => {
             let (table, insert) = Weather();
             tx.execute(table, ())?;
 
@@ -4547,8 +4386,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WorldMapArea.dbc" => {
-            let data = world_map_area::WorldMapArea::read(file_contents)?;
+        AnyTable::WorldMapArea(data) => {
             let (table, insert) = WorldMapArea();
             tx.execute(table, ())?;
 
@@ -4566,8 +4404,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WorldMapContinent.dbc" => {
-            let data = world_map_continent::WorldMapContinent::read(file_contents)?;
+        AnyTable::WorldMapContinent(data) => {
             let (table, insert) = WorldMapContinent();
             tx.execute(table, ())?;
 
@@ -4589,8 +4426,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WorldMapOverlay.dbc" => {
-            let data = world_map_overlay::WorldMapOverlay::read(file_contents)?;
+        AnyTable::WorldMapOverlay(data) => {
             let (table, insert) = WorldMapOverlay();
             tx.execute(table, ())?;
 
@@ -4616,8 +4452,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WorldMapTransforms.dbc" => {
-            let data = world_map_transforms::WorldMapTransforms::read(file_contents)?;
+        AnyTable::WorldMapTransforms(data) => {
             let (table, insert) = WorldMapTransforms();
             tx.execute(table, ())?;
 
@@ -4635,8 +4470,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WorldSafeLocs.dbc" => {
-            let data = world_safe_locs::WorldSafeLocs::read(file_contents)?;
+        AnyTable::WorldSafeLocs(data) => {
             let (table, insert) = WorldSafeLocs();
             tx.execute(table, ())?;
 
@@ -4667,8 +4501,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WorldStateUI.dbc" => {
-            let data = world_state_ui::WorldStateUI::read(file_contents)?;
+        AnyTable::WorldStateUI(data) => {
             let (table, insert) = WorldStateUI();
             tx.execute(table, ())?;
 
@@ -4740,8 +4573,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WorldStateZoneSounds.dbc" => {
-            let data = world_state_zone_sounds::WorldStateZoneSounds::read(file_contents)?;
+        AnyTable::WorldStateZoneSounds(data) => {
             let (table, insert) = WorldStateZoneSounds();
             tx.execute(table, ())?;
 
@@ -4758,8 +4590,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WowError_Strings.dbc" => {
-            let data = wow_error_strings::WowError_Strings::read(file_contents)?;
+        AnyTable::WowError_Strings(data) => {
             let (table, insert) = WowError_Strings();
             tx.execute(table, ())?;
 
@@ -4787,8 +4618,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ZoneIntroMusicTable.dbc" => {
-            let data = zone_intro_music_table::ZoneIntroMusicTable::read(file_contents)?;
+        AnyTable::ZoneIntroMusicTable(data) => {
             let (table, insert) = ZoneIntroMusicTable();
             tx.execute(table, ())?;
 
@@ -4802,8 +4632,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ZoneMusic.dbc" => {
-            let data = zone_music::ZoneMusic::read(file_contents)?;
+        AnyTable::ZoneMusic(data) => {
             let (table, insert) = ZoneMusic();
             tx.execute(table, ())?;
 
@@ -4820,8 +4649,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "gtChanceToMeleeCrit.dbc" => {
-            let data = gt_chance_to_melee_crit::gtChanceToMeleeCrit::read(file_contents)?;
+        AnyTable::gtChanceToMeleeCrit(data) => {
             let (table, insert) = gtChanceToMeleeCrit();
             tx.execute(table, ())?;
 
@@ -4831,8 +4659,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "gtChanceToMeleeCritBase.dbc" => {
-            let data = gt_chance_to_melee_crit_base::gtChanceToMeleeCritBase::read(file_contents)?;
+        AnyTable::gtChanceToMeleeCritBase(data) => {
             let (table, insert) = gtChanceToMeleeCritBase();
             tx.execute(table, ())?;
 
@@ -4842,8 +4669,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "gtChanceToSpellCrit.dbc" => {
-            let data = gt_chance_to_spell_crit::gtChanceToSpellCrit::read(file_contents)?;
+        AnyTable::gtChanceToSpellCrit(data) => {
             let (table, insert) = gtChanceToSpellCrit();
             tx.execute(table, ())?;
 
@@ -4853,8 +4679,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "gtChanceToSpellCritBase.dbc" => {
-            let data = gt_chance_to_spell_crit_base::gtChanceToSpellCritBase::read(file_contents)?;
+        AnyTable::gtChanceToSpellCritBase(data) => {
             let (table, insert) = gtChanceToSpellCritBase();
             tx.execute(table, ())?;
 
@@ -4864,8 +4689,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "gtCombatRatings.dbc" => {
-            let data = gt_combat_ratings::gtCombatRatings::read(file_contents)?;
+        AnyTable::gtCombatRatings(data) => {
             let (table, insert) = gtCombatRatings();
             tx.execute(table, ())?;
 
@@ -4875,8 +4699,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "gtNPCManaCostScaler.dbc" => {
-            let data = gt_npc_mana_cost_scaler::gtNPCManaCostScaler::read(file_contents)?;
+        AnyTable::gtNPCManaCostScaler(data) => {
             let (table, insert) = gtNPCManaCostScaler();
             tx.execute(table, ())?;
 
@@ -4886,8 +4709,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "gtOCTRegenHP.dbc" => {
-            let data = gt_oct_regen_hp::gtOCTRegenHP::read(file_contents)?;
+        AnyTable::gtOCTRegenHP(data) => {
             let (table, insert) = gtOCTRegenHP();
             tx.execute(table, ())?;
 
@@ -4897,8 +4719,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "gtOCTRegenMP.dbc" => {
-            let data = gt_oct_regen_mp::gtOCTRegenMP::read(file_contents)?;
+        AnyTable::gtOCTRegenMP(data) => {
             let (table, insert) = gtOCTRegenMP();
             tx.execute(table, ())?;
 
@@ -4908,8 +4729,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "gtRegenHPPerSpt.dbc" => {
-            let data = gt_regen_hp_per_spt::gtRegenHPPerSpt::read(file_contents)?;
+        AnyTable::gtRegenHPPerSpt(data) => {
             let (table, insert) = gtRegenHPPerSpt();
             tx.execute(table, ())?;
 
@@ -4919,8 +4739,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "gtRegenMPPerSpt.dbc" => {
-            let data = gt_regen_mp_per_spt::gtRegenMPPerSpt::read(file_contents)?;
+        AnyTable::gtRegenMPPerSpt(data) => {
             let (table, insert) = gtRegenMPPerSpt();
             tx.execute(table, ())?;
 
@@ -4930,7 +4749,6 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        v => return Err(SqliteError::FilenameNotFound { name: v.to_string() }),
     }
 
     tx.commit()?;
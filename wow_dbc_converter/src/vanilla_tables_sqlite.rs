@@ -6,9 +6,12 @@ use wow_dbc::vanilla_tables::*;
 pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_contents: &mut &[u8]) -> Result<(), SqliteError> {
     let tx = conn.transaction()?;
 
-    match file_name {
-        "AnimationData.dbc" => {
-            let data = animation_data::AnimationData::read(file_contents)?;
+    let Some(data) = AnyTable::read_by_filename(file_name, file_contents)? else {
+        return Err(SqliteError::FilenameNotFound { name: file_name.to_string() });
+    };
+
+    match data {
+        AnyTable::AnimationData(data) => {
             let (table, insert) = AnimationData();
             tx.execute(table, ())?;
 
@@ -24,8 +27,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "AreaPOI.dbc" => {
-            let data = area_poi::AreaPOI::read(file_contents)?;
+        AnyTable::AreaPOI(data) => {
             let (table, insert) = AreaPOI();
             tx.execute(table, ())?;
 
@@ -63,8 +65,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "AreaTable.dbc" => {
-            let data = area_table::AreaTable::read(file_contents)?;
+        AnyTable::AreaTable(data) => {
             let (table, insert) = AreaTable();
             tx.execute(table, ())?;
 
@@ -98,8 +99,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "AreaTrigger.dbc" => {
-            let data = area_trigger::AreaTrigger::read(file_contents)?;
+        AnyTable::AreaTrigger(data) => {
             let (table, insert) = AreaTrigger();
             tx.execute(table, ())?;
 
@@ -118,8 +118,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "AttackAnimKits.dbc" => {
-            let data = attack_anim_kits::AttackAnimKits::read(file_contents)?;
+        AnyTable::AttackAnimKits(data) => {
             let (table, insert) = AttackAnimKits();
             tx.execute(table, ())?;
 
@@ -133,8 +132,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "AttackAnimTypes.dbc" => {
-            let data = attack_anim_types::AttackAnimTypes::read(file_contents)?;
+        AnyTable::AttackAnimTypes(data) => {
             let (table, insert) = AttackAnimTypes();
             tx.execute(table, ())?;
 
@@ -145,8 +143,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "AuctionHouse.dbc" => {
-            let data = auction_house::AuctionHouse::read(file_contents)?;
+        AnyTable::AuctionHouse(data) => {
             let (table, insert) = AuctionHouse();
             tx.execute(table, ())?;
 
@@ -168,8 +165,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "BankBagSlotPrices.dbc" => {
-            let data = bank_bag_slot_prices::BankBagSlotPrices::read(file_contents)?;
+        AnyTable::BankBagSlotPrices(data) => {
             let (table, insert) = BankBagSlotPrices();
             tx.execute(table, ())?;
 
@@ -180,8 +176,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CameraShakes.dbc" => {
-            let data = camera_shakes::CameraShakes::read(file_contents)?;
+        AnyTable::CameraShakes(data) => {
             let (table, insert) = CameraShakes();
             tx.execute(table, ())?;
 
@@ -198,8 +193,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Cfg_Categories.dbc" => {
-            let data = cfg_categories::Cfg_Categories::read(file_contents)?;
+        AnyTable::Cfg_Categories(data) => {
             let (table, insert) = Cfg_Categories();
             tx.execute(table, ())?;
 
@@ -219,8 +213,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Cfg_Configs.dbc" => {
-            let data = cfg_configs::Cfg_Configs::read(file_contents)?;
+        AnyTable::Cfg_Configs(data) => {
             let (table, insert) = Cfg_Configs();
             tx.execute(table, ())?;
 
@@ -233,8 +226,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CharBaseInfo.dbc" => {
-            let data = char_base_info::CharBaseInfo::read(file_contents)?;
+        AnyTable::CharBaseInfo(data) => {
             let (table, insert) = CharBaseInfo();
             tx.execute(table, ())?;
 
@@ -245,8 +237,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CharHairGeosets.dbc" => {
-            let data = char_hair_geosets::CharHairGeosets::read(file_contents)?;
+        AnyTable::CharHairGeosets(data) => {
             let (table, insert) = CharHairGeosets();
             tx.execute(table, ())?;
 
@@ -261,8 +252,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CharHairTextures.dbc" => {
-            let data = char_hair_textures::CharHairTextures::read(file_contents)?;
+        AnyTable::CharHairTextures(data) => {
             let (table, insert) = CharHairTextures();
             tx.execute(table, ())?;
 
@@ -279,8 +269,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CharSections.dbc" => {
-            let data = char_sections::CharSections::read(file_contents)?;
+        AnyTable::CharSections(data) => {
             let (table, insert) = CharSections();
             tx.execute(table, ())?;
 
@@ -299,8 +288,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CharStartOutfit.dbc" => {
-            let data = char_start_outfit::CharStartOutfit::read(file_contents)?;
+        AnyTable::CharStartOutfit(data) => {
             let (table, insert) = CharStartOutfit();
             tx.execute(table, ())?;
 
@@ -350,8 +338,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CharVariations.dbc" => {
-            let data = char_variations::CharVariations::read(file_contents)?;
+        AnyTable::CharVariations(data) => {
             let (table, insert) = CharVariations();
             tx.execute(table, ())?;
 
@@ -366,8 +353,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CharacterCreateCameras.dbc" => {
-            let data = character_create_cameras::CharacterCreateCameras::read(file_contents)?;
+        AnyTable::CharacterCreateCameras(data) => {
             let (table, insert) = CharacterCreateCameras();
             tx.execute(table, ())?;
 
@@ -382,8 +368,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CharacterFacialHairStyles.dbc" => {
-            let data = character_facial_hair_styles::CharacterFacialHairStyles::read(file_contents)?;
+        AnyTable::CharacterFacialHairStyles(data) => {
             let (table, insert) = CharacterFacialHairStyles();
             tx.execute(table, ())?;
 
@@ -401,8 +386,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ChatChannels.dbc" => {
-            let data = chat_channels::ChatChannels::read(file_contents)?;
+        AnyTable::ChatChannels(data) => {
             let (table, insert) = ChatChannels();
             tx.execute(table, ())?;
 
@@ -432,8 +416,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ChatProfanity.dbc" => {
-            let data = chat_profanity::ChatProfanity::read(file_contents)?;
+        AnyTable::ChatProfanity(data) => {
             let (table, insert) = ChatProfanity();
             tx.execute(table, ())?;
 
@@ -444,8 +427,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ChrClasses.dbc" => {
-            let data = chr_classes::ChrClasses::read(file_contents)?;
+        AnyTable::ChrClasses(data) => {
             let (table, insert) = ChrClasses();
             tx.execute(table, ())?;
 
@@ -471,8 +453,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ChrRaces.dbc" => {
-            let data = chr_races::ChrRaces::read(file_contents)?;
+        AnyTable::ChrRaces(data) => {
             let (table, insert) = ChrRaces();
             tx.execute(table, ())?;
 
@@ -510,8 +491,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CinematicCamera.dbc" => {
-            let data = cinematic_camera::CinematicCamera::read(file_contents)?;
+        AnyTable::CinematicCamera(data) => {
             let (table, insert) = CinematicCamera();
             tx.execute(table, ())?;
 
@@ -527,8 +507,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CinematicSequences.dbc" => {
-            let data = cinematic_sequences::CinematicSequences::read(file_contents)?;
+        AnyTable::CinematicSequences(data) => {
             let (table, insert) = CinematicSequences();
             tx.execute(table, ())?;
 
@@ -547,8 +526,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CreatureDisplayInfo.dbc" => {
-            let data = creature_display_info::CreatureDisplayInfo::read(file_contents)?;
+        AnyTable::CreatureDisplayInfo(data) => {
             let (table, insert) = CreatureDisplayInfo();
             tx.execute(table, ())?;
 
@@ -569,8 +547,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CreatureDisplayInfoExtra.dbc" => {
-            let data = creature_display_info_extra::CreatureDisplayInfoExtra::read(file_contents)?;
+        AnyTable::CreatureDisplayInfoExtra(data) => {
             let (table, insert) = CreatureDisplayInfoExtra();
             tx.execute(table, ())?;
 
@@ -598,8 +575,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CreatureFamily.dbc" => {
-            let data = creature_family::CreatureFamily::read(file_contents)?;
+        AnyTable::CreatureFamily(data) => {
             let (table, insert) = CreatureFamily();
             tx.execute(table, ())?;
 
@@ -626,8 +602,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CreatureModelData.dbc" => {
-            let data = creature_model_data::CreatureModelData::read(file_contents)?;
+        AnyTable::CreatureModelData(data) => {
             let (table, insert) = CreatureModelData();
             tx.execute(table, ())?;
 
@@ -652,8 +627,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CreatureSoundData.dbc" => {
-            let data = creature_sound_data::CreatureSoundData::read(file_contents)?;
+        AnyTable::CreatureSoundData(data) => {
             let (table, insert) = CreatureSoundData();
             tx.execute(table, ())?;
 
@@ -692,8 +666,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CreatureSpellData.dbc" => {
-            let data = creature_spell_data::CreatureSpellData::read(file_contents)?;
+        AnyTable::CreatureSpellData(data) => {
             let (table, insert) = CreatureSpellData();
             tx.execute(table, ())?;
 
@@ -711,8 +684,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CreatureType.dbc" => {
-            let data = creature_type::CreatureType::read(file_contents)?;
+        AnyTable::CreatureType(data) => {
             let (table, insert) = CreatureType();
             tx.execute(table, ())?;
 
@@ -732,8 +704,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "DeathThudLookups.dbc" => {
-            let data = death_thud_lookups::DeathThudLookups::read(file_contents)?;
+        AnyTable::DeathThudLookups(data) => {
             let (table, insert) = DeathThudLookups();
             tx.execute(table, ())?;
 
@@ -747,8 +718,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "DurabilityCosts.dbc" => {
-            let data = durability_costs::DurabilityCosts::read(file_contents)?;
+        AnyTable::DurabilityCosts(data) => {
             let (table, insert) = DurabilityCosts();
             tx.execute(table, ())?;
 
@@ -787,8 +757,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "DurabilityQuality.dbc" => {
-            let data = durability_quality::DurabilityQuality::read(file_contents)?;
+        AnyTable::DurabilityQuality(data) => {
             let (table, insert) = DurabilityQuality();
             tx.execute(table, ())?;
 
@@ -799,8 +768,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Emotes.dbc" => {
-            let data = emotes::Emotes::read(file_contents)?;
+        AnyTable::Emotes(data) => {
             let (table, insert) = Emotes();
             tx.execute(table, ())?;
 
@@ -816,8 +784,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "EmotesText.dbc" => {
-            let data = emotes_text::EmotesText::read(file_contents)?;
+        AnyTable::EmotesText(data) => {
             let (table, insert) = EmotesText();
             tx.execute(table, ())?;
 
@@ -845,8 +812,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "EmotesTextData.dbc" => {
-            let data = emotes_text_data::EmotesTextData::read(file_contents)?;
+        AnyTable::EmotesTextData(data) => {
             let (table, insert) = EmotesTextData();
             tx.execute(table, ())?;
 
@@ -865,8 +831,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "EmotesTextSound.dbc" => {
-            let data = emotes_text_sound::EmotesTextSound::read(file_contents)?;
+        AnyTable::EmotesTextSound(data) => {
             let (table, insert) = EmotesTextSound();
             tx.execute(table, ())?;
 
@@ -880,8 +845,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "EnvironmentalDamage.dbc" => {
-            let data = environmental_damage::EnvironmentalDamage::read(file_contents)?;
+        AnyTable::EnvironmentalDamage(data) => {
             let (table, insert) = EnvironmentalDamage();
             tx.execute(table, ())?;
 
@@ -893,8 +857,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Exhaustion.dbc" => {
-            let data = exhaustion::Exhaustion::read(file_contents)?;
+        AnyTable::Exhaustion(data) => {
             let (table, insert) = Exhaustion();
             tx.execute(table, ())?;
 
@@ -918,8 +881,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Faction.dbc" => {
-            let data = faction::Faction::read(file_contents)?;
+        AnyTable::Faction(data) => {
             let (table, insert) = Faction();
             tx.execute(table, ())?;
 
@@ -965,8 +927,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "FactionGroup.dbc" => {
-            let data = faction_group::FactionGroup::read(file_contents)?;
+        AnyTable::FactionGroup(data) => {
             let (table, insert) = FactionGroup();
             tx.execute(table, ())?;
 
@@ -987,8 +948,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "FactionTemplate.dbc" => {
-            let data = faction_template::FactionTemplate::read(file_contents)?;
+        AnyTable::FactionTemplate(data) => {
             let (table, insert) = FactionTemplate();
             tx.execute(table, ())?;
 
@@ -1011,8 +971,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "FootprintTextures.dbc" => {
-            let data = footprint_textures::FootprintTextures::read(file_contents)?;
+        AnyTable::FootprintTextures(data) => {
             let (table, insert) = FootprintTextures();
             tx.execute(table, ())?;
 
@@ -1023,8 +982,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "FootstepTerrainLookup.dbc" => {
-            let data = footstep_terrain_lookup::FootstepTerrainLookup::read(file_contents)?;
+        AnyTable::FootstepTerrainLookup(data) => {
             let (table, insert) = FootstepTerrainLookup();
             tx.execute(table, ())?;
 
@@ -1038,8 +996,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GMSurveyCurrentSurvey.dbc" => {
-            let data = gm_survey_current_survey::GMSurveyCurrentSurvey::read(file_contents)?;
+        AnyTable::GMSurveyCurrentSurvey(data) => {
             let (table, insert) = GMSurveyCurrentSurvey();
             tx.execute(table, ())?;
 
@@ -1050,8 +1007,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GMSurveyQuestions.dbc" => {
-            let data = gm_survey_questions::GMSurveyQuestions::read(file_contents)?;
+        AnyTable::GMSurveyQuestions(data) => {
             let (table, insert) = GMSurveyQuestions();
             tx.execute(table, ())?;
 
@@ -1070,8 +1026,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GMSurveySurveys.dbc" => {
-            let data = gm_survey_surveys::GMSurveySurveys::read(file_contents)?;
+        AnyTable::GMSurveySurveys(data) => {
             let (table, insert) = GMSurveySurveys();
             tx.execute(table, ())?;
 
@@ -1091,8 +1046,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GMTicketCategory.dbc" => {
-            let data = gm_ticket_category::GMTicketCategory::read(file_contents)?;
+        AnyTable::GMTicketCategory(data) => {
             let (table, insert) = GMTicketCategory();
             tx.execute(table, ())?;
 
@@ -1111,8 +1065,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GameObjectArtKit.dbc" => {
-            let data = game_object_art_kit::GameObjectArtKit::read(file_contents)?;
+        AnyTable::GameObjectArtKit(data) => {
             let (table, insert) = GameObjectArtKit();
             tx.execute(table, ())?;
 
@@ -1129,8 +1082,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GameObjectDisplayInfo.dbc" => {
-            let data = game_object_display_info::GameObjectDisplayInfo::read(file_contents)?;
+        AnyTable::GameObjectDisplayInfo(data) => {
             let (table, insert) = GameObjectDisplayInfo();
             tx.execute(table, ())?;
 
@@ -1151,8 +1103,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GameTips.dbc" => {
-            let data = game_tips::GameTips::read(file_contents)?;
+        AnyTable::GameTips(data) => {
             let (table, insert) = GameTips();
             tx.execute(table, ())?;
 
@@ -1171,8 +1122,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GroundEffectDoodad.dbc" => {
-            let data = ground_effect_doodad::GroundEffectDoodad::read(file_contents)?;
+        AnyTable::GroundEffectDoodad(data) => {
             let (table, insert) = GroundEffectDoodad();
             tx.execute(table, ())?;
 
@@ -1184,8 +1134,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GroundEffectTexture.dbc" => {
-            let data = ground_effect_texture::GroundEffectTexture::read(file_contents)?;
+        AnyTable::GroundEffectTexture(data) => {
             let (table, insert) = GroundEffectTexture();
             tx.execute(table, ())?;
 
@@ -1201,8 +1150,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "HelmetGeosetVisData.dbc" => {
-            let data = helmet_geoset_vis_data::HelmetGeosetVisData::read(file_contents)?;
+        AnyTable::HelmetGeosetVisData(data) => {
             let (table, insert) = HelmetGeosetVisData();
             tx.execute(table, ())?;
 
@@ -1217,8 +1165,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemBagFamily.dbc" => {
-            let data = item_bag_family::ItemBagFamily::read(file_contents)?;
+        AnyTable::ItemBagFamily(data) => {
             let (table, insert) = ItemBagFamily();
             tx.execute(table, ())?;
 
@@ -1237,8 +1184,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemClass.dbc" => {
-            let data = item_class::ItemClass::read(file_contents)?;
+        AnyTable::ItemClass(data) => {
             let (table, insert) = ItemClass();
             tx.execute(table, ())?;
 
@@ -1259,8 +1205,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemDisplayInfo.dbc" => {
-            let data = item_display_info::ItemDisplayInfo::read(file_contents)?;
+        AnyTable::ItemDisplayInfo(data) => {
             let (table, insert) = ItemDisplayInfo();
             tx.execute(table, ())?;
 
@@ -1292,8 +1237,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemGroupSounds.dbc" => {
-            let data = item_group_sounds::ItemGroupSounds::read(file_contents)?;
+        AnyTable::ItemGroupSounds(data) => {
             let (table, insert) = ItemGroupSounds();
             tx.execute(table, ())?;
 
@@ -1307,8 +1251,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemPetFood.dbc" => {
-            let data = item_pet_food::ItemPetFood::read(file_contents)?;
+        AnyTable::ItemPetFood(data) => {
             let (table, insert) = ItemPetFood();
             tx.execute(table, ())?;
 
@@ -1327,8 +1270,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemRandomProperties.dbc" => {
-            let data = item_random_properties::ItemRandomProperties::read(file_contents)?;
+        AnyTable::ItemRandomProperties(data) => {
             let (table, insert) = ItemRandomProperties();
             tx.execute(table, ())?;
 
@@ -1353,8 +1295,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemSet.dbc" => {
-            let data = item_set::ItemSet::read(file_contents)?;
+        AnyTable::ItemSet(data) => {
             let (table, insert) = ItemSet();
             tx.execute(table, ())?;
 
@@ -1408,8 +1349,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemSubClass.dbc" => {
-            let data = item_sub_class::ItemSubClass::read(file_contents)?;
+        AnyTable::ItemSubClass(data) => {
             let (table, insert) = ItemSubClass();
             tx.execute(table, ())?;
 
@@ -1446,8 +1386,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemSubClassMask.dbc" => {
-            let data = item_sub_class_mask::ItemSubClassMask::read(file_contents)?;
+        AnyTable::ItemSubClassMask(data) => {
             let (table, insert) = ItemSubClassMask();
             tx.execute(table, ())?;
 
@@ -1467,8 +1406,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemVisualEffects.dbc" => {
-            let data = item_visual_effects::ItemVisualEffects::read(file_contents)?;
+        AnyTable::ItemVisualEffects(data) => {
             let (table, insert) = ItemVisualEffects();
             tx.execute(table, ())?;
 
@@ -1479,8 +1417,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemVisuals.dbc" => {
-            let data = item_visuals::ItemVisuals::read(file_contents)?;
+        AnyTable::ItemVisuals(data) => {
             let (table, insert) = ItemVisuals();
             tx.execute(table, ())?;
 
@@ -1495,8 +1432,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LFGDungeons.dbc" => {
-            let data = lfg_dungeons::LFGDungeons::read(file_contents)?;
+        AnyTable::LFGDungeons(data) => {
             let (table, insert) = LFGDungeons();
             tx.execute(table, ())?;
 
@@ -1519,8 +1455,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LanguageWords.dbc" => {
-            let data = language_words::LanguageWords::read(file_contents)?;
+        AnyTable::LanguageWords(data) => {
             let (table, insert) = LanguageWords();
             tx.execute(table, ())?;
 
@@ -1532,8 +1467,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Languages.dbc" => {
-            let data = languages::Languages::read(file_contents)?;
+        AnyTable::Languages(data) => {
             let (table, insert) = Languages();
             tx.execute(table, ())?;
 
@@ -1552,8 +1486,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Light.dbc" => {
-            let data = light::Light::read(file_contents)?;
+        AnyTable::Light(data) => {
             let (table, insert) = Light();
             tx.execute(table, ())?;
 
@@ -1574,8 +1507,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LightFloatBand.dbc" => {
-            let data = light_float_band::LightFloatBand::read(file_contents)?;
+        AnyTable::LightFloatBand(data) => {
             let (table, insert) = LightFloatBand();
             tx.execute(table, ())?;
 
@@ -1618,8 +1550,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LightIntBand.dbc" => {
-            let data = light_int_band::LightIntBand::read(file_contents)?;
+        AnyTable::LightIntBand(data) => {
             let (table, insert) = LightIntBand();
             tx.execute(table, ())?;
 
@@ -1662,8 +1593,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LightParams.dbc" => {
-            let data = light_params::LightParams::read(file_contents)?;
+        AnyTable::LightParams(data) => {
             let (table, insert) = LightParams();
             tx.execute(table, ())?;
 
@@ -1681,8 +1611,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LightSkybox.dbc" => {
-            let data = light_skybox::LightSkybox::read(file_contents)?;
+        AnyTable::LightSkybox(data) => {
             let (table, insert) = LightSkybox();
             tx.execute(table, ())?;
 
@@ -1693,8 +1622,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LiquidType.dbc" => {
-            let data = liquid_type::LiquidType::read(file_contents)?;
+        AnyTable::LiquidType(data) => {
             let (table, insert) = LiquidType();
             tx.execute(table, ())?;
 
@@ -1707,8 +1635,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LoadingScreenTaxiSplines.dbc" => {
-            let data = loading_screen_taxi_splines::LoadingScreenTaxiSplines::read(file_contents)?;
+        AnyTable::LoadingScreenTaxiSplines(data) => {
             let (table, insert) = LoadingScreenTaxiSplines();
             tx.execute(table, ())?;
 
@@ -1736,8 +1663,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LoadingScreens.dbc" => {
-            let data = loading_screens::LoadingScreens::read(file_contents)?;
+        AnyTable::LoadingScreens(data) => {
             let (table, insert) = LoadingScreens();
             tx.execute(table, ())?;
 
@@ -1749,8 +1675,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Lock.dbc" => {
-            let data = lock::Lock::read(file_contents)?;
+        AnyTable::Lock(data) => {
             let (table, insert) = Lock();
             tx.execute(table, ())?;
 
@@ -1792,8 +1717,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LockType.dbc" => {
-            let data = lock_type::LockType::read(file_contents)?;
+        AnyTable::LockType(data) => {
             let (table, insert) = LockType();
             tx.execute(table, ())?;
 
@@ -1831,8 +1755,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "MailTemplate.dbc" => {
-            let data = mail_template::MailTemplate::read(file_contents)?;
+        AnyTable::MailTemplate(data) => {
             let (table, insert) = MailTemplate();
             tx.execute(table, ())?;
 
@@ -1851,8 +1774,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Map.dbc" => {
-            let data = map::Map::read(file_contents)?;
+        AnyTable::Map(data) => {
             let (table, insert) = Map();
             tx.execute(table, ())?;
 
@@ -1903,8 +1825,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Material.dbc" => {
-            let data = material::Material::read(file_contents)?;
+        AnyTable::Material(data) => {
             let (table, insert) = Material();
             tx.execute(table, ())?;
 
@@ -1916,8 +1837,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "NPCSounds.dbc" => {
-            let data = npc_sounds::NPCSounds::read(file_contents)?;
+        AnyTable::NPCSounds(data) => {
             let (table, insert) = NPCSounds();
             tx.execute(table, ())?;
 
@@ -1931,8 +1851,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "NameGen.dbc" => {
-            let data = name_gen::NameGen::read(file_contents)?;
+        AnyTable::NameGen(data) => {
             let (table, insert) = NameGen();
             tx.execute(table, ())?;
 
@@ -1945,8 +1864,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "NamesProfanity.dbc" => {
-            let data = names_profanity::NamesProfanity::read(file_contents)?;
+        AnyTable::NamesProfanity(data) => {
             let (table, insert) = NamesProfanity();
             tx.execute(table, ())?;
 
@@ -1957,8 +1875,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "NamesReserved.dbc" => {
-            let data = names_reserved::NamesReserved::read(file_contents)?;
+        AnyTable::NamesReserved(data) => {
             let (table, insert) = NamesReserved();
             tx.execute(table, ())?;
 
@@ -1969,8 +1886,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Package.dbc" => {
-            let data = package::Package::read(file_contents)?;
+        AnyTable::Package(data) => {
             let (table, insert) = Package();
             tx.execute(table, ())?;
 
@@ -1991,8 +1907,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "PageTextMaterial.dbc" => {
-            let data = page_text_material::PageTextMaterial::read(file_contents)?;
+        AnyTable::PageTextMaterial(data) => {
             let (table, insert) = PageTextMaterial();
             tx.execute(table, ())?;
 
@@ -2003,8 +1918,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "PaperDollItemFrame.dbc" => {
-            let data = paper_doll_item_frame::PaperDollItemFrame::read(file_contents)?;
+        AnyTable::PaperDollItemFrame(data) => {
             let (table, insert) = PaperDollItemFrame();
             tx.execute(table, ())?;
 
@@ -2016,8 +1930,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "PetLoyalty.dbc" => {
-            let data = pet_loyalty::PetLoyalty::read(file_contents)?;
+        AnyTable::PetLoyalty(data) => {
             let (table, insert) = PetLoyalty();
             tx.execute(table, ())?;
 
@@ -2036,8 +1949,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "PetPersonality.dbc" => {
-            let data = pet_personality::PetPersonality::read(file_contents)?;
+        AnyTable::PetPersonality(data) => {
             let (table, insert) = PetPersonality();
             tx.execute(table, ())?;
 
@@ -2065,8 +1977,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "QuestInfo.dbc" => {
-            let data = quest_info::QuestInfo::read(file_contents)?;
+        AnyTable::QuestInfo(data) => {
             let (table, insert) = QuestInfo();
             tx.execute(table, ())?;
 
@@ -2085,8 +1996,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "QuestSort.dbc" => {
-            let data = quest_sort::QuestSort::read(file_contents)?;
+        AnyTable::QuestSort(data) => {
             let (table, insert) = QuestSort();
             tx.execute(table, ())?;
 
@@ -2105,8 +2015,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Resistances.dbc" => {
-            let data = resistances::Resistances::read(file_contents)?;
+        AnyTable::Resistances(data) => {
             let (table, insert) = Resistances();
             tx.execute(table, ())?;
 
@@ -2127,8 +2036,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ServerMessages.dbc" => {
-            let data = server_messages::ServerMessages::read(file_contents)?;
+        AnyTable::ServerMessages(data) => {
             let (table, insert) = ServerMessages();
             tx.execute(table, ())?;
 
@@ -2147,8 +2055,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SheatheSoundLookups.dbc" => {
-            let data = sheathe_sound_lookups::SheatheSoundLookups::read(file_contents)?;
+        AnyTable::SheatheSoundLookups(data) => {
             let (table, insert) = SheatheSoundLookups();
             tx.execute(table, ())?;
 
@@ -2164,8 +2071,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SkillCostsData.dbc" => {
-            let data = skill_costs_data::SkillCostsData::read(file_contents)?;
+        AnyTable::SkillCostsData(data) => {
             let (table, insert) = SkillCostsData();
             tx.execute(table, ())?;
 
@@ -2179,8 +2085,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SkillLine.dbc" => {
-            let data = skill_line::SkillLine::read(file_contents)?;
+        AnyTable::SkillLine(data) => {
             let (table, insert) = SkillLine();
             tx.execute(table, ())?;
 
@@ -2211,8 +2116,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SkillLineAbility.dbc" => {
-            let data = skill_line_ability::SkillLineAbility::read(file_contents)?;
+        AnyTable::SkillLineAbility(data) => {
             let (table, insert) = SkillLineAbility();
             tx.execute(table, ())?;
 
@@ -2236,8 +2140,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SkillLineCategory.dbc" => {
-            let data = skill_line_category::SkillLineCategory::read(file_contents)?;
+        AnyTable::SkillLineCategory(data) => {
             let (table, insert) = SkillLineCategory();
             tx.execute(table, ())?;
 
@@ -2257,8 +2160,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SkillRaceClassInfo.dbc" => {
-            let data = skill_race_class_info::SkillRaceClassInfo::read(file_contents)?;
+        AnyTable::SkillRaceClassInfo(data) => {
             let (table, insert) = SkillRaceClassInfo();
             tx.execute(table, ())?;
 
@@ -2275,8 +2177,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SkillTiers.dbc" => {
-            let data = skill_tiers::SkillTiers::read(file_contents)?;
+        AnyTable::SkillTiers(data) => {
             let (table, insert) = SkillTiers();
             tx.execute(table, ())?;
 
@@ -2318,8 +2219,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SoundAmbience.dbc" => {
-            let data = sound_ambience::SoundAmbience::read(file_contents)?;
+        AnyTable::SoundAmbience(data) => {
             let (table, insert) = SoundAmbience();
             tx.execute(table, ())?;
 
@@ -2331,8 +2231,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SoundCharacterMacroLines.dbc" => {
-            let data = sound_character_macro_lines::SoundCharacterMacroLines::read(file_contents)?;
+        AnyTable::SoundCharacterMacroLines(data) => {
             let (table, insert) = SoundCharacterMacroLines();
             tx.execute(table, ())?;
 
@@ -2346,8 +2245,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SoundEntries.dbc" => {
-            let data = sound_entries::SoundEntries::read(file_contents)?;
+        AnyTable::SoundEntries(data) => {
             let (table, insert) = SoundEntries();
             tx.execute(table, ())?;
 
@@ -2385,8 +2283,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SoundProviderPreferences.dbc" => {
-            let data = sound_provider_preferences::SoundProviderPreferences::read(file_contents)?;
+        AnyTable::SoundProviderPreferences(data) => {
             let (table, insert) = SoundProviderPreferences();
             tx.execute(table, ())?;
 
@@ -2419,8 +2316,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SoundSamplePreferences.dbc" => {
-            let data = sound_sample_preferences::SoundSamplePreferences::read(file_contents)?;
+        AnyTable::SoundSamplePreferences(data) => {
             let (table, insert) = SoundSamplePreferences();
             tx.execute(table, ())?;
 
@@ -2446,8 +2342,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SoundWaterType.dbc" => {
-            let data = sound_water_type::SoundWaterType::read(file_contents)?;
+        AnyTable::SoundWaterType(data) => {
             let (table, insert) = SoundWaterType();
             tx.execute(table, ())?;
 
@@ -2460,8 +2355,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpamMessages.dbc" => {
-            let data = spam_messages::SpamMessages::read(file_contents)?;
+        AnyTable::SpamMessages(data) => {
             let (table, insert) = SpamMessages();
             tx.execute(table, ())?;
 
@@ -2472,8 +2366,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Spell.dbc" => {
-            let data = spell::Spell::read(file_contents)?;
+        AnyTable::Spell(data) => {
             let (table, insert) = Spell();
             tx.execute(table, ())?;
 
@@ -2655,8 +2548,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellAuraNames.dbc" => {
-            let data = spell_aura_names::SpellAuraNames::read(file_contents)?;
+        AnyTable::SpellAuraNames(data) => {
             let (table, insert) = SpellAuraNames();
             tx.execute(table, ())?;
 
@@ -2677,8 +2569,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellCastTimes.dbc" => {
-            let data = spell_cast_times::SpellCastTimes::read(file_contents)?;
+        AnyTable::SpellCastTimes(data) => {
             let (table, insert) = SpellCastTimes();
             tx.execute(table, ())?;
 
@@ -2691,8 +2582,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellCategory.dbc" => {
-            let data = spell_category::SpellCategory::read(file_contents)?;
+        AnyTable::SpellCategory(data) => {
             let (table, insert) = SpellCategory();
             tx.execute(table, ())?;
 
@@ -2703,8 +2593,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellChainEffects.dbc" => {
-            let data = spell_chain_effects::SpellChainEffects::read(file_contents)?;
+        AnyTable::SpellChainEffects(data) => {
             let (table, insert) = SpellChainEffects();
             tx.execute(table, ())?;
 
@@ -2721,8 +2610,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellDispelType.dbc" => {
-            let data = spell_dispel_type::SpellDispelType::read(file_contents)?;
+        AnyTable::SpellDispelType(data) => {
             let (table, insert) = SpellDispelType();
             tx.execute(table, ())?;
 
@@ -2743,8 +2631,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellDuration.dbc" => {
-            let data = spell_duration::SpellDuration::read(file_contents)?;
+        AnyTable::SpellDuration(data) => {
             let (table, insert) = SpellDuration();
             tx.execute(table, ())?;
 
@@ -2757,8 +2644,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellEffectCameraShakes.dbc" => {
-            let data = spell_effect_camera_shakes::SpellEffectCameraShakes::read(file_contents)?;
+        AnyTable::SpellEffectCameraShakes(data) => {
             let (table, insert) = SpellEffectCameraShakes();
             tx.execute(table, ())?;
 
@@ -2771,8 +2657,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellEffectNames.dbc" => {
-            let data = spell_effect_names::SpellEffectNames::read(file_contents)?;
+        AnyTable::SpellEffectNames(data) => {
             let (table, insert) = SpellEffectNames();
             tx.execute(table, ())?;
 
@@ -2791,8 +2676,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellFocusObject.dbc" => {
-            let data = spell_focus_object::SpellFocusObject::read(file_contents)?;
+        AnyTable::SpellFocusObject(data) => {
             let (table, insert) = SpellFocusObject();
             tx.execute(table, ())?;
 
@@ -2811,8 +2695,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellIcon.dbc" => {
-            let data = spell_icon::SpellIcon::read(file_contents)?;
+        AnyTable::SpellIcon(data) => {
             let (table, insert) = SpellIcon();
             tx.execute(table, ())?;
 
@@ -2823,8 +2706,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellItemEnchantment.dbc" => {
-            let data = spell_item_enchantment::SpellItemEnchantment::read(file_contents)?;
+        AnyTable::SpellItemEnchantment(data) => {
             let (table, insert) = SpellItemEnchantment();
             tx.execute(table, ())?;
 
@@ -2857,8 +2739,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellMechanic.dbc" => {
-            let data = spell_mechanic::SpellMechanic::read(file_contents)?;
+        AnyTable::SpellMechanic(data) => {
             let (table, insert) = SpellMechanic();
             tx.execute(table, ())?;
 
@@ -2877,8 +2758,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellRadius.dbc" => {
-            let data = spell_radius::SpellRadius::read(file_contents)?;
+        AnyTable::SpellRadius(data) => {
             let (table, insert) = SpellRadius();
             tx.execute(table, ())?;
 
@@ -2891,8 +2771,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellRange.dbc" => {
-            let data = spell_range::SpellRange::read(file_contents)?;
+        AnyTable::SpellRange(data) => {
             let (table, insert) = SpellRange();
             tx.execute(table, ())?;
 
@@ -2923,8 +2802,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellShapeshiftForm.dbc" => {
-            let data = spell_shapeshift_form::SpellShapeshiftForm::read(file_contents)?;
+        AnyTable::SpellShapeshiftForm(data) => {
             let (table, insert) = SpellShapeshiftForm();
             tx.execute(table, ())?;
 
@@ -2947,8 +2825,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellVisual.dbc" => {
-            let data = spell_visual::SpellVisual::read(file_contents)?;
+        AnyTable::SpellVisual(data) => {
             let (table, insert) = SpellVisual();
             tx.execute(table, ())?;
 
@@ -2973,8 +2850,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellVisualEffectName.dbc" => {
-            let data = spell_visual_effect_name::SpellVisualEffectName::read(file_contents)?;
+        AnyTable::SpellVisualEffectName(data) => {
             let (table, insert) = SpellVisualEffectName();
             tx.execute(table, ())?;
 
@@ -2988,8 +2864,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellVisualKit.dbc" => {
-            let data = spell_visual_kit::SpellVisualKit::read(file_contents)?;
+        AnyTable::SpellVisualKit(data) => {
             let (table, insert) = SpellVisualKit();
             tx.execute(table, ())?;
 
@@ -3033,8 +2908,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellVisualPrecastTransitions.dbc" => {
-            let data = spell_visual_precast_transitions::SpellVisualPrecastTransitions::read(file_contents)?;
+        AnyTable::SpellVisualPrecastTransitions(data) => {
             let (table, insert) = SpellVisualPrecastTransitions();
             tx.execute(table, ())?;
 
@@ -3046,8 +2920,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "StableSlotPrices.dbc" => {
-            let data = stable_slot_prices::StableSlotPrices::read(file_contents)?;
+        AnyTable::StableSlotPrices(data) => {
             let (table, insert) = StableSlotPrices();
             tx.execute(table, ())?;
 
@@ -3058,8 +2931,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Startup_Strings.dbc" => {
-            let data = startup_strings::Startup_Strings::read(file_contents)?;
+        AnyTable::Startup_Strings(data) => {
             let (table, insert) = Startup_Strings();
             tx.execute(table, ())?;
 
@@ -3079,8 +2951,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Stationery.dbc" => {
-            let data = stationery::Stationery::read(file_contents)?;
+        AnyTable::Stationery(data) => {
             let (table, insert) = Stationery();
             tx.execute(table, ())?;
 
@@ -3093,8 +2964,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "StringLookups.dbc" => {
-            let data = string_lookups::StringLookups::read(file_contents)?;
+        AnyTable::StringLookups(data) => {
             let (table, insert) = StringLookups();
             tx.execute(table, ())?;
 
@@ -3105,8 +2975,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Talent.dbc" => {
-            let data = talent::Talent::read(file_contents)?;
+        AnyTable::Talent(data) => {
             let (table, insert) = Talent();
             tx.execute(table, ())?;
 
@@ -3136,8 +3005,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TalentTab.dbc" => {
-            let data = talent_tab::TalentTab::read(file_contents)?;
+        AnyTable::TalentTab(data) => {
             let (table, insert) = TalentTab();
             tx.execute(table, ())?;
 
@@ -3161,8 +3029,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TaxiNodes.dbc" => {
-            let data = taxi_nodes::TaxiNodes::read(file_contents)?;
+        AnyTable::TaxiNodes(data) => {
             let (table, insert) = TaxiNodes();
             tx.execute(table, ())?;
 
@@ -3187,8 +3054,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TaxiPath.dbc" => {
-            let data = taxi_path::TaxiPath::read(file_contents)?;
+        AnyTable::TaxiPath(data) => {
             let (table, insert) = TaxiPath();
             tx.execute(table, ())?;
 
@@ -3201,8 +3067,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TaxiPathNode.dbc" => {
-            let data = taxi_path_node::TaxiPathNode::read(file_contents)?;
+        AnyTable::TaxiPathNode(data) => {
             let (table, insert) = TaxiPathNode();
             tx.execute(table, ())?;
 
@@ -3220,8 +3085,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TerrainType.dbc" => {
-            let data = terrain_type::TerrainType::read(file_contents)?;
+        AnyTable::TerrainType(data) => {
             let (table, insert) = TerrainType();
             tx.execute(table, ())?;
 
@@ -3236,8 +3100,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TerrainTypeSounds.dbc" => {
-            let data = terrain_type_sounds::TerrainTypeSounds::read(file_contents)?;
+        AnyTable::TerrainTypeSounds(data) => {
             let (table, insert) = TerrainTypeSounds();
             tx.execute(table, ())?;
 
@@ -3247,8 +3110,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TransportAnimation.dbc" => {
-            let data = transport_animation::TransportAnimation::read(file_contents)?;
+        AnyTable::TransportAnimation(data) => {
             let (table, insert) = TransportAnimation();
             tx.execute(table, ())?;
 
@@ -3264,8 +3126,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "UISoundLookups.dbc" => {
-            let data = ui_sound_lookups::UISoundLookups::read(file_contents)?;
+        AnyTable::UISoundLookups(data) => {
             let (table, insert) = UISoundLookups();
             tx.execute(table, ())?;
 
@@ -3277,8 +3138,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "UnitBlood.dbc" => {
-            let data = unit_blood::UnitBlood::read(file_contents)?;
+        AnyTable::UnitBlood(data) => {
             let (table, insert) = UnitBlood();
             tx.execute(table, ())?;
 
@@ -3297,8 +3157,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "UnitBloodLevels.dbc" => {
-            let data = unit_blood_levels::UnitBloodLevels::read(file_contents)?;
+        AnyTable::UnitBloodLevels(data) => {
             let (table, insert) = UnitBloodLevels();
             tx.execute(table, ())?;
 
@@ -3311,8 +3170,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "VideoHardware.dbc" => {
-            let data = video_hardware::VideoHardware::read(file_contents)?;
+        AnyTable::VideoHardware(data) => {
             let (table, insert) = VideoHardware();
             tx.execute(table, ())?;
 
@@ -3343,8 +3201,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "VocalUISounds.dbc" => {
-            let data = vocal_ui_sounds::VocalUISounds::read(file_contents)?;
+        AnyTable::VocalUISounds(data) => {
             let (table, insert) = VocalUISounds();
             tx.execute(table, ())?;
 
@@ -3360,8 +3217,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WMOAreaTable.dbc" => {
-            let data = wmo_area_table::WMOAreaTable::read(file_contents)?;
+        AnyTable::WMOAreaTable(data) => {
             let (table, insert) = WMOAreaTable();
             tx.execute(table, ())?;
 
@@ -3390,8 +3246,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WeaponImpactSounds.dbc" => {
-            let data = weapon_impact_sounds::WeaponImpactSounds::read(file_contents)?;
+        AnyTable::WeaponImpactSounds(data) => {
             let (table, insert) = WeaponImpactSounds();
             tx.execute(table, ())?;
 
@@ -3423,8 +3278,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WeaponSwingSounds2.dbc" => {
-            let data = weapon_swing_sounds2::WeaponSwingSounds2::read(file_contents)?;
+        AnyTable::WeaponSwingSounds2(data) => {
             let (table, insert) = WeaponSwingSounds2();
             tx.execute(table, ())?;
 
@@ -3437,8 +3291,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WorldMapArea.dbc" => {
-            let data = world_map_area::WorldMapArea::read(file_contents)?;
+        AnyTable::WorldMapArea(data) => {
             let (table, insert) = WorldMapArea();
             tx.execute(table, ())?;
 
@@ -3455,8 +3308,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WorldMapContinent.dbc" => {
-            let data = world_map_continent::WorldMapContinent::read(file_contents)?;
+        AnyTable::WorldMapContinent(data) => {
             let (table, insert) = WorldMapContinent();
             tx.execute(table, ())?;
 
@@ -3478,8 +3330,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WorldMapOverlay.dbc" => {
-            let data = world_map_overlay::WorldMapOverlay::read(file_contents)?;
+        AnyTable::WorldMapOverlay(data) => {
             let (table, insert) = WorldMapOverlay();
             tx.execute(table, ())?;
 
@@ -3505,8 +3356,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WorldSafeLocs.dbc" => {
-            let data = world_safe_locs::WorldSafeLocs::read(file_contents)?;
+        AnyTable::WorldSafeLocs(data) => {
             let (table, insert) = WorldSafeLocs();
             tx.execute(table, ())?;
 
@@ -3529,8 +3379,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WorldStateUI.dbc" => {
-            let data = world_state_ui::WorldStateUI::read(file_contents)?;
+        AnyTable::WorldStateUI(data) => {
             let (table, insert) = WorldStateUI();
             tx.execute(table, ())?;
 
@@ -3578,8 +3427,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WowError_Strings.dbc" => {
-            let data = wow_error_strings::WowError_Strings::read(file_contents)?;
+        AnyTable::WowError_Strings(data) => {
             let (table, insert) = WowError_Strings();
             tx.execute(table, ())?;
 
@@ -3599,8 +3447,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ZoneIntroMusicTable.dbc" => {
-            let data = zone_intro_music_table::ZoneIntroMusicTable::read(file_contents)?;
+        AnyTable::ZoneIntroMusicTable(data) => {
             let (table, insert) = ZoneIntroMusicTable();
             tx.execute(table, ())?;
 
@@ -3614,8 +3461,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ZoneMusic.dbc" => {
-            let data = zone_music::ZoneMusic::read(file_contents)?;
+        AnyTable::ZoneMusic(data) => {
             let (table, insert) = ZoneMusic();
             tx.execute(table, ())?;
 
@@ -3632,7 +3478,6 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        v => return Err(SqliteError::FilenameNotFound { name: v.to_string() }),
     }
 
     tx.commit()?;
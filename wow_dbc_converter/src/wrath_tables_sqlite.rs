@@ -6,9 +6,12 @@ use wow_dbc::wrath_tables::*;
 pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_contents: &mut &[u8]) -> Result<(), SqliteError> {
     let tx = conn.transaction()?;
 
-    match file_name {
-        "Achievement.dbc" => {
-            let data = achievement::Achievement::read(file_contents)?;
+    let Some(data) = AnyTable::read_by_filename(file_name, file_contents)? else {
+        return Err(SqliteError::FilenameNotFound { name: file_name.to_string() });
+    };
+
+    match data {
+        AnyTable::Achievement(data) => {
             let (table, insert) = Achievement();
             tx.execute(table, ())?;
 
@@ -79,8 +82,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Achievement_Category.dbc" => {
-            let data = achievement_category::Achievement_Category::read(file_contents)?;
+        AnyTable::Achievement_Category(data) => {
             let (table, insert) = Achievement_Category();
             tx.execute(table, ())?;
 
@@ -109,8 +111,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Achievement_Criteria.dbc" => {
-            let data = achievement_criteria::Achievement_Criteria::read(file_contents)?;
+        AnyTable::Achievement_Criteria(data) => {
             let (table, insert) = Achievement_Criteria();
             tx.execute(table, ())?;
 
@@ -150,8 +151,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "AnimationData.dbc" => {
-            let data = animation_data::AnimationData::read(file_contents)?;
+        AnyTable::AnimationData(data) => {
             let (table, insert) = AnimationData();
             tx.execute(table, ())?;
 
@@ -168,8 +168,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "AreaGroup.dbc" => {
-            let data = area_group::AreaGroup::read(file_contents)?;
+        AnyTable::AreaGroup(data) => {
             let (table, insert) = AreaGroup();
             tx.execute(table, ())?;
 
@@ -186,8 +185,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "AreaPOI.dbc" => {
-            let data = area_poi::AreaPOI::read(file_contents)?;
+        AnyTable::AreaPOI(data) => {
             let (table, insert) = AreaPOI();
             tx.execute(table, ())?;
 
@@ -250,8 +248,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "AreaTable.dbc" => {
-            let data = area_table::AreaTable::read(file_contents)?;
+        AnyTable::AreaTable(data) => {
             let (table, insert) = AreaTable();
             tx.execute(table, ())?;
 
@@ -296,8 +293,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "AreaTrigger.dbc" => {
-            let data = area_trigger::AreaTrigger::read(file_contents)?;
+        AnyTable::AreaTrigger(data) => {
             let (table, insert) = AreaTrigger();
             tx.execute(table, ())?;
 
@@ -316,8 +312,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "AttackAnimKits.dbc" => {
-            let data = attack_anim_kits::AttackAnimKits::read(file_contents)?;
+        AnyTable::AttackAnimKits(data) => {
             let (table, insert) = AttackAnimKits();
             tx.execute(table, ())?;
 
@@ -331,8 +326,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "AttackAnimTypes.dbc" => {
-            let data = attack_anim_types::AttackAnimTypes::read(file_contents)?;
+        AnyTable::AttackAnimTypes(data) => {
             let (table, insert) = AttackAnimTypes();
             tx.execute(table, ())?;
 
@@ -343,8 +337,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "AuctionHouse.dbc" => {
-            let data = auction_house::AuctionHouse::read(file_contents)?;
+        AnyTable::AuctionHouse(data) => {
             let (table, insert) = AuctionHouse();
             tx.execute(table, ())?;
 
@@ -374,8 +367,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "BankBagSlotPrices.dbc" => {
-            let data = bank_bag_slot_prices::BankBagSlotPrices::read(file_contents)?;
+        AnyTable::BankBagSlotPrices(data) => {
             let (table, insert) = BankBagSlotPrices();
             tx.execute(table, ())?;
 
@@ -386,8 +378,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "BannedAddOns.dbc" => {
-            let data = banned_add_ons::BannedAddOns::read(file_contents)?;
+        AnyTable::BannedAddOns(data) => {
             let (table, insert) = BannedAddOns();
             tx.execute(table, ())?;
 
@@ -407,8 +398,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "BarberShopStyle.dbc" => {
-            let data = barber_shop_style::BarberShopStyle::read(file_contents)?;
+        AnyTable::BarberShopStyle(data) => {
             let (table, insert) = BarberShopStyle();
             tx.execute(table, ())?;
 
@@ -457,8 +447,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "BattlemasterList.dbc" => {
-            let data = battlemaster_list::BattlemasterList::read(file_contents)?;
+        AnyTable::BattlemasterList(data) => {
             let (table, insert) = BattlemasterList();
             tx.execute(table, ())?;
 
@@ -499,8 +488,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CameraShakes.dbc" => {
-            let data = camera_shakes::CameraShakes::read(file_contents)?;
+        AnyTable::CameraShakes(data) => {
             let (table, insert) = CameraShakes();
             tx.execute(table, ())?;
 
@@ -517,8 +505,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Cfg_Categories.dbc" => {
-            let data = cfg_categories::Cfg_Categories::read(file_contents)?;
+        AnyTable::Cfg_Categories(data) => {
             let (table, insert) = Cfg_Categories();
             tx.execute(table, ())?;
 
@@ -548,8 +535,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Cfg_Configs.dbc" => {
-            let data = cfg_configs::Cfg_Configs::read(file_contents)?;
+        AnyTable::Cfg_Configs(data) => {
             let (table, insert) = Cfg_Configs();
             tx.execute(table, ())?;
 
@@ -562,8 +548,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CharBaseInfo.dbc" => {
-            let data = char_base_info::CharBaseInfo::read(file_contents)?;
+        AnyTable::CharBaseInfo(data) => {
             let (table, insert) = CharBaseInfo();
             tx.execute(table, ())?;
 
@@ -574,8 +559,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CharHairGeosets.dbc" => {
-            let data = char_hair_geosets::CharHairGeosets::read(file_contents)?;
+        AnyTable::CharHairGeosets(data) => {
             let (table, insert) = CharHairGeosets();
             tx.execute(table, ())?;
 
@@ -590,8 +574,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CharHairTextures.dbc" => {
-            let data = char_hair_textures::CharHairTextures::read(file_contents)?;
+        AnyTable::CharHairTextures(data) => {
             let (table, insert) = CharHairTextures();
             tx.execute(table, ())?;
 
@@ -608,8 +591,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CharSections.dbc" => {
-            let data = char_sections::CharSections::read(file_contents)?;
+        AnyTable::CharSections(data) => {
             let (table, insert) = CharSections();
             tx.execute(table, ())?;
 
@@ -628,8 +610,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CharStartOutfit.dbc" => {
-            let data = char_start_outfit::CharStartOutfit::read(file_contents)?;
+        AnyTable::CharStartOutfit(data) => {
             let (table, insert) = CharStartOutfit();
             tx.execute(table, ())?;
 
@@ -715,8 +696,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CharTitles.dbc" => {
-            let data = char_titles::CharTitles::read(file_contents)?;
+        AnyTable::CharTitles(data) => {
             let (table, insert) = CharTitles();
             tx.execute(table, ())?;
 
@@ -762,8 +742,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CharVariations.dbc" => {
-            let data = char_variations::CharVariations::read(file_contents)?;
+        AnyTable::CharVariations(data) => {
             let (table, insert) = CharVariations();
             tx.execute(table, ())?;
 
@@ -778,8 +757,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CharacterFacialHairStyles.dbc" => {
-            let data = character_facial_hair_styles::CharacterFacialHairStyles::read(file_contents)?;
+        AnyTable::CharacterFacialHairStyles(data) => {
             let (table, insert) = CharacterFacialHairStyles();
             tx.execute(table, ())?;
 
@@ -796,8 +774,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ChatChannels.dbc" => {
-            let data = chat_channels::ChatChannels::read(file_contents)?;
+        AnyTable::ChatChannels(data) => {
             let (table, insert) = ChatChannels();
             tx.execute(table, ())?;
 
@@ -843,8 +820,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ChatProfanity.dbc" => {
-            let data = chat_profanity::ChatProfanity::read(file_contents)?;
+        AnyTable::ChatProfanity(data) => {
             let (table, insert) = ChatProfanity();
             tx.execute(table, ())?;
 
@@ -856,8 +832,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ChrClasses.dbc" => {
-            let data = chr_classes::ChrClasses::read(file_contents)?;
+        AnyTable::ChrClasses(data) => {
             let (table, insert) = ChrClasses();
             tx.execute(table, ())?;
 
@@ -926,8 +901,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ChrRaces.dbc" => {
-            let data = chr_races::ChrRaces::read(file_contents)?;
+        AnyTable::ChrRaces(data) => {
             let (table, insert) = ChrRaces();
             tx.execute(table, ())?;
 
@@ -1005,8 +979,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CinematicCamera.dbc" => {
-            let data = cinematic_camera::CinematicCamera::read(file_contents)?;
+        AnyTable::CinematicCamera(data) => {
             let (table, insert) = CinematicCamera();
             tx.execute(table, ())?;
 
@@ -1022,8 +995,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CinematicSequences.dbc" => {
-            let data = cinematic_sequences::CinematicSequences::read(file_contents)?;
+        AnyTable::CinematicSequences(data) => {
             let (table, insert) = CinematicSequences();
             tx.execute(table, ())?;
 
@@ -1042,8 +1014,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CreatureDisplayInfo.dbc" => {
-            let data = creature_display_info::CreatureDisplayInfo::read(file_contents)?;
+        AnyTable::CreatureDisplayInfo(data) => {
             let (table, insert) = CreatureDisplayInfo();
             tx.execute(table, ())?;
 
@@ -1068,8 +1039,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CreatureDisplayInfoExtra.dbc" => {
-            let data = creature_display_info_extra::CreatureDisplayInfoExtra::read(file_contents)?;
+        AnyTable::CreatureDisplayInfoExtra(data) => {
             let (table, insert) = CreatureDisplayInfoExtra();
             tx.execute(table, ())?;
 
@@ -1099,8 +1069,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CreatureFamily.dbc" => {
-            let data = creature_family::CreatureFamily::read(file_contents)?;
+        AnyTable::CreatureFamily(data) => {
             let (table, insert) = CreatureFamily();
             tx.execute(table, ())?;
 
@@ -1137,8 +1106,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CreatureModelData.dbc" => {
-            let data = creature_model_data::CreatureModelData::read(file_contents)?;
+        AnyTable::CreatureModelData(data) => {
             let (table, insert) = CreatureModelData();
             tx.execute(table, ())?;
 
@@ -1175,8 +1143,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CreatureMovementInfo.dbc" => {
-            let data = creature_movement_info::CreatureMovementInfo::read(file_contents)?;
+        AnyTable::CreatureMovementInfo(data) => {
             let (table, insert) = CreatureMovementInfo();
             tx.execute(table, ())?;
 
@@ -1187,8 +1154,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CreatureSoundData.dbc" => {
-            let data = creature_sound_data::CreatureSoundData::read(file_contents)?;
+        AnyTable::CreatureSoundData(data) => {
             let (table, insert) = CreatureSoundData();
             tx.execute(table, ())?;
 
@@ -1235,8 +1201,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CreatureSpellData.dbc" => {
-            let data = creature_spell_data::CreatureSpellData::read(file_contents)?;
+        AnyTable::CreatureSpellData(data) => {
             let (table, insert) = CreatureSpellData();
             tx.execute(table, ())?;
 
@@ -1254,8 +1219,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CreatureType.dbc" => {
-            let data = creature_type::CreatureType::read(file_contents)?;
+        AnyTable::CreatureType(data) => {
             let (table, insert) = CreatureType();
             tx.execute(table, ())?;
 
@@ -1283,8 +1247,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CurrencyCategory.dbc" => {
-            let data = currency_category::CurrencyCategory::read(file_contents)?;
+        AnyTable::CurrencyCategory(data) => {
             let (table, insert) = CurrencyCategory();
             tx.execute(table, ())?;
 
@@ -1312,8 +1275,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "CurrencyTypes.dbc" => {
-            let data = currency_types::CurrencyTypes::read(file_contents)?;
+        AnyTable::CurrencyTypes(data) => {
             let (table, insert) = CurrencyTypes();
             tx.execute(table, ())?;
 
@@ -1326,8 +1288,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "DanceMoves.dbc" => {
-            let data = dance_moves::DanceMoves::read(file_contents)?;
+        AnyTable::DanceMoves(data) => {
             let (table, insert) = DanceMoves();
             tx.execute(table, ())?;
 
@@ -1360,8 +1321,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "DeathThudLookups.dbc" => {
-            let data = death_thud_lookups::DeathThudLookups::read(file_contents)?;
+        AnyTable::DeathThudLookups(data) => {
             let (table, insert) = DeathThudLookups();
             tx.execute(table, ())?;
 
@@ -1375,8 +1335,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "DeclinedWord.dbc" => {
-            let data = declined_word::DeclinedWord::read(file_contents)?;
+        AnyTable::DeclinedWord(data) => {
             let (table, insert) = DeclinedWord();
             tx.execute(table, ())?;
 
@@ -1387,8 +1346,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "DeclinedWordCases.dbc" => {
-            let data = declined_word_cases::DeclinedWordCases::read(file_contents)?;
+        AnyTable::DeclinedWordCases(data) => {
             let (table, insert) = DeclinedWordCases();
             tx.execute(table, ())?;
 
@@ -1401,8 +1359,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "DestructibleModelData.dbc" => {
-            let data = destructible_model_data::DestructibleModelData::read(file_contents)?;
+        AnyTable::DestructibleModelData(data) => {
             let (table, insert) = DestructibleModelData();
             tx.execute(table, ())?;
 
@@ -1430,8 +1387,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "DungeonEncounter.dbc" => {
-            let data = dungeon_encounter::DungeonEncounter::read(file_contents)?;
+        AnyTable::DungeonEncounter(data) => {
             let (table, insert) = DungeonEncounter();
             tx.execute(table, ())?;
 
@@ -1463,8 +1419,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "DungeonMap.dbc" => {
-            let data = dungeon_map::DungeonMap::read(file_contents)?;
+        AnyTable::DungeonMap(data) => {
             let (table, insert) = DungeonMap();
             tx.execute(table, ())?;
 
@@ -1481,8 +1436,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "DungeonMapChunk.dbc" => {
-            let data = dungeon_map_chunk::DungeonMapChunk::read(file_contents)?;
+        AnyTable::DungeonMapChunk(data) => {
             let (table, insert) = DungeonMapChunk();
             tx.execute(table, ())?;
 
@@ -1496,8 +1450,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "DurabilityCosts.dbc" => {
-            let data = durability_costs::DurabilityCosts::read(file_contents)?;
+        AnyTable::DurabilityCosts(data) => {
             let (table, insert) = DurabilityCosts();
             tx.execute(table, ())?;
 
@@ -1536,8 +1489,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "DurabilityQuality.dbc" => {
-            let data = durability_quality::DurabilityQuality::read(file_contents)?;
+        AnyTable::DurabilityQuality(data) => {
             let (table, insert) = DurabilityQuality();
             tx.execute(table, ())?;
 
@@ -1548,8 +1500,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Emotes.dbc" => {
-            let data = emotes::Emotes::read(file_contents)?;
+        AnyTable::Emotes(data) => {
             let (table, insert) = Emotes();
             tx.execute(table, ())?;
 
@@ -1565,8 +1516,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "EmotesText.dbc" => {
-            let data = emotes_text::EmotesText::read(file_contents)?;
+        AnyTable::EmotesText(data) => {
             let (table, insert) = EmotesText();
             tx.execute(table, ())?;
 
@@ -1594,8 +1544,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "EmotesTextData.dbc" => {
-            let data = emotes_text_data::EmotesTextData::read(file_contents)?;
+        AnyTable::EmotesTextData(data) => {
             let (table, insert) = EmotesTextData();
             tx.execute(table, ())?;
 
@@ -1622,8 +1571,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "EmotesTextSound.dbc" => {
-            let data = emotes_text_sound::EmotesTextSound::read(file_contents)?;
+        AnyTable::EmotesTextSound(data) => {
             let (table, insert) = EmotesTextSound();
             tx.execute(table, ())?;
 
@@ -1637,8 +1585,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "EnvironmentalDamage.dbc" => {
-            let data = environmental_damage::EnvironmentalDamage::read(file_contents)?;
+        AnyTable::EnvironmentalDamage(data) => {
             let (table, insert) = EnvironmentalDamage();
             tx.execute(table, ())?;
 
@@ -1650,8 +1597,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Exhaustion.dbc" => {
-            let data = exhaustion::Exhaustion::read(file_contents)?;
+        AnyTable::Exhaustion(data) => {
             let (table, insert) = Exhaustion();
             tx.execute(table, ())?;
 
@@ -1683,8 +1629,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Faction.dbc" => {
-            let data = faction::Faction::read(file_contents)?;
+        AnyTable::Faction(data) => {
             let (table, insert) = Faction();
             tx.execute(table, ())?;
 
@@ -1750,8 +1695,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "FactionGroup.dbc" => {
-            let data = faction_group::FactionGroup::read(file_contents)?;
+        AnyTable::FactionGroup(data) => {
             let (table, insert) = FactionGroup();
             tx.execute(table, ())?;
 
@@ -1780,8 +1724,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "FactionTemplate.dbc" => {
-            let data = faction_template::FactionTemplate::read(file_contents)?;
+        AnyTable::FactionTemplate(data) => {
             let (table, insert) = FactionTemplate();
             tx.execute(table, ())?;
 
@@ -1804,8 +1747,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "FileData.dbc" => {
-            let data = file_data::FileData::read(file_contents)?;
+        AnyTable::FileData(data) => {
             let (table, insert) = FileData();
             tx.execute(table, ())?;
 
@@ -1817,8 +1759,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "FootprintTextures.dbc" => {
-            let data = footprint_textures::FootprintTextures::read(file_contents)?;
+        AnyTable::FootprintTextures(data) => {
             let (table, insert) = FootprintTextures();
             tx.execute(table, ())?;
 
@@ -1829,8 +1770,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "FootstepTerrainLookup.dbc" => {
-            let data = footstep_terrain_lookup::FootstepTerrainLookup::read(file_contents)?;
+        AnyTable::FootstepTerrainLookup(data) => {
             let (table, insert) = FootstepTerrainLookup();
             tx.execute(table, ())?;
 
@@ -1844,8 +1784,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GMSurveyAnswers.dbc" => {
-            let data = gm_survey_answers::GMSurveyAnswers::read(file_contents)?;
+        AnyTable::GMSurveyAnswers(data) => {
             let (table, insert) = GMSurveyAnswers();
             tx.execute(table, ())?;
 
@@ -1874,8 +1813,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GMSurveyCurrentSurvey.dbc" => {
-            let data = gm_survey_current_survey::GMSurveyCurrentSurvey::read(file_contents)?;
+        AnyTable::GMSurveyCurrentSurvey(data) => {
             let (table, insert) = GMSurveyCurrentSurvey();
             tx.execute(table, ())?;
 
@@ -1886,8 +1824,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GMSurveyQuestions.dbc" => {
-            let data = gm_survey_questions::GMSurveyQuestions::read(file_contents)?;
+        AnyTable::GMSurveyQuestions(data) => {
             let (table, insert) = GMSurveyQuestions();
             tx.execute(table, ())?;
 
@@ -1914,8 +1851,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GMSurveySurveys.dbc" => {
-            let data = gm_survey_surveys::GMSurveySurveys::read(file_contents)?;
+        AnyTable::GMSurveySurveys(data) => {
             let (table, insert) = GMSurveySurveys();
             tx.execute(table, ())?;
 
@@ -1935,8 +1871,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GMTicketCategory.dbc" => {
-            let data = gm_ticket_category::GMTicketCategory::read(file_contents)?;
+        AnyTable::GMTicketCategory(data) => {
             let (table, insert) = GMTicketCategory();
             tx.execute(table, ())?;
 
@@ -1963,8 +1898,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GameObjectArtKit.dbc" => {
-            let data = game_object_art_kit::GameObjectArtKit::read(file_contents)?;
+        AnyTable::GameObjectArtKit(data) => {
             let (table, insert) = GameObjectArtKit();
             tx.execute(table, ())?;
 
@@ -1981,8 +1915,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GameObjectDisplayInfo.dbc" => {
-            let data = game_object_display_info::GameObjectDisplayInfo::read(file_contents)?;
+        AnyTable::GameObjectDisplayInfo(data) => {
             let (table, insert) = GameObjectDisplayInfo();
             tx.execute(table, ())?;
 
@@ -2010,8 +1943,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GameTables.dbc" => {
-            let data = game_tables::GameTables::read(file_contents)?;
+        AnyTable::GameTables(data) => {
             let (table, insert) = GameTables();
             tx.execute(table, ())?;
 
@@ -2023,8 +1955,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GameTips.dbc" => {
-            let data = game_tips::GameTips::read(file_contents)?;
+        AnyTable::GameTips(data) => {
             let (table, insert) = GameTips();
             tx.execute(table, ())?;
 
@@ -2051,8 +1982,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GemProperties.dbc" => {
-            let data = gem_properties::GemProperties::read(file_contents)?;
+        AnyTable::GemProperties(data) => {
             let (table, insert) = GemProperties();
             tx.execute(table, ())?;
 
@@ -2066,8 +1996,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GlyphProperties.dbc" => {
-            let data = glyph_properties::GlyphProperties::read(file_contents)?;
+        AnyTable::GlyphProperties(data) => {
             let (table, insert) = GlyphProperties();
             tx.execute(table, ())?;
 
@@ -2080,8 +2009,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GlyphSlot.dbc" => {
-            let data = glyph_slot::GlyphSlot::read(file_contents)?;
+        AnyTable::GlyphSlot(data) => {
             let (table, insert) = GlyphSlot();
             tx.execute(table, ())?;
 
@@ -2093,8 +2021,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GroundEffectDoodad.dbc" => {
-            let data = ground_effect_doodad::GroundEffectDoodad::read(file_contents)?;
+        AnyTable::GroundEffectDoodad(data) => {
             let (table, insert) = GroundEffectDoodad();
             tx.execute(table, ())?;
 
@@ -2106,8 +2033,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "GroundEffectTexture.dbc" => {
-            let data = ground_effect_texture::GroundEffectTexture::read(file_contents)?;
+        AnyTable::GroundEffectTexture(data) => {
             let (table, insert) = GroundEffectTexture();
             tx.execute(table, ())?;
 
@@ -2127,8 +2053,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "HelmetGeosetVisData.dbc" => {
-            let data = helmet_geoset_vis_data::HelmetGeosetVisData::read(file_contents)?;
+        AnyTable::HelmetGeosetVisData(data) => {
             let (table, insert) = HelmetGeosetVisData();
             tx.execute(table, ())?;
 
@@ -2145,8 +2070,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "HolidayDescriptions.dbc" => {
-            let data = holiday_descriptions::HolidayDescriptions::read(file_contents)?;
+        AnyTable::HolidayDescriptions(data) => {
             let (table, insert) = HolidayDescriptions();
             tx.execute(table, ())?;
 
@@ -2173,8 +2097,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "HolidayNames.dbc" => {
-            let data = holiday_names::HolidayNames::read(file_contents)?;
+        AnyTable::HolidayNames(data) => {
             let (table, insert) = HolidayNames();
             tx.execute(table, ())?;
 
@@ -2201,8 +2124,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Holidays.dbc" => {
-            let data = holidays::Holidays::read(file_contents)?;
+        AnyTable::Holidays(data) => {
             let (table, insert) = Holidays();
             tx.execute(table, ())?;
 
@@ -2266,8 +2188,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Item.dbc" => {
-            let data = item::Item::read(file_contents)?;
+        AnyTable::Item(data) => {
             let (table, insert) = Item();
             tx.execute(table, ())?;
 
@@ -2284,8 +2205,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemBagFamily.dbc" => {
-            let data = item_bag_family::ItemBagFamily::read(file_contents)?;
+        AnyTable::ItemBagFamily(data) => {
             let (table, insert) = ItemBagFamily();
             tx.execute(table, ())?;
 
@@ -2312,8 +2232,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemClass.dbc" => {
-            let data = item_class::ItemClass::read(file_contents)?;
+        AnyTable::ItemClass(data) => {
             let (table, insert) = ItemClass();
             tx.execute(table, ())?;
 
@@ -2342,8 +2261,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemCondExtCosts.dbc" => {
-            let data = item_cond_ext_costs::ItemCondExtCosts::read(file_contents)?;
+        AnyTable::ItemCondExtCosts(data) => {
             let (table, insert) = ItemCondExtCosts();
             tx.execute(table, ())?;
 
@@ -2356,8 +2274,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemDisplayInfo.dbc" => {
-            let data = item_display_info::ItemDisplayInfo::read(file_contents)?;
+        AnyTable::ItemDisplayInfo(data) => {
             let (table, insert) = ItemDisplayInfo();
             tx.execute(table, ())?;
 
@@ -2391,8 +2308,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemExtendedCost.dbc" => {
-            let data = item_extended_cost::ItemExtendedCost::read(file_contents)?;
+        AnyTable::ItemExtendedCost(data) => {
             let (table, insert) = ItemExtendedCost();
             tx.execute(table, ())?;
 
@@ -2417,8 +2333,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemGroupSounds.dbc" => {
-            let data = item_group_sounds::ItemGroupSounds::read(file_contents)?;
+        AnyTable::ItemGroupSounds(data) => {
             let (table, insert) = ItemGroupSounds();
             tx.execute(table, ())?;
 
@@ -2432,8 +2347,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemLimitCategory.dbc" => {
-            let data = item_limit_category::ItemLimitCategory::read(file_contents)?;
+        AnyTable::ItemLimitCategory(data) => {
             let (table, insert) = ItemLimitCategory();
             tx.execute(table, ())?;
 
@@ -2462,8 +2376,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemPetFood.dbc" => {
-            let data = item_pet_food::ItemPetFood::read(file_contents)?;
+        AnyTable::ItemPetFood(data) => {
             let (table, insert) = ItemPetFood();
             tx.execute(table, ())?;
 
@@ -2490,8 +2403,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemPurchaseGroup.dbc" => {
-            let data = item_purchase_group::ItemPurchaseGroup::read(file_contents)?;
+        AnyTable::ItemPurchaseGroup(data) => {
             let (table, insert) = ItemPurchaseGroup();
             tx.execute(table, ())?;
 
@@ -2526,8 +2438,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemRandomProperties.dbc" => {
-            let data = item_random_properties::ItemRandomProperties::read(file_contents)?;
+        AnyTable::ItemRandomProperties(data) => {
             let (table, insert) = ItemRandomProperties();
             tx.execute(table, ())?;
 
@@ -2560,8 +2471,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemRandomSuffix.dbc" => {
-            let data = item_random_suffix::ItemRandomSuffix::read(file_contents)?;
+        AnyTable::ItemRandomSuffix(data) => {
             let (table, insert) = ItemRandomSuffix();
             tx.execute(table, ())?;
 
@@ -2599,8 +2509,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemSet.dbc" => {
-            let data = item_set::ItemSet::read(file_contents)?;
+        AnyTable::ItemSet(data) => {
             let (table, insert) = ItemSet();
             tx.execute(table, ())?;
 
@@ -2662,8 +2571,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemSubClass.dbc" => {
-            let data = item_sub_class::ItemSubClass::read(file_contents)?;
+        AnyTable::ItemSubClass(data) => {
             let (table, insert) = ItemSubClass();
             tx.execute(table, ())?;
 
@@ -2716,8 +2624,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemSubClassMask.dbc" => {
-            let data = item_sub_class_mask::ItemSubClassMask::read(file_contents)?;
+        AnyTable::ItemSubClassMask(data) => {
             let (table, insert) = ItemSubClassMask();
             tx.execute(table, ())?;
 
@@ -2745,8 +2652,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemVisualEffects.dbc" => {
-            let data = item_visual_effects::ItemVisualEffects::read(file_contents)?;
+        AnyTable::ItemVisualEffects(data) => {
             let (table, insert) = ItemVisualEffects();
             tx.execute(table, ())?;
 
@@ -2757,8 +2663,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ItemVisuals.dbc" => {
-            let data = item_visuals::ItemVisuals::read(file_contents)?;
+        AnyTable::ItemVisuals(data) => {
             let (table, insert) = ItemVisuals();
             tx.execute(table, ())?;
 
@@ -2773,8 +2678,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LFGDungeonExpansion.dbc" => {
-            let data = lfg_dungeon_expansion::LFGDungeonExpansion::read(file_contents)?;
+        AnyTable::LFGDungeonExpansion(data) => {
             let (table, insert) = LFGDungeonExpansion();
             tx.execute(table, ())?;
 
@@ -2791,8 +2695,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LFGDungeonGroup.dbc" => {
-            let data = lfg_dungeon_group::LFGDungeonGroup::read(file_contents)?;
+        AnyTable::LFGDungeonGroup(data) => {
             let (table, insert) = LFGDungeonGroup();
             tx.execute(table, ())?;
 
@@ -2822,8 +2725,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LFGDungeons.dbc" => {
-            let data = lfg_dungeons::LFGDungeons::read(file_contents)?;
+        AnyTable::LFGDungeons(data) => {
             let (table, insert) = LFGDungeons();
             tx.execute(table, ())?;
 
@@ -2881,8 +2783,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LanguageWords.dbc" => {
-            let data = language_words::LanguageWords::read(file_contents)?;
+        AnyTable::LanguageWords(data) => {
             let (table, insert) = LanguageWords();
             tx.execute(table, ())?;
 
@@ -2894,8 +2795,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Languages.dbc" => {
-            let data = languages::Languages::read(file_contents)?;
+        AnyTable::Languages(data) => {
             let (table, insert) = Languages();
             tx.execute(table, ())?;
 
@@ -2922,8 +2822,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Light.dbc" => {
-            let data = light::Light::read(file_contents)?;
+        AnyTable::Light(data) => {
             let (table, insert) = Light();
             tx.execute(table, ())?;
 
@@ -2947,8 +2846,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LightFloatBand.dbc" => {
-            let data = light_float_band::LightFloatBand::read(file_contents)?;
+        AnyTable::LightFloatBand(data) => {
             let (table, insert) = LightFloatBand();
             tx.execute(table, ())?;
 
@@ -2991,8 +2889,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LightIntBand.dbc" => {
-            let data = light_int_band::LightIntBand::read(file_contents)?;
+        AnyTable::LightIntBand(data) => {
             let (table, insert) = LightIntBand();
             tx.execute(table, ())?;
 
@@ -3035,8 +2932,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LightParams.dbc" => {
-            let data = light_params::LightParams::read(file_contents)?;
+        AnyTable::LightParams(data) => {
             let (table, insert) = LightParams();
             tx.execute(table, ())?;
 
@@ -3054,8 +2950,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LightSkybox.dbc" => {
-            let data = light_skybox::LightSkybox::read(file_contents)?;
+        AnyTable::LightSkybox(data) => {
             let (table, insert) = LightSkybox();
             tx.execute(table, ())?;
 
@@ -3067,8 +2962,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LiquidMaterial.dbc" => {
-            let data = liquid_material::LiquidMaterial::read(file_contents)?;
+        AnyTable::LiquidMaterial(data) => {
             let (table, insert) = LiquidMaterial();
             tx.execute(table, ())?;
 
@@ -3080,8 +2974,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LiquidType.dbc" => {
-            let data = liquid_type::LiquidType::read(file_contents)?;
+        AnyTable::LiquidType(data) => {
             let (table, insert) = LiquidType();
             tx.execute(table, ())?;
 
@@ -3135,8 +3028,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LoadingScreenTaxiSplines.dbc" => {
-            let data = loading_screen_taxi_splines::LoadingScreenTaxiSplines::read(file_contents)?;
+        AnyTable::LoadingScreenTaxiSplines(data) => {
             let (table, insert) = LoadingScreenTaxiSplines();
             tx.execute(table, ())?;
 
@@ -3164,8 +3056,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LoadingScreens.dbc" => {
-            let data = loading_screens::LoadingScreens::read(file_contents)?;
+        AnyTable::LoadingScreens(data) => {
             let (table, insert) = LoadingScreens();
             tx.execute(table, ())?;
 
@@ -3178,8 +3069,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Lock.dbc" => {
-            let data = lock::Lock::read(file_contents)?;
+        AnyTable::Lock(data) => {
             let (table, insert) = Lock();
             tx.execute(table, ())?;
 
@@ -3221,8 +3111,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "LockType.dbc" => {
-            let data = lock_type::LockType::read(file_contents)?;
+        AnyTable::LockType(data) => {
             let (table, insert) = LockType();
             tx.execute(table, ())?;
 
@@ -3284,8 +3173,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "MailTemplate.dbc" => {
-            let data = mail_template::MailTemplate::read(file_contents)?;
+        AnyTable::MailTemplate(data) => {
             let (table, insert) = MailTemplate();
             tx.execute(table, ())?;
 
@@ -3329,8 +3217,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Map.dbc" => {
-            let data = map::Map::read(file_contents)?;
+        AnyTable::Map(data) => {
             let (table, insert) = Map();
             tx.execute(table, ())?;
 
@@ -3405,8 +3292,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "MapDifficulty.dbc" => {
-            let data = map_difficulty::MapDifficulty::read(file_contents)?;
+        AnyTable::MapDifficulty(data) => {
             let (table, insert) = MapDifficulty();
             tx.execute(table, ())?;
 
@@ -3438,8 +3324,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Material.dbc" => {
-            let data = material::Material::read(file_contents)?;
+        AnyTable::Material(data) => {
             let (table, insert) = Material();
             tx.execute(table, ())?;
 
@@ -3453,8 +3338,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Movie.dbc" => {
-            let data = movie::Movie::read(file_contents)?;
+        AnyTable::Movie(data) => {
             let (table, insert) = Movie();
             tx.execute(table, ())?;
 
@@ -3466,8 +3350,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "MovieFileData.dbc" => {
-            let data = movie_file_data::MovieFileData::read(file_contents)?;
+        AnyTable::MovieFileData(data) => {
             let (table, insert) = MovieFileData();
             tx.execute(table, ())?;
 
@@ -3478,8 +3361,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "MovieVariation.dbc" => {
-            let data = movie_variation::MovieVariation::read(file_contents)?;
+        AnyTable::MovieVariation(data) => {
             let (table, insert) = MovieVariation();
             tx.execute(table, ())?;
 
@@ -3491,8 +3373,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "NPCSounds.dbc" => {
-            let data = npc_sounds::NPCSounds::read(file_contents)?;
+        AnyTable::NPCSounds(data) => {
             let (table, insert) = NPCSounds();
             tx.execute(table, ())?;
 
@@ -3506,8 +3387,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "NameGen.dbc" => {
-            let data = name_gen::NameGen::read(file_contents)?;
+        AnyTable::NameGen(data) => {
             let (table, insert) = NameGen();
             tx.execute(table, ())?;
 
@@ -3520,8 +3400,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "NamesProfanity.dbc" => {
-            let data = names_profanity::NamesProfanity::read(file_contents)?;
+        AnyTable::NamesProfanity(data) => {
             let (table, insert) = NamesProfanity();
             tx.execute(table, ())?;
 
@@ -3533,8 +3412,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "NamesReserved.dbc" => {
-            let data = names_reserved::NamesReserved::read(file_contents)?;
+        AnyTable::NamesReserved(data) => {
             let (table, insert) = NamesReserved();
             tx.execute(table, ())?;
 
@@ -3546,8 +3424,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ObjectEffect.dbc" => {
-            let data = object_effect::ObjectEffect::read(file_contents)?;
+        AnyTable::ObjectEffect(data) => {
             let (table, insert) = ObjectEffect();
             tx.execute(table, ())?;
 
@@ -3568,8 +3445,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ObjectEffectGroup.dbc" => {
-            let data = object_effect_group::ObjectEffectGroup::read(file_contents)?;
+        AnyTable::ObjectEffectGroup(data) => {
             let (table, insert) = ObjectEffectGroup();
             tx.execute(table, ())?;
 
@@ -3580,8 +3456,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ObjectEffectModifier.dbc" => {
-            let data = object_effect_modifier::ObjectEffectModifier::read(file_contents)?;
+        AnyTable::ObjectEffectModifier(data) => {
             let (table, insert) = ObjectEffectModifier();
             tx.execute(table, ())?;
 
@@ -3598,8 +3473,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ObjectEffectPackage.dbc" => {
-            let data = object_effect_package::ObjectEffectPackage::read(file_contents)?;
+        AnyTable::ObjectEffectPackage(data) => {
             let (table, insert) = ObjectEffectPackage();
             tx.execute(table, ())?;
 
@@ -3610,8 +3484,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ObjectEffectPackageElem.dbc" => {
-            let data = object_effect_package_elem::ObjectEffectPackageElem::read(file_contents)?;
+        AnyTable::ObjectEffectPackageElem(data) => {
             let (table, insert) = ObjectEffectPackageElem();
             tx.execute(table, ())?;
 
@@ -3624,8 +3497,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "OverrideSpellData.dbc" => {
-            let data = override_spell_data::OverrideSpellData::read(file_contents)?;
+        AnyTable::OverrideSpellData(data) => {
             let (table, insert) = OverrideSpellData();
             tx.execute(table, ())?;
 
@@ -3646,8 +3518,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Package.dbc" => {
-            let data = package::Package::read(file_contents)?;
+        AnyTable::Package(data) => {
             let (table, insert) = Package();
             tx.execute(table, ())?;
 
@@ -3676,8 +3547,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "PageTextMaterial.dbc" => {
-            let data = page_text_material::PageTextMaterial::read(file_contents)?;
+        AnyTable::PageTextMaterial(data) => {
             let (table, insert) = PageTextMaterial();
             tx.execute(table, ())?;
 
@@ -3688,8 +3558,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "PaperDollItemFrame.dbc" => {
-            let data = paper_doll_item_frame::PaperDollItemFrame::read(file_contents)?;
+        AnyTable::PaperDollItemFrame(data) => {
             let (table, insert) = PaperDollItemFrame();
             tx.execute(table, ())?;
 
@@ -3701,8 +3570,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ParticleColor.dbc" => {
-            let data = particle_color::ParticleColor::read(file_contents)?;
+        AnyTable::ParticleColor(data) => {
             let (table, insert) = ParticleColor();
             tx.execute(table, ())?;
 
@@ -3721,8 +3589,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "PetPersonality.dbc" => {
-            let data = pet_personality::PetPersonality::read(file_contents)?;
+        AnyTable::PetPersonality(data) => {
             let (table, insert) = PetPersonality();
             tx.execute(table, ())?;
 
@@ -3755,8 +3622,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "PetitionType.dbc" => {
-            let data = petition_type::PetitionType::read(file_contents)?;
+        AnyTable::PetitionType(data) => {
             let (table, insert) = PetitionType();
             tx.execute(table, ())?;
 
@@ -3768,8 +3634,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "PowerDisplay.dbc" => {
-            let data = power_display::PowerDisplay::read(file_contents)?;
+        AnyTable::PowerDisplay(data) => {
             let (table, insert) = PowerDisplay();
             tx.execute(table, ())?;
 
@@ -3784,8 +3649,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "PvpDifficulty.dbc" => {
-            let data = pvp_difficulty::PvpDifficulty::read(file_contents)?;
+        AnyTable::PvpDifficulty(data) => {
             let (table, insert) = PvpDifficulty();
             tx.execute(table, ())?;
 
@@ -3800,8 +3664,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "QuestFactionReward.dbc" => {
-            let data = quest_faction_reward::QuestFactionReward::read(file_contents)?;
+        AnyTable::QuestFactionReward(data) => {
             let (table, insert) = QuestFactionReward();
             tx.execute(table, ())?;
 
@@ -3821,8 +3684,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "QuestInfo.dbc" => {
-            let data = quest_info::QuestInfo::read(file_contents)?;
+        AnyTable::QuestInfo(data) => {
             let (table, insert) = QuestInfo();
             tx.execute(table, ())?;
 
@@ -3849,8 +3711,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "QuestSort.dbc" => {
-            let data = quest_sort::QuestSort::read(file_contents)?;
+        AnyTable::QuestSort(data) => {
             let (table, insert) = QuestSort();
             tx.execute(table, ())?;
 
@@ -3877,8 +3738,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "QuestXP.dbc" => {
-            let data = quest_xp::QuestXP::read(file_contents)?;
+        AnyTable::QuestXP(data) => {
             let (table, insert) = QuestXP();
             tx.execute(table, ())?;
 
@@ -3898,8 +3758,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "RandPropPoints.dbc" => {
-            let data = rand_prop_points::RandPropPoints::read(file_contents)?;
+        AnyTable::RandPropPoints(data) => {
             let (table, insert) = RandPropPoints();
             tx.execute(table, ())?;
 
@@ -3924,8 +3783,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Resistances.dbc" => {
-            let data = resistances::Resistances::read(file_contents)?;
+        AnyTable::Resistances(data) => {
             let (table, insert) = Resistances();
             tx.execute(table, ())?;
 
@@ -3954,8 +3812,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ScalingStatDistribution.dbc" => {
-            let data = scaling_stat_distribution::ScalingStatDistribution::read(file_contents)?;
+        AnyTable::ScalingStatDistribution(data) => {
             let (table, insert) = ScalingStatDistribution();
             tx.execute(table, ())?;
 
@@ -3986,8 +3843,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ScalingStatValues.dbc" => {
-            let data = scaling_stat_values::ScalingStatValues::read(file_contents)?;
+        AnyTable::ScalingStatValues(data) => {
             let (table, insert) = ScalingStatValues();
             tx.execute(table, ())?;
 
@@ -4020,8 +3876,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ScreenEffect.dbc" => {
-            let data = screen_effect::ScreenEffect::read(file_contents)?;
+        AnyTable::ScreenEffect(data) => {
             let (table, insert) = ScreenEffect();
             tx.execute(table, ())?;
 
@@ -4040,8 +3895,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ServerMessages.dbc" => {
-            let data = server_messages::ServerMessages::read(file_contents)?;
+        AnyTable::ServerMessages(data) => {
             let (table, insert) = ServerMessages();
             tx.execute(table, ())?;
 
@@ -4068,8 +3922,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SheatheSoundLookups.dbc" => {
-            let data = sheathe_sound_lookups::SheatheSoundLookups::read(file_contents)?;
+        AnyTable::SheatheSoundLookups(data) => {
             let (table, insert) = SheatheSoundLookups();
             tx.execute(table, ())?;
 
@@ -4085,8 +3938,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SkillCostsData.dbc" => {
-            let data = skill_costs_data::SkillCostsData::read(file_contents)?;
+        AnyTable::SkillCostsData(data) => {
             let (table, insert) = SkillCostsData();
             tx.execute(table, ())?;
 
@@ -4100,8 +3952,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SkillLine.dbc" => {
-            let data = skill_line::SkillLine::read(file_contents)?;
+        AnyTable::SkillLine(data) => {
             let (table, insert) = SkillLine();
             tx.execute(table, ())?;
 
@@ -4166,8 +4017,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SkillLineAbility.dbc" => {
-            let data = skill_line_ability::SkillLineAbility::read(file_contents)?;
+        AnyTable::SkillLineAbility(data) => {
             let (table, insert) = SkillLineAbility();
             tx.execute(table, ())?;
 
@@ -4190,8 +4040,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SkillLineCategory.dbc" => {
-            let data = skill_line_category::SkillLineCategory::read(file_contents)?;
+        AnyTable::SkillLineCategory(data) => {
             let (table, insert) = SkillLineCategory();
             tx.execute(table, ())?;
 
@@ -4219,8 +4068,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SkillRaceClassInfo.dbc" => {
-            let data = skill_race_class_info::SkillRaceClassInfo::read(file_contents)?;
+        AnyTable::SkillRaceClassInfo(data) => {
             let (table, insert) = SkillRaceClassInfo();
             tx.execute(table, ())?;
 
@@ -4237,8 +4085,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SkillTiers.dbc" => {
-            let data = skill_tiers::SkillTiers::read(file_contents)?;
+        AnyTable::SkillTiers(data) => {
             let (table, insert) = SkillTiers();
             tx.execute(table, ())?;
 
@@ -4280,8 +4127,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SoundAmbience.dbc" => {
-            let data = sound_ambience::SoundAmbience::read(file_contents)?;
+        AnyTable::SoundAmbience(data) => {
             let (table, insert) = SoundAmbience();
             tx.execute(table, ())?;
 
@@ -4293,8 +4139,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SoundEmitters.dbc" => {
-            let data = sound_emitters::SoundEmitters::read(file_contents)?;
+        AnyTable::SoundEmitters(data) => {
             let (table, insert) = SoundEmitters();
             tx.execute(table, ())?;
 
@@ -4313,8 +4158,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SoundEntries.dbc" => {
-            let data = sound_entries::SoundEntries::read(file_contents)?;
+        AnyTable::SoundEntries(data) => {
             let (table, insert) = SoundEntries();
             tx.execute(table, ())?;
 
@@ -4353,8 +4197,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SoundEntriesAdvanced.dbc" => {
-            let data = sound_entries_advanced::SoundEntriesAdvanced::read(file_contents)?;
+        AnyTable::SoundEntriesAdvanced(data) => {
             let (table, insert) = SoundEntriesAdvanced();
             tx.execute(table, ())?;
 
@@ -4387,8 +4230,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SoundFilter.dbc" => {
-            let data = sound_filter::SoundFilter::read(file_contents)?;
+        AnyTable::SoundFilter(data) => {
             let (table, insert) = SoundFilter();
             tx.execute(table, ())?;
 
@@ -4399,8 +4241,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SoundFilterElem.dbc" => {
-            let data = sound_filter_elem::SoundFilterElem::read(file_contents)?;
+        AnyTable::SoundFilterElem(data) => {
             let (table, insert) = SoundFilterElem();
             tx.execute(table, ())?;
 
@@ -4422,8 +4263,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SoundProviderPreferences.dbc" => {
-            let data = sound_provider_preferences::SoundProviderPreferences::read(file_contents)?;
+        AnyTable::SoundProviderPreferences(data) => {
             let (table, insert) = SoundProviderPreferences();
             tx.execute(table, ())?;
 
@@ -4456,8 +4296,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SoundSamplePreferences.dbc" => {
-            let data = sound_sample_preferences::SoundSamplePreferences::read(file_contents)?;
+        AnyTable::SoundSamplePreferences(data) => {
             let (table, insert) = SoundSamplePreferences();
             tx.execute(table, ())?;
 
@@ -4483,8 +4322,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SoundWaterType.dbc" => {
-            let data = sound_water_type::SoundWaterType::read(file_contents)?;
+        AnyTable::SoundWaterType(data) => {
             let (table, insert) = SoundWaterType();
             tx.execute(table, ())?;
 
@@ -4497,8 +4335,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpamMessages.dbc" => {
-            let data = spam_messages::SpamMessages::read(file_contents)?;
+        AnyTable::SpamMessages(data) => {
             let (table, insert) = SpamMessages();
             tx.execute(table, ())?;
 
@@ -4509,8 +4346,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Spell.dbc" => {
-            let data = spell::Spell::read(file_contents)?;
+        AnyTable::Spell(data) => {
             let (table, insert) = Spell();
             tx.execute(table, ())?;
 
@@ -4753,8 +4589,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellCastTimes.dbc" => {
-            let data = spell_cast_times::SpellCastTimes::read(file_contents)?;
+        AnyTable::SpellCastTimes(data) => {
             let (table, insert) = SpellCastTimes();
             tx.execute(table, ())?;
 
@@ -4767,8 +4602,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellCategory.dbc" => {
-            let data = spell_category::SpellCategory::read(file_contents)?;
+        AnyTable::SpellCategory(data) => {
             let (table, insert) = SpellCategory();
             tx.execute(table, ())?;
 
@@ -4779,8 +4613,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellChainEffects.dbc" => {
-            let data = spell_chain_effects::SpellChainEffects::read(file_contents)?;
+        AnyTable::SpellChainEffects(data) => {
             let (table, insert) = SpellChainEffects();
             tx.execute(table, ())?;
 
@@ -4837,8 +4670,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellDescriptionVariables.dbc" => {
-            let data = spell_description_variables::SpellDescriptionVariables::read(file_contents)?;
+        AnyTable::SpellDescriptionVariables(data) => {
             let (table, insert) = SpellDescriptionVariables();
             tx.execute(table, ())?;
 
@@ -4849,8 +4681,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellDifficulty.dbc" => {
-            let data = spell_difficulty::SpellDifficulty::read(file_contents)?;
+        AnyTable::SpellDifficulty(data) => {
             let (table, insert) = SpellDifficulty();
             tx.execute(table, ())?;
 
@@ -4864,8 +4695,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellDispelType.dbc" => {
-            let data = spell_dispel_type::SpellDispelType::read(file_contents)?;
+        AnyTable::SpellDispelType(data) => {
             let (table, insert) = SpellDispelType();
             tx.execute(table, ())?;
 
@@ -4895,8 +4725,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellDuration.dbc" => {
-            let data = spell_duration::SpellDuration::read(file_contents)?;
+        AnyTable::SpellDuration(data) => {
             let (table, insert) = SpellDuration();
             tx.execute(table, ())?;
 
@@ -4909,8 +4738,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellEffectCameraShakes.dbc" => {
-            let data = spell_effect_camera_shakes::SpellEffectCameraShakes::read(file_contents)?;
+        AnyTable::SpellEffectCameraShakes(data) => {
             let (table, insert) = SpellEffectCameraShakes();
             tx.execute(table, ())?;
 
@@ -4923,8 +4751,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellFocusObject.dbc" => {
-            let data = spell_focus_object::SpellFocusObject::read(file_contents)?;
+        AnyTable::SpellFocusObject(data) => {
             let (table, insert) = SpellFocusObject();
             tx.execute(table, ())?;
 
@@ -4951,8 +4778,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellIcon.dbc" => {
-            let data = spell_icon::SpellIcon::read(file_contents)?;
+        AnyTable::SpellIcon(data) => {
             let (table, insert) = SpellIcon();
             tx.execute(table, ())?;
 
@@ -4963,8 +4789,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellItemEnchantment.dbc" => {
-            let data = spell_item_enchantment::SpellItemEnchantment::read(file_contents)?;
+        AnyTable::SpellItemEnchantment(data) => {
             let (table, insert) = SpellItemEnchantment();
             tx.execute(table, ())?;
 
@@ -5011,8 +4836,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellItemEnchantmentCondition.dbc" => {
-            let data = spell_item_enchantment_condition::SpellItemEnchantmentCondition::read(file_contents)?;
+        AnyTable::SpellItemEnchantmentCondition(data) => {
             let (table, insert) = SpellItemEnchantmentCondition();
             tx.execute(table, ())?;
 
@@ -5052,8 +4876,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellMechanic.dbc" => {
-            let data = spell_mechanic::SpellMechanic::read(file_contents)?;
+        AnyTable::SpellMechanic(data) => {
             let (table, insert) = SpellMechanic();
             tx.execute(table, ())?;
 
@@ -5080,8 +4903,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellMissile.dbc" => {
-            let data = spell_missile::SpellMissile::read(file_contents)?;
+        AnyTable::SpellMissile(data) => {
             let (table, insert) = SpellMissile();
             tx.execute(table, ())?;
 
@@ -5105,8 +4927,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellMissileMotion.dbc" => {
-            let data = spell_missile_motion::SpellMissileMotion::read(file_contents)?;
+        AnyTable::SpellMissileMotion(data) => {
             let (table, insert) = SpellMissileMotion();
             tx.execute(table, ())?;
 
@@ -5120,8 +4941,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellRadius.dbc" => {
-            let data = spell_radius::SpellRadius::read(file_contents)?;
+        AnyTable::SpellRadius(data) => {
             let (table, insert) = SpellRadius();
             tx.execute(table, ())?;
 
@@ -5134,8 +4954,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellRange.dbc" => {
-            let data = spell_range::SpellRange::read(file_contents)?;
+        AnyTable::SpellRange(data) => {
             let (table, insert) = SpellRange();
             tx.execute(table, ())?;
 
@@ -5184,8 +5003,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellRuneCost.dbc" => {
-            let data = spell_rune_cost::SpellRuneCost::read(file_contents)?;
+        AnyTable::SpellRuneCost(data) => {
             let (table, insert) = SpellRuneCost();
             tx.execute(table, ())?;
 
@@ -5199,8 +5017,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellShapeshiftForm.dbc" => {
-            let data = spell_shapeshift_form::SpellShapeshiftForm::read(file_contents)?;
+        AnyTable::SpellShapeshiftForm(data) => {
             let (table, insert) = SpellShapeshiftForm();
             tx.execute(table, ())?;
 
@@ -5244,8 +5061,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellVisual.dbc" => {
-            let data = spell_visual::SpellVisual::read(file_contents)?;
+        AnyTable::SpellVisual(data) => {
             let (table, insert) = SpellVisual();
             tx.execute(table, ())?;
 
@@ -5286,8 +5102,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellVisualEffectName.dbc" => {
-            let data = spell_visual_effect_name::SpellVisualEffectName::read(file_contents)?;
+        AnyTable::SpellVisualEffectName(data) => {
             let (table, insert) = SpellVisualEffectName();
             tx.execute(table, ())?;
 
@@ -5303,8 +5118,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellVisualKit.dbc" => {
-            let data = spell_visual_kit::SpellVisualKit::read(file_contents)?;
+        AnyTable::SpellVisualKit(data) => {
             let (table, insert) = SpellVisualKit();
             tx.execute(table, ())?;
 
@@ -5351,8 +5165,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellVisualKitAreaModel.dbc" => {
-            let data = spell_visual_kit_area_model::SpellVisualKitAreaModel::read(file_contents)?;
+        AnyTable::SpellVisualKitAreaModel(data) => {
             let (table, insert) = SpellVisualKitAreaModel();
             tx.execute(table, ())?;
 
@@ -5364,8 +5177,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellVisualKitModelAttach.dbc" => {
-            let data = spell_visual_kit_model_attach::SpellVisualKitModelAttach::read(file_contents)?;
+        AnyTable::SpellVisualKitModelAttach(data) => {
             let (table, insert) = SpellVisualKitModelAttach();
             tx.execute(table, ())?;
 
@@ -5384,8 +5196,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SpellVisualPrecastTransitions.dbc" => {
-            let data = spell_visual_precast_transitions::SpellVisualPrecastTransitions::read(file_contents)?;
+        AnyTable::SpellVisualPrecastTransitions(data) => {
             let (table, insert) = SpellVisualPrecastTransitions();
             tx.execute(table, ())?;
 
@@ -5397,8 +5208,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "StableSlotPrices.dbc" => {
-            let data = stable_slot_prices::StableSlotPrices::read(file_contents)?;
+        AnyTable::StableSlotPrices(data) => {
             let (table, insert) = StableSlotPrices();
             tx.execute(table, ())?;
 
@@ -5409,8 +5219,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Startup_Strings.dbc" => {
-            let data = startup_strings::Startup_Strings::read(file_contents)?;
+        AnyTable::Startup_Strings(data) => {
             let (table, insert) = Startup_Strings();
             tx.execute(table, ())?;
 
@@ -5438,8 +5247,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Stationery.dbc" => {
-            let data = stationery::Stationery::read(file_contents)?;
+        AnyTable::Stationery(data) => {
             let (table, insert) = Stationery();
             tx.execute(table, ())?;
 
@@ -5452,8 +5260,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "StringLookups.dbc" => {
-            let data = string_lookups::StringLookups::read(file_contents)?;
+        AnyTable::StringLookups(data) => {
             let (table, insert) = StringLookups();
             tx.execute(table, ())?;
 
@@ -5464,8 +5271,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "SummonProperties.dbc" => {
-            let data = summon_properties::SummonProperties::read(file_contents)?;
+        AnyTable::SummonProperties(data) => {
             let (table, insert) = SummonProperties();
             tx.execute(table, ())?;
 
@@ -5480,8 +5286,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Talent.dbc" => {
-            let data = talent::Talent::read(file_contents)?;
+        AnyTable::Talent(data) => {
             let (table, insert) = Talent();
             tx.execute(table, ())?;
 
@@ -5513,8 +5318,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TalentTab.dbc" => {
-            let data = talent_tab::TalentTab::read(file_contents)?;
+        AnyTable::TalentTab(data) => {
             let (table, insert) = TalentTab();
             tx.execute(table, ())?;
 
@@ -5547,8 +5351,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TaxiNodes.dbc" => {
-            let data = taxi_nodes::TaxiNodes::read(file_contents)?;
+        AnyTable::TaxiNodes(data) => {
             let (table, insert) = TaxiNodes();
             tx.execute(table, ())?;
 
@@ -5581,8 +5384,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TaxiPath.dbc" => {
-            let data = taxi_path::TaxiPath::read(file_contents)?;
+        AnyTable::TaxiPath(data) => {
             let (table, insert) = TaxiPath();
             tx.execute(table, ())?;
 
@@ -5595,8 +5397,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TaxiPathNode.dbc" => {
-            let data = taxi_path_node::TaxiPathNode::read(file_contents)?;
+        AnyTable::TaxiPathNode(data) => {
             let (table, insert) = TaxiPathNode();
             tx.execute(table, ())?;
 
@@ -5616,8 +5417,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TeamContributionPoints.dbc" => {
-            let data = team_contribution_points::TeamContributionPoints::read(file_contents)?;
+        AnyTable::TeamContributionPoints(data) => {
             let (table, insert) = TeamContributionPoints();
             tx.execute(table, ())?;
 
@@ -5628,8 +5428,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TerrainType.dbc" => {
-            let data = terrain_type::TerrainType::read(file_contents)?;
+        AnyTable::TerrainType(data) => {
             let (table, insert) = TerrainType();
             tx.execute(table, ())?;
 
@@ -5644,8 +5443,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TerrainTypeSounds.dbc" => {
-            let data = terrain_type_sounds::TerrainTypeSounds::read(file_contents)?;
+        AnyTable::TerrainTypeSounds(data) => {
             let (table, insert) = TerrainTypeSounds();
             tx.execute(table, ())?;
 
@@ -5655,8 +5453,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TotemCategory.dbc" => {
-            let data = totem_category::TotemCategory::read(file_contents)?;
+        AnyTable::TotemCategory(data) => {
             let (table, insert) = TotemCategory();
             tx.execute(table, ())?;
 
@@ -5685,8 +5482,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TransportAnimation.dbc" => {
-            let data = transport_animation::TransportAnimation::read(file_contents)?;
+        AnyTable::TransportAnimation(data) => {
             let (table, insert) = TransportAnimation();
             tx.execute(table, ())?;
 
@@ -5702,8 +5498,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TransportPhysics.dbc" => {
-            let data = transport_physics::TransportPhysics::read(file_contents)?;
+        AnyTable::TransportPhysics(data) => {
             let (table, insert) = TransportPhysics();
             tx.execute(table, ())?;
 
@@ -5723,8 +5518,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "TransportRotation.dbc" => {
-            let data = transport_rotation::TransportRotation::read(file_contents)?;
+        AnyTable::TransportRotation(data) => {
             let (table, insert) = TransportRotation();
             tx.execute(table, ())?;
 
@@ -5740,8 +5534,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "UISoundLookups.dbc" => {
-            let data = ui_sound_lookups::UISoundLookups::read(file_contents)?;
+        AnyTable::UISoundLookups(data) => {
             let (table, insert) = UISoundLookups();
             tx.execute(table, ())?;
 
@@ -5753,8 +5546,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "UnitBlood.dbc" => {
-            let data = unit_blood::UnitBlood::read(file_contents)?;
+        AnyTable::UnitBlood(data) => {
             let (table, insert) = UnitBlood();
             tx.execute(table, ())?;
 
@@ -5773,8 +5565,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "UnitBloodLevels.dbc" => {
-            let data = unit_blood_levels::UnitBloodLevels::read(file_contents)?;
+        AnyTable::UnitBloodLevels(data) => {
             let (table, insert) = UnitBloodLevels();
             tx.execute(table, ())?;
 
@@ -5787,8 +5578,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Vehicle.dbc" => {
-            let data = vehicle::Vehicle::read(file_contents)?;
+        AnyTable::Vehicle(data) => {
             let (table, insert) = Vehicle();
             tx.execute(table, ())?;
 
@@ -5837,8 +5627,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "VehicleSeat.dbc" => {
-            let data = vehicle_seat::VehicleSeat::read(file_contents)?;
+        AnyTable::VehicleSeat(data) => {
             let (table, insert) = VehicleSeat();
             tx.execute(table, ())?;
 
@@ -5905,8 +5694,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "VehicleUIIndSeat.dbc" => {
-            let data = vehicle_ui_ind_seat::VehicleUIIndSeat::read(file_contents)?;
+        AnyTable::VehicleUIIndSeat(data) => {
             let (table, insert) = VehicleUIIndSeat();
             tx.execute(table, ())?;
 
@@ -5920,8 +5708,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "VehicleUIIndicator.dbc" => {
-            let data = vehicle_ui_indicator::VehicleUIIndicator::read(file_contents)?;
+        AnyTable::VehicleUIIndicator(data) => {
             let (table, insert) = VehicleUIIndicator();
             tx.execute(table, ())?;
 
@@ -5932,8 +5719,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "VideoHardware.dbc" => {
-            let data = video_hardware::VideoHardware::read(file_contents)?;
+        AnyTable::VideoHardware(data) => {
             let (table, insert) = VideoHardware();
             tx.execute(table, ())?;
 
@@ -5965,8 +5751,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "VocalUISounds.dbc" => {
-            let data = vocal_ui_sounds::VocalUISounds::read(file_contents)?;
+        AnyTable::VocalUISounds(data) => {
             let (table, insert) = VocalUISounds();
             tx.execute(table, ())?;
 
@@ -5982,8 +5767,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WMOAreaTable.dbc" => {
-            let data = wmo_area_table::WMOAreaTable::read(file_contents)?;
+        AnyTable::WMOAreaTable(data) => {
             let (table, insert) = WMOAreaTable();
             tx.execute(table, ())?;
 
@@ -6020,8 +5804,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WeaponImpactSounds.dbc" => {
-            let data = weapon_impact_sounds::WeaponImpactSounds::read(file_contents)?;
+        AnyTable::WeaponImpactSounds(data) => {
             let (table, insert) = WeaponImpactSounds();
             tx.execute(table, ())?;
 
@@ -6053,8 +5836,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WeaponSwingSounds2.dbc" => {
-            let data = weapon_swing_sounds2::WeaponSwingSounds2::read(file_contents)?;
+        AnyTable::WeaponSwingSounds2(data) => {
             let (table, insert) = WeaponSwingSounds2();
             tx.execute(table, ())?;
 
@@ -6067,8 +5849,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "Weather.dbc" => {
-            let data = weather::Weather::read(file_contents)?;
+        AnyTable::Weather(data) => {
             let (table, insert) = Weather();
             tx.execute(table, ())?;
 
@@ -6085,8 +5866,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WorldChunkSounds.dbc" => {
-            let data = world_chunk_sounds::WorldChunkSounds::read(file_contents)?;
+        AnyTable::WorldChunkSounds(data) => {
             let (table, insert) = WorldChunkSounds();
             tx.execute(table, ())?;
 
@@ -6104,8 +5884,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WorldMapArea.dbc" => {
-            let data = world_map_area::WorldMapArea::read(file_contents)?;
+        AnyTable::WorldMapArea(data) => {
             let (table, insert) = WorldMapArea();
             tx.execute(table, ())?;
 
@@ -6125,8 +5904,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WorldMapContinent.dbc" => {
-            let data = world_map_continent::WorldMapContinent::read(file_contents)?;
+        AnyTable::WorldMapContinent(data) => {
             let (table, insert) = WorldMapContinent();
             tx.execute(table, ())?;
 
@@ -6149,8 +5927,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WorldMapOverlay.dbc" => {
-            let data = world_map_overlay::WorldMapOverlay::read(file_contents)?;
+        AnyTable::WorldMapOverlay(data) => {
             let (table, insert) = WorldMapOverlay();
             tx.execute(table, ())?;
 
@@ -6176,8 +5953,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WorldMapTransforms.dbc" => {
-            let data = world_map_transforms::WorldMapTransforms::read(file_contents)?;
+        AnyTable::WorldMapTransforms(data) => {
             let (table, insert) = WorldMapTransforms();
             tx.execute(table, ())?;
 
@@ -6196,8 +5972,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WorldSafeLocs.dbc" => {
-            let data = world_safe_locs::WorldSafeLocs::read(file_contents)?;
+        AnyTable::WorldSafeLocs(data) => {
             let (table, insert) = WorldSafeLocs();
             tx.execute(table, ())?;
 
@@ -6228,8 +6003,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WorldStateUI.dbc" => {
-            let data = world_state_ui::WorldStateUI::read(file_contents)?;
+        AnyTable::WorldStateUI(data) => {
             let (table, insert) = WorldStateUI();
             tx.execute(table, ())?;
 
@@ -6301,8 +6075,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WorldStateZoneSounds.dbc" => {
-            let data = world_state_zone_sounds::WorldStateZoneSounds::read(file_contents)?;
+        AnyTable::WorldStateZoneSounds(data) => {
             let (table, insert) = WorldStateZoneSounds();
             tx.execute(table, ())?;
 
@@ -6319,8 +6092,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "WowError_Strings.dbc" => {
-            let data = wow_error_strings::WowError_Strings::read(file_contents)?;
+        AnyTable::WowError_Strings(data) => {
             let (table, insert) = WowError_Strings();
             tx.execute(table, ())?;
 
@@ -6348,8 +6120,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ZoneIntroMusicTable.dbc" => {
-            let data = zone_intro_music_table::ZoneIntroMusicTable::read(file_contents)?;
+        AnyTable::ZoneIntroMusicTable(data) => {
             let (table, insert) = ZoneIntroMusicTable();
             tx.execute(table, ())?;
 
@@ -6363,8 +6134,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "ZoneMusic.dbc" => {
-            let data = zone_music::ZoneMusic::read(file_contents)?;
+        AnyTable::ZoneMusic(data) => {
             let (table, insert) = ZoneMusic();
             tx.execute(table, ())?;
 
@@ -6381,8 +6151,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "gtBarberShopCostBase.dbc" => {
-            let data = gt_barber_shop_cost_base::gtBarberShopCostBase::read(file_contents)?;
+        AnyTable::gtBarberShopCostBase(data) => {
             let (table, insert) = gtBarberShopCostBase();
             tx.execute(table, ())?;
 
@@ -6392,8 +6161,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "gtChanceToMeleeCrit.dbc" => {
-            let data = gt_chance_to_melee_crit::gtChanceToMeleeCrit::read(file_contents)?;
+        AnyTable::gtChanceToMeleeCrit(data) => {
             let (table, insert) = gtChanceToMeleeCrit();
             tx.execute(table, ())?;
 
@@ -6403,8 +6171,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "gtChanceToMeleeCritBase.dbc" => {
-            let data = gt_chance_to_melee_crit_base::gtChanceToMeleeCritBase::read(file_contents)?;
+        AnyTable::gtChanceToMeleeCritBase(data) => {
             let (table, insert) = gtChanceToMeleeCritBase();
             tx.execute(table, ())?;
 
@@ -6414,8 +6181,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "gtChanceToSpellCrit.dbc" => {
-            let data = gt_chance_to_spell_crit::gtChanceToSpellCrit::read(file_contents)?;
+        AnyTable::gtChanceToSpellCrit(data) => {
             let (table, insert) = gtChanceToSpellCrit();
             tx.execute(table, ())?;
 
@@ -6425,8 +6191,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "gtChanceToSpellCritBase.dbc" => {
-            let data = gt_chance_to_spell_crit_base::gtChanceToSpellCritBase::read(file_contents)?;
+        AnyTable::gtChanceToSpellCritBase(data) => {
             let (table, insert) = gtChanceToSpellCritBase();
             tx.execute(table, ())?;
 
@@ -6436,8 +6201,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "gtCombatRatings.dbc" => {
-            let data = gt_combat_ratings::gtCombatRatings::read(file_contents)?;
+        AnyTable::gtCombatRatings(data) => {
             let (table, insert) = gtCombatRatings();
             tx.execute(table, ())?;
 
@@ -6447,8 +6211,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "gtNPCManaCostScaler.dbc" => {
-            let data = gt_npc_mana_cost_scaler::gtNPCManaCostScaler::read(file_contents)?;
+        AnyTable::gtNPCManaCostScaler(data) => {
             let (table, insert) = gtNPCManaCostScaler();
             tx.execute(table, ())?;
 
@@ -6458,8 +6221,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "gtOCTClassCombatRatingScalar.dbc" => {
-            let data = gt_oct_class_combat_rating_scalar::gtOCTClassCombatRatingScalar::read(file_contents)?;
+        AnyTable::gtOCTClassCombatRatingScalar(data) => {
             let (table, insert) = gtOCTClassCombatRatingScalar();
             tx.execute(table, ())?;
 
@@ -6470,8 +6232,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "gtOCTRegenHP.dbc" => {
-            let data = gt_oct_regen_hp::gtOCTRegenHP::read(file_contents)?;
+        AnyTable::gtOCTRegenHP(data) => {
             let (table, insert) = gtOCTRegenHP();
             tx.execute(table, ())?;
 
@@ -6481,8 +6242,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "gtOCTRegenMP.dbc" => {
-            let data = gt_oct_regen_mp::gtOCTRegenMP::read(file_contents)?;
+        AnyTable::gtOCTRegenMP(data) => {
             let (table, insert) = gtOCTRegenMP();
             tx.execute(table, ())?;
 
@@ -6492,8 +6252,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "gtRegenHPPerSpt.dbc" => {
-            let data = gt_regen_hp_per_spt::gtRegenHPPerSpt::read(file_contents)?;
+        AnyTable::gtRegenHPPerSpt(data) => {
             let (table, insert) = gtRegenHPPerSpt();
             tx.execute(table, ())?;
 
@@ -6503,8 +6262,7 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        "gtRegenMPPerSpt.dbc" => {
-            let data = gt_regen_mp_per_spt::gtRegenMPPerSpt::read(file_contents)?;
+        AnyTable::gtRegenMPPerSpt(data) => {
             let (table, insert) = gtRegenMPPerSpt();
             tx.execute(table, ())?;
 
@@ -6514,7 +6272,6 @@ pub(crate) fn write_to_sqlite(conn: &mut Connection, file_name: &str, file_conte
                 ])?;
             }
         }
-        v => return Err(SqliteError::FilenameNotFound { name: v.to_string() }),
     }
 
     tx.commit()?;